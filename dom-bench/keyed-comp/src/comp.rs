@@ -31,7 +31,7 @@ pub(crate) struct Span {
         ><slot /></span>
     },
     pub(crate) class: DomExternalClasses,
-    pub(crate) aria_hidden: Prop<String>,
+    pub(crate) aria_hidden: Prop<bool>,
     pub(crate) click: Event<TapEvent>,
 }
 
@@ -40,7 +40,7 @@ impl Component for Span {
         Self {
             template: Default::default(),
             class: DomExternalClasses::new(),
-            aria_hidden: Prop::new(String::new()),
+            aria_hidden: Prop::new(false),
             click: Default::default(),
         }
     }
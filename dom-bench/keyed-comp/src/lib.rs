@@ -143,7 +143,7 @@ struct HelloWorld {
                 </Td>
                 <Td class:col_md_1>
                 <A>
-                    <Span class:glyphicon class:glyphicon_remove aria_hidden="true" click=@remove(&item.id)></Span>
+                    <Span class:glyphicon class:glyphicon_remove aria_hidden=true click=@remove(&item.id)></Span>
                 </A>
                 </Td>
                 <Td class:col_md_6></Td>
@@ -153,7 +153,7 @@ struct HelloWorld {
       </Table>
       <Span
         class:preloadicon class:glyphicon class:glyphicon_remove
-        aria_hidden="true"
+        aria_hidden=true
       ></Span>
     },
     rows: Vec<TableRow>,
@@ -142,7 +142,7 @@ struct HelloWorld {
                 </td>
                 <td class:col_md_1>
                 <a>
-                    <span class:glyphicon class:glyphicon_remove aria_hidden="true" tap=@remove(&item.id)></span>
+                    <span class:glyphicon class:glyphicon_remove aria_hidden=true tap=@remove(&item.id)></span>
                 </a>
                 </td>
                 <td class:col_md_6></td>
@@ -152,7 +152,7 @@ struct HelloWorld {
       </table_elem>
       <span
         class:preloadicon class:glyphicon class:glyphicon_remove
-        aria_hidden="true"
+        aria_hidden=true
       ></span>
     },
     rows: Vec<TableRow>,
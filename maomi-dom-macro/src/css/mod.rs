@@ -246,14 +246,32 @@ impl StyleSheetConstructor for DomStyleSheet {
     where
         Self: Sized,
     {
+        self.to_tokens_with_css_text(ss, tokens, None)
+    }
+}
+
+impl DomStyleSheet {
+    /// Generate the tokens, and collect the CSS text of this stylesheet into `css_text` if provided
+    pub(crate) fn to_tokens_with_css_text(
+        &self,
+        ss: &StyleSheet<Self>,
+        tokens: &mut proc_macro2::TokenStream,
+        mut css_text: Option<&mut String>,
+    ) {
         let debug_mode = CSS_OUT_MODE.with(|x| x.get() == CssOutMode::Debug);
         let inner_tokens = &mut proc_macro2::TokenStream::new();
+        let css_out_file = CSS_OUT_FILE.as_ref();
 
         // generate @keyframes output
-        if let Some(css_out_file) = CSS_OUT_FILE.as_ref() {
+        if css_out_file.is_some() || css_text.is_some() {
             for (generated_ident, content) in self.key_frames_def.iter() {
                 let s = Self::keyframes_str(generated_ident, content, Some(tokens), debug_mode, &ss.var_context);
-                css_out_file.lock().unwrap().write(s.as_bytes()).unwrap();
+                if let Some(css_out_file) = css_out_file {
+                    css_out_file.lock().unwrap().write(s.as_bytes()).unwrap();
+                }
+                if let Some(css_text) = css_text.as_deref_mut() {
+                    *css_text += &s;
+                }
             }
         }
 
@@ -667,7 +685,7 @@ impl StyleSheetConstructor for DomStyleSheet {
                         tokens.append_all(quote_spanned! {span=>
                             compile_error!(#s);
                         });
-                    } else if let Some(css_out_file) = CSS_OUT_FILE.as_ref() {
+                    } else if css_out_file.is_some() || css_text.is_some() {
                         let mut s = String::new();
                         let mut cssw = CssWriter::new(&mut s, debug_mode);
                         handle_rule_content(
@@ -680,7 +698,12 @@ impl StyleSheetConstructor for DomStyleSheet {
                             var_context,
                         )
                         .unwrap();
-                        css_out_file.lock().unwrap().write(s.as_bytes()).unwrap();
+                        if let Some(css_out_file) = css_out_file {
+                            css_out_file.lock().unwrap().write(s.as_bytes()).unwrap();
+                        }
+                        if let Some(css_text) = css_text.as_deref_mut() {
+                            *css_text += &s;
+                        }
                     } else {
                         // empty
                     }
@@ -1159,6 +1182,36 @@ mod test {
         });
     }
 
+    #[test]
+    #[serial]
+    fn css_text() {
+        setup_env(false, |env| {
+            let ss: StyleSheet<DomStyleSheet> = syn::parse_str(
+                r#"
+                    #[css_name("kf")]
+                    const KF: keyframes = {
+                        from {
+                            opacity = 0;
+                        }
+                    };
+                    #[css_name("c")]
+                    class c {
+                        animation_name = KF;
+                    }
+                "#,
+            )
+            .unwrap();
+            let mut css_text = String::new();
+            ss.style_sheet_constructor().to_tokens_with_css_text(
+                &ss,
+                &mut proc_macro2::TokenStream::new(),
+                Some(&mut css_text),
+            );
+            assert_eq!(css_text, r#"@keyframes kf{0%{opacity:0}}.c{animation-name:kf}"#);
+            assert_eq!(env.read_output(), css_text);
+        });
+    }
+
     #[test]
     #[serial]
     fn pseudo_classes() {
@@ -4,8 +4,13 @@ use syn::*;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+const MATHML_NAMESPACE: &str = "http://www.w3.org/1998/Math/MathML";
+
 fn add_global_attrs(
     fields: &mut Punctuated<Field, token::Comma>,
+    namespace: Option<&LitStr>,
+    krate: &Path,
 ) {
     let vis: Visibility = parse_quote! { pub };
     let span = vis.span();
@@ -20,32 +25,149 @@ fn add_global_attrs(
         });
     };
     add_attr("id", parse_quote! { attribute!(&str in web_sys::Element) });
-    add_attr("title", parse_quote! { attribute!(&str in web_sys::HtmlElement) }); // FIXME use LocaleStr
-    add_attr("hidden", parse_quote! { attribute!(bool in web_sys::HtmlElement) });
-    add_attr("touch_start", parse_quote! { event!(event::touch::TouchStart) });
-    add_attr("touch_move", parse_quote! { event!(event::touch::TouchMove) });
-    add_attr("touch_end", parse_quote! { event!(event::touch::TouchEnd) });
-    add_attr("touch_cancel", parse_quote! { event!(event::touch::TouchCancel) });
-    add_attr("mouse_down", parse_quote! { event!(event::mouse::MouseDown) });
-    add_attr("mouse_up", parse_quote! { event!(event::mouse::MouseUp) });
-    add_attr("mouse_move", parse_quote! { event!(event::mouse::MouseMove) });
-    add_attr("mouse_enter", parse_quote! { event!(event::mouse::MouseEnter) });
-    add_attr("mouse_leave", parse_quote! { event!(event::mouse::MouseLeave) });
-    add_attr("click", parse_quote! { event!(event::mouse::Click) });
-    add_attr("tap", parse_quote! { event!(event::tap::Tap) });
-    add_attr("long_tap", parse_quote! { event!(event::tap::LongTap) });
-    add_attr("cancel_tap", parse_quote! { event!(event::tap::CancelTap) });
-    add_attr("scroll", parse_quote! { event!(event::scroll::Scroll) });
-    add_attr("animation_start", parse_quote! { event!(event::animation::AnimationStart) });
-    add_attr("animation_iteration", parse_quote! { event!(event::animation::AnimationIteration) });
-    add_attr("animation_end", parse_quote! { event!(event::animation::AnimationEnd) });
-    add_attr("animation_cancel", parse_quote! { event!(event::animation::AnimationCancel) });
-    add_attr("transition_run", parse_quote! { event!(event::transition::TransitionRun) });
-    add_attr("transition_start", parse_quote! { event!(event::transition::TransitionStart) });
-    add_attr("transition_end", parse_quote! { event!(event::transition::TransitionEnd) });
-    add_attr("transition_cancel", parse_quote! { event!(event::transition::TransitionCancel) });
-    // FIXME add aria properties
-    add_attr("aria_hidden", parse_quote! { attribute!(&str) });
+    match namespace.map(|x| x.value()) {
+        None => {
+            add_attr("title", parse_quote! { attribute!(LocaleStr in web_sys::HtmlElement) });
+            add_attr("hidden", parse_quote! { attribute!(bool in web_sys::HtmlElement) });
+        }
+        Some(x) if x == SVG_NAMESPACE => {
+            add_attr("fill", parse_quote! { attribute!(&str) });
+            add_attr("fill_opacity", parse_quote! { attribute!(&str) });
+            add_attr("fill_rule", parse_quote! { attribute!(&str) });
+            add_attr("stroke", parse_quote! { attribute!(&str) });
+            add_attr("stroke_width", parse_quote! { attribute!(&str) });
+            add_attr("stroke_opacity", parse_quote! { attribute!(&str) });
+            add_attr("stroke_linecap", parse_quote! { attribute!(&str) });
+            add_attr("stroke_linejoin", parse_quote! { attribute!(&str) });
+            add_attr("stroke_dasharray", parse_quote! { attribute!(&str) });
+            add_attr("stroke_dashoffset", parse_quote! { attribute!(&str) });
+            add_attr("opacity", parse_quote! { attribute!(&str) });
+            add_attr("transform", parse_quote! { attribute!(&str) });
+            add_attr("clip_path", parse_quote! { attribute!(&str) });
+            add_attr("mask", parse_quote! { attribute!(&str) });
+            add_attr("filter", parse_quote! { attribute!(&str) });
+            add_attr("visibility", parse_quote! { attribute!(&str) });
+        }
+        Some(x) if x == MATHML_NAMESPACE => {
+            add_attr("dir", parse_quote! { attribute!(&str) });
+            add_attr("displaystyle", parse_quote! { attribute!(&str) });
+            add_attr("mathbackground", parse_quote! { attribute!(&str) });
+            add_attr("mathcolor", parse_quote! { attribute!(&str) });
+            add_attr("mathsize", parse_quote! { attribute!(&str) });
+            add_attr("mathvariant", parse_quote! { attribute!(&str) });
+            add_attr("scriptlevel", parse_quote! { attribute!(&str) });
+        }
+        Some(_) => {}
+    }
+    add_attr("touch_start", parse_quote! { event!(#krate::__element_macro::events::TouchStart) });
+    add_attr("touch_move", parse_quote! { event!(#krate::__element_macro::events::TouchMove) });
+    add_attr("touch_end", parse_quote! { event!(#krate::__element_macro::events::TouchEnd) });
+    add_attr("touch_cancel", parse_quote! { event!(#krate::__element_macro::events::TouchCancel) });
+    add_attr("mouse_down", parse_quote! { event!(#krate::__element_macro::events::MouseDown) });
+    add_attr("mouse_up", parse_quote! { event!(#krate::__element_macro::events::MouseUp) });
+    add_attr("mouse_move", parse_quote! { event!(#krate::__element_macro::events::MouseMove) });
+    add_attr("mouse_enter", parse_quote! { event!(#krate::__element_macro::events::MouseEnter) });
+    add_attr("mouse_leave", parse_quote! { event!(#krate::__element_macro::events::MouseLeave) });
+    add_attr("click", parse_quote! { event!(#krate::__element_macro::events::Click) });
+    add_attr("pointer_down", parse_quote! { event!(#krate::__element_macro::events::PointerDown) });
+    add_attr("pointer_move", parse_quote! { event!(#krate::__element_macro::events::PointerMove) });
+    add_attr("pointer_up", parse_quote! { event!(#krate::__element_macro::events::PointerUp) });
+    add_attr("pointer_cancel", parse_quote! { event!(#krate::__element_macro::events::PointerCancel) });
+    add_attr("key_down", parse_quote! { event!(#krate::__element_macro::events::KeyDown) });
+    add_attr("key_up", parse_quote! { event!(#krate::__element_macro::events::KeyUp) });
+    add_attr("focus", parse_quote! { event!(#krate::__element_macro::events::Focus) });
+    add_attr("blur", parse_quote! { event!(#krate::__element_macro::events::Blur) });
+    add_attr("focus_in", parse_quote! { event!(#krate::__element_macro::events::FocusIn) });
+    add_attr("focus_out", parse_quote! { event!(#krate::__element_macro::events::FocusOut) });
+    add_attr("tap", parse_quote! { event!(#krate::__element_macro::events::Tap) });
+    add_attr("long_tap", parse_quote! { event!(#krate::__element_macro::events::LongTap) });
+    add_attr("cancel_tap", parse_quote! { event!(#krate::__element_macro::events::CancelTap) });
+    add_attr("drag_start", parse_quote! { event!(#krate::__element_macro::events::DragStart) });
+    add_attr("drag_move", parse_quote! { event!(#krate::__element_macro::events::DragMove) });
+    add_attr("drag_end", parse_quote! { event!(#krate::__element_macro::events::DragEnd) });
+    add_attr("scroll", parse_quote! { event!(#krate::__element_macro::events::Scroll) });
+    add_attr("wheel", parse_quote! { event!(#krate::__element_macro::events::Wheel) });
+    add_attr("resize", parse_quote! { event!(#krate::__element_macro::events::Resize) });
+    add_attr("intersection", parse_quote! { event!(#krate::__element_macro::events::Intersection) });
+    add_attr("animation_start", parse_quote! { event!(#krate::__element_macro::events::AnimationStart) });
+    add_attr("animation_iteration", parse_quote! { event!(#krate::__element_macro::events::AnimationIteration) });
+    add_attr("animation_end", parse_quote! { event!(#krate::__element_macro::events::AnimationEnd) });
+    add_attr("animation_cancel", parse_quote! { event!(#krate::__element_macro::events::AnimationCancel) });
+    add_attr("transition_run", parse_quote! { event!(#krate::__element_macro::events::TransitionRun) });
+    add_attr("transition_start", parse_quote! { event!(#krate::__element_macro::events::TransitionStart) });
+    add_attr("transition_end", parse_quote! { event!(#krate::__element_macro::events::TransitionEnd) });
+    add_attr("transition_cancel", parse_quote! { event!(#krate::__element_macro::events::TransitionCancel) });
+    // the ARIA attributes are also available in SVG and MathML elements
+    add_attr("role", parse_quote! { attribute!(#krate::aria::Role) });
+    add_attr("tabindex", parse_quote! { attribute!(i32) });
+    add_attr("lang", parse_quote! { attribute!(&str) });
+    add_attr("aria_activedescendant", parse_quote! { attribute!(&str) });
+    add_attr("aria_atomic", parse_quote! { attribute!(bool) });
+    add_attr("aria_autocomplete", parse_quote! { attribute!(#krate::aria::AriaAutocomplete) });
+    add_attr("aria_braillelabel", parse_quote! { attribute!(LocaleStr) });
+    add_attr("aria_brailleroledescription", parse_quote! { attribute!(LocaleStr) });
+    add_attr("aria_busy", parse_quote! { attribute!(bool) });
+    add_attr("aria_checked", parse_quote! { attribute!(#krate::aria::AriaTristate) });
+    add_attr("aria_colcount", parse_quote! { attribute!(i32) });
+    add_attr("aria_colindex", parse_quote! { attribute!(i32) });
+    add_attr("aria_colindextext", parse_quote! { attribute!(&str) });
+    add_attr("aria_colspan", parse_quote! { attribute!(i32) });
+    add_attr("aria_controls", parse_quote! { attribute!(&str) });
+    add_attr("aria_current", parse_quote! { attribute!(#krate::aria::AriaCurrent) });
+    add_attr("aria_describedby", parse_quote! { attribute!(&str) });
+    add_attr("aria_description", parse_quote! { attribute!(LocaleStr) });
+    add_attr("aria_details", parse_quote! { attribute!(&str) });
+    add_attr("aria_disabled", parse_quote! { attribute!(bool) });
+    add_attr("aria_errormessage", parse_quote! { attribute!(&str) });
+    add_attr("aria_expanded", parse_quote! { attribute!(bool) });
+    add_attr("aria_flowto", parse_quote! { attribute!(&str) });
+    add_attr("aria_haspopup", parse_quote! { attribute!(#krate::aria::AriaHasPopup) });
+    add_attr("aria_hidden", parse_quote! { attribute!(bool) });
+    add_attr("aria_invalid", parse_quote! { attribute!(#krate::aria::AriaInvalid) });
+    add_attr("aria_keyshortcuts", parse_quote! { attribute!(&str) });
+    add_attr("aria_label", parse_quote! { attribute!(LocaleStr) });
+    add_attr("aria_labelledby", parse_quote! { attribute!(&str) });
+    add_attr("aria_level", parse_quote! { attribute!(i32) });
+    add_attr("aria_live", parse_quote! { attribute!(#krate::aria::AriaLive) });
+    add_attr("aria_modal", parse_quote! { attribute!(bool) });
+    add_attr("aria_multiline", parse_quote! { attribute!(bool) });
+    add_attr("aria_multiselectable", parse_quote! { attribute!(bool) });
+    add_attr("aria_orientation", parse_quote! { attribute!(#krate::aria::AriaOrientation) });
+    add_attr("aria_owns", parse_quote! { attribute!(&str) });
+    add_attr("aria_placeholder", parse_quote! { attribute!(LocaleStr) });
+    add_attr("aria_posinset", parse_quote! { attribute!(i32) });
+    add_attr("aria_pressed", parse_quote! { attribute!(#krate::aria::AriaTristate) });
+    add_attr("aria_readonly", parse_quote! { attribute!(bool) });
+    add_attr("aria_relevant", parse_quote! { attribute!(&str) });
+    add_attr("aria_required", parse_quote! { attribute!(bool) });
+    add_attr("aria_roledescription", parse_quote! { attribute!(LocaleStr) });
+    add_attr("aria_rowcount", parse_quote! { attribute!(i32) });
+    add_attr("aria_rowindex", parse_quote! { attribute!(i32) });
+    add_attr("aria_rowindextext", parse_quote! { attribute!(&str) });
+    add_attr("aria_rowspan", parse_quote! { attribute!(i32) });
+    add_attr("aria_selected", parse_quote! { attribute!(bool) });
+    add_attr("aria_setsize", parse_quote! { attribute!(i32) });
+    add_attr("aria_sort", parse_quote! { attribute!(#krate::aria::AriaSort) });
+    add_attr("aria_valuemax", parse_quote! { attribute!(f64) });
+    add_attr("aria_valuemin", parse_quote! { attribute!(f64) });
+    add_attr("aria_valuenow", parse_quote! { attribute!(f64) });
+    add_attr("aria_valuetext", parse_quote! { attribute!(LocaleStr) });
+}
+
+fn snake_to_camel_case(s: &str) -> String {
+    let mut ret = String::with_capacity(s.len());
+    let mut upper = false;
+    for c in s.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            upper = false;
+            ret.push(c.to_ascii_uppercase());
+        } else {
+            ret.push(c);
+        }
+    }
+    ret
 }
 
 enum Attr {
@@ -53,16 +175,18 @@ enum Attr {
         ty_name: Type,
         dom_element_name: Path,
         ty: Path,
+        attr_name: Option<LitStr>,
     },
     Raw {
         ty_name: Type,
         ty: Path,
+        attr_name: Option<LitStr>,
     },
     Binding {
         ty_name: Type,
         dom_element_name: Path,
         ty: Path,
-        event: LitStr,
+        events: Vec<LitStr>,
         cb: ExprClosure,
     },
 }
@@ -72,19 +196,31 @@ impl Parse for Attr {
         let ty_name: Type = input.parse()?;
         let span = ty_name.span();
         let s = ty_name.to_token_stream().to_string();
-        if input.is_empty() {
+        // the translated strings are set to DOM as `&str`
+        let ty_name = if s == "LocaleStr" {
+            parse_quote_spanned! {span=> &str }
+        } else {
+            ty_name
+        };
+        if input.is_empty() || input.peek(token::As) {
             let ty = match s.as_str() {
                 "& str" => parse_quote_spanned! {span=> DomStrAttr },
-                _ => {
-                    return Err(Error::new(span, "unknown raw attribute type"))
-                }
+                "LocaleStr" => parse_quote_spanned! {span=> DomLocaleStrAttr },
+                _ => parse_quote_spanned! {span=> DomTypedAttr::<#ty_name> },
             };
-            return Ok(Self::Raw { ty_name, ty });
+            let attr_name = if input.is_empty() {
+                None
+            } else {
+                let _: token::As = input.parse()?;
+                Some(input.parse()?)
+            };
+            return Ok(Self::Raw { ty_name, ty, attr_name });
         }
         let _: token::In = input.parse()?;
         let dom_element_name = input.parse()?;
         let ty = match s.as_str() {
             "& str" => parse_quote_spanned! {span=> DomStrAttr },
+            "LocaleStr" => parse_quote_spanned! {span=> DomLocaleStrAttr },
             "bool" => parse_quote_spanned! {span=> DomBoolAttr },
             "u32" => parse_quote_spanned! {span=> DomU32Attr },
             "i32" => parse_quote_spanned! {span=> DomI32Attr },
@@ -93,15 +229,26 @@ impl Parse for Attr {
                 return Err(Error::new(span, "unknown attribute type"))
             }
         };
-        if input.is_empty() {
+        if input.is_empty() || input.peek(token::As) {
+            let attr_name = if input.is_empty() {
+                None
+            } else {
+                let _: token::As = input.parse()?;
+                Some(input.parse()?)
+            };
             return Ok(Self::Normal {
                 ty_name,
                 dom_element_name,
                 ty,
+                attr_name,
             });
         }
         let _: token::While = input.parse()?;
-        let event = input.parse()?;
+        let mut events = vec![input.parse()?];
+        while input.peek(token::Comma) {
+            let _: token::Comma = input.parse()?;
+            events.push(input.parse()?);
+        }
         let cb = input.parse()?;
         let span = ty.span();
         let ty = match s.as_str() {
@@ -116,18 +263,29 @@ impl Parse for Attr {
             ty_name,
             dom_element_name,
             ty,
-            event,
+            events,
             cb,
         })
     }
 }
 
 impl Attr {
-    fn ty(&self) -> Path {
+    fn ty(&self, krate: &Path) -> Path {
+        let ty = match self {
+            Self::Normal { ty, .. } => ty,
+            Self::Raw { ty, .. } => ty,
+            Self::Binding { ty, .. } => ty,
+        };
+        parse_quote! { #krate::__element_macro::#ty }
+    }
+
+    // the raw attributes are named by the field name with `_` replaced by `-` by default
+    fn dom_attr_name(&self, field_name: &str) -> Option<String> {
         match self {
-            Self::Normal { ty, .. } => ty.clone(),
-            Self::Raw { ty, .. } => ty.clone(),
-            Self::Binding { ty, .. } => ty.clone(),
+            Self::Normal { attr_name: Some(x), .. } => Some(x.value()),
+            Self::Raw { attr_name: Some(x), .. } => Some(x.value()),
+            Self::Raw { attr_name: None, .. } => Some(field_name.replace('_', "-")),
+            _ => None,
         }
     }
 
@@ -140,12 +298,12 @@ impl Attr {
         match self {
             Self::Normal { ty_name, dom_element_name, .. } | Self::Binding { ty_name, dom_element_name, .. } => {
                 let span = field_name.span();
-                let dom_setter_name = Ident::new(&format!("dom_setter_{}_{}", tag_name.to_string(), field_name.to_string().trim_start_matches("r#")), span);
+                let dom_setter_name = Ident::new(&format!("dom_setter_{}_{}", tag_name.to_string().trim_start_matches("r#"), field_name.to_string().trim_start_matches("r#")), span);
                 let dom_element_fn_name = Ident::new(&format!("set_{}", field_name.to_string().trim_start_matches("r#")), span);
                 tokens.append_all(quote_spanned! {span=>
                     #[inline]
                     #[allow(non_snake_case)]
-                    fn #dom_setter_name(elem: &web_sys::HtmlElement, v: #ty_name) {
+                    fn #dom_setter_name(elem: &__web_sys::HtmlElement, v: #ty_name) {
                         #dom_element_name::#dom_element_fn_name(elem.unchecked_ref(), v.into());
                     }
                 });
@@ -153,15 +311,30 @@ impl Attr {
             }
             Self::Raw { ty_name, .. } => {
                 let span = field_name.span();
-                let field_name_str = field_name.to_string();
-                let dom_setter_name = Ident::new(&format!("dom_setter_{}_{}", tag_name.to_string(), field_name.to_string().trim_start_matches("r#")), span);
-                tokens.append_all(quote_spanned! {span=>
-                    #[inline]
-                    #[allow(non_snake_case)]
-                    fn #dom_setter_name(elem: &web_sys::HtmlElement, v: #ty_name) {
-                        elem.set_attribute(#field_name_str, v.into()).ok();
-                    }
-                });
+                let field_name_str = self
+                    .dom_attr_name(field_name.to_string().trim_start_matches("r#"))
+                    .unwrap();
+                let dom_setter_name = Ident::new(&format!("dom_setter_{}_{}", tag_name.to_string().trim_start_matches("r#"), field_name.to_string().trim_start_matches("r#")), span);
+                if ty_name.to_token_stream().to_string() == "& str" {
+                    tokens.append_all(quote_spanned! {span=>
+                        #[inline]
+                        #[allow(non_snake_case)]
+                        fn #dom_setter_name(elem: &__web_sys::HtmlElement, v: #ty_name) {
+                            elem.set_attribute(#field_name_str, v.into()).ok();
+                        }
+                    });
+                } else {
+                    tokens.append_all(quote_spanned! {span=>
+                        #[inline]
+                        #[allow(non_snake_case)]
+                        fn #dom_setter_name(elem: &__web_sys::HtmlElement, v: Option<&#ty_name>) {
+                            match v {
+                                Some(v) => elem.set_attribute(#field_name_str, &AttributeValue::attribute_value(v)).ok(),
+                                None => elem.remove_attribute(#field_name_str).ok(),
+                            };
+                        }
+                    });
+                }
                 dom_setter_name
             }
         }
@@ -169,41 +342,97 @@ impl Attr {
 }
 
 pub(crate) struct DomElementDefinitionAttribute {
-    // empty
+    namespace: Option<LitStr>,
 }
 
 impl Parse for DomElementDefinitionAttribute {
-    fn parse(_: ParseStream) -> Result<Self> {
-        Ok(Self {})
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.is_empty() {
+            return Ok(Self { namespace: None });
+        }
+        let name: Ident = input.parse()?;
+        if name != "namespace" {
+            return Err(Error::new(name.span(), "unknown option"));
+        }
+        let _: token::Eq = input.parse()?;
+        let namespace = input.parse()?;
+        Ok(Self { namespace: Some(namespace) })
+    }
+}
+
+pub(crate) struct CustomElementDefinitionAttribute {
+    tag: LitStr,
+}
+
+impl Parse for CustomElementDefinitionAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name: Ident = input.parse()?;
+        if name != "tag" {
+            return Err(Error::new(name.span(), "expected `tag` option"));
+        }
+        let _: token::Eq = input.parse()?;
+        let tag: LitStr = input.parse()?;
+        // the custom element names must contain a hyphen and start with a lower-case letter
+        let v = tag.value();
+        let valid = v.starts_with(|c: char| c.is_ascii_lowercase())
+            && v.contains('-')
+            && !v.contains(|c: char| c.is_ascii_uppercase() || c.is_whitespace() || "\"'<>/=".contains(c));
+        if !valid {
+            return Err(Error::new(tag.span(), "illegal custom element name"));
+        }
+        Ok(Self { tag })
     }
 }
 
 pub(crate) struct DomElementDefinition {
     s: ItemStruct,
+    krate: Path,
+    namespace: Option<LitStr>,
+    custom_tag: Option<LitStr>,
     attrs: Vec<(Ident, String, Attr)>,
     events: Vec<(Ident, String)>,
+    props: Vec<(Ident, String)>,
 }
 
-impl Parse for DomElementDefinition {
-    fn parse(input: ParseStream) -> Result<Self> {
-        let mut s: ItemStruct = input.parse()?;
+impl DomElementDefinition {
+    pub(crate) fn new(attr: DomElementDefinitionAttribute, s: ItemStruct) -> Result<Self> {
+        Self::new_with_crate(parse_quote! { crate }, attr.namespace, None, s)
+    }
+
+    pub(crate) fn new_custom(attr: CustomElementDefinitionAttribute, s: ItemStruct) -> Result<Self> {
+        Self::new_with_crate(parse_quote! { maomi_dom }, None, Some(attr.tag), s)
+    }
+
+    fn new_with_crate(
+        krate: Path,
+        namespace: Option<LitStr>,
+        custom_tag: Option<LitStr>,
+        mut s: ItemStruct,
+    ) -> Result<Self> {
         let mut attrs = vec![];
         let mut events = vec![];
+        let mut props = vec![];
         if let Fields::Named(fields) = &mut s.fields {
-            add_global_attrs(&mut fields.named);
+            add_global_attrs(&mut fields.named, namespace.as_ref(), &krate);
             for field in &mut fields.named {
                 if let Type::Macro(m) = field.ty.clone() {
                     let field_name = field.ident.clone().unwrap();
                     let attr_name = field_name.to_string().trim_start_matches("r#").to_string();
                     field.ident = Some(field_name.clone());
                     if m.mac.path.is_ident("attribute") {
+                        let tokens = m.mac.tokens.clone();
+                        let attr = Attr::parse.parse2(tokens)?;
+                        if custom_tag.is_some() {
+                            if let Attr::Binding { .. } = &attr {
+                                return Err(Error::new(m.mac.span(), "binding attributes are not supported in custom elements"));
+                            }
+                        }
+                        let attr_name = attr.dom_attr_name(&attr_name).unwrap_or(attr_name);
                         let field_doc_comment = format!(r#"The `{}` attribute."#, attr_name);
                         field.attrs.push(parse_quote! {
                             #[doc = #field_doc_comment]
                         });
-                        let tokens = m.mac.tokens.clone();
-                        let attr = Attr::parse.parse2(tokens)?;
-                        field.ty = Type::Path(TypePath { qself: None, path: attr.ty() });
+                        field.ty = Type::Path(TypePath { qself: None, path: attr.ty(&krate) });
                         attrs.push((field_name, attr_name, attr));
                     } else if m.mac.path.is_ident("event") {
                         let field_doc_comment = format!(r#"The `{}` event."#, attr_name.replace('_', ""));
@@ -214,10 +443,36 @@ impl Parse for DomElementDefinition {
                         let tokens = m.mac.tokens.clone();
                         let p = Path::parse.parse2(tokens)?;
                         let ty = parse_quote_spanned! {span=>
-                            DomEvent<#p>
+                            #krate::__element_macro::DomEvent<#p>
                         };
                         field.ty = Type::Path(ty);
                         events.push((field_name, attr_name));
+                    } else if m.mac.path.is_ident("property") {
+                        // the JS properties are named in camel case by default
+                        let tokens = m.mac.tokens.clone();
+                        let (ty, prop_name) = (|input: ParseStream| {
+                            let ty: Type = input.parse()?;
+                            let prop_name: Option<LitStr> = if input.is_empty() {
+                                None
+                            } else {
+                                let _: token::As = input.parse()?;
+                                Some(input.parse()?)
+                            };
+                            Ok((ty, prop_name))
+                        }).parse2(tokens)?;
+                        let prop_name = match prop_name {
+                            Some(x) => x.value(),
+                            None => snake_to_camel_case(&attr_name),
+                        };
+                        let field_doc_comment = format!(r#"The `{}` property."#, prop_name);
+                        field.attrs.push(parse_quote! {
+                            #[doc = #field_doc_comment]
+                        });
+                        let span = m.mac.span();
+                        field.ty = parse_quote_spanned! {span=>
+                            #krate::__element_macro::DomProperty<#ty>
+                        };
+                        props.push((field_name, prop_name));
                     } else {
                         return Err(Error::new(m.mac.span(), "unknown macro"))
                     }
@@ -238,7 +493,7 @@ impl Parse for DomElementDefinition {
                 vis: parse_quote! { pub },
                 ident: Some(Ident::new("class", span)),
                 colon_token: Default::default(),
-                ty: parse_quote! { DomClassList },
+                ty: parse_quote! { #krate::__element_macro::DomClassList },
             });
             fields.named.push(Field {
                 attrs: vec![parse_quote! {
@@ -247,22 +502,53 @@ impl Parse for DomElementDefinition {
                 vis: parse_quote! { pub },
                 ident: Some(Ident::new("style", span)),
                 colon_token: Default::default(),
-                ty: parse_quote! { DomStyleList },
+                ty: parse_quote! { #krate::__element_macro::DomStyleList },
+            });
+            // `dataset` and `attrs` are not HTML attributes, so they never collide with declared attributes,
+            // i.e. the `data:xxx=` shorthand still works with `<object data>`
+            for field in fields.named.iter() {
+                if let Some(x) = field.ident.as_ref() {
+                    if x == "dataset" || x == "attrs" {
+                        return Err(Error::new(x.span(), "`dataset` and `attrs` are reserved field names"));
+                    }
+                }
+            }
+            fields.named.push(Field {
+                attrs: vec![parse_quote! {
+                    #[doc = "The `data-*` attributes of the element."]
+                }],
+                vis: parse_quote! { pub },
+                ident: Some(Ident::new("dataset", span)),
+                colon_token: Default::default(),
+                ty: parse_quote! { #krate::__element_macro::DomDataList },
+            });
+            fields.named.push(Field {
+                attrs: vec![parse_quote! {
+                    #[doc = "The other attributes of the element which are not declared."]
+                }],
+                vis: parse_quote! { pub },
+                ident: Some(Ident::new("attrs", span)),
+                colon_token: Default::default(),
+                ty: parse_quote! { #krate::__element_macro::DomAttrList },
             });
             fields.named.push(Field {
                 attrs: Vec::with_capacity(0),
                 vis: Visibility::Inherited,
                 ident: Some(Ident::new("dom_elem_lazy", span)),
                 colon_token: Default::default(),
-                ty: parse_quote! { std::cell::UnsafeCell<dom_state_ty!(web_sys::Element, (), RematchedDomElem)> },
+                ty: parse_quote! { #krate::__element_macro::DomElementLazy },
             });
         } else {
             return Err(Error::new(s.span(), "expected named struct"));
         }
         Ok(Self {
             s,
+            krate,
+            namespace,
+            custom_tag,
             attrs,
             events,
+            props,
         })
     }
 }
@@ -270,19 +556,40 @@ impl Parse for DomElementDefinition {
 impl ToTokens for DomElementDefinition {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let s = &self.s;
+        let krate = &self.krate;
         let tag_name = &s.ident;
-        let tag_name_str = tag_name.to_string();
-        let struct_doc_comment = format!("The HTML `<{}>` element.", tag_name);
+        let tag_name_str = match &self.custom_tag {
+            Some(x) => x.value(),
+            None => tag_name.to_string().trim_start_matches("r#").to_string(),
+        };
+        let struct_doc_comment = match self.namespace.as_ref().map(|x| x.value()) {
+            None if self.custom_tag.is_some() => format!("The custom element `<{}>`.", tag_name_str),
+            None => format!("The HTML `<{}>` element.", tag_name_str),
+            Some(x) if x == SVG_NAMESPACE => format!("The SVG `<{}>` element.", tag_name_str),
+            Some(x) if x == MATHML_NAMESPACE => format!("The MathML `<{}>` element.", tag_name_str),
+            Some(x) => format!("The `<{}>` element in `{}` namespace.", tag_name_str, x),
+        };
+        let create_elem = match &self.namespace {
+            None => quote! {
+                tag_name.with(|m| create_dom_element(owner, m))
+            },
+            Some(ns) => quote! {
+                {
+                    thread_local! {
+                        static namespace: &'static MaybeJsStr = MaybeJsStr::new_leaked(#ns);
+                    }
+                    namespace.with(|ns| {
+                        tag_name.with(|m| create_dom_element_ns(owner, ns, m))
+                    })
+                }
+            },
+        };
+        let mut dom_setters = quote! {};
         let attrs_init = self.attrs.iter().map(|(field_name, attr_name, attr)| {
-            let dom_setter_name = attr.generate_dom_setter(tag_name, field_name, tokens);
-            let ty = attr.ty();
+            let dom_setter_name = attr.generate_dom_setter(tag_name, field_name, &mut dom_setters);
+            let ty = attr.ty(krate);
             quote! {
-                #field_name: #ty {
-                    inner: Default::default(),
-                    f: #dom_setter_name,
-                    #[cfg(feature = "prerendering")]
-                    attr_name: #attr_name,
-                },
+                #field_name: <#ty as DomAttrInit>::init(#dom_setter_name, #attr_name),
             }
         }).collect::<Box<_>>();
         let events_init = self.events.iter().map(|(ev, _)| {
@@ -290,18 +597,31 @@ impl ToTokens for DomElementDefinition {
                 #ev: Default::default(),
             }
         });
+        let props_init = self.props.iter().map(|(field_name, prop_name)| {
+            quote! {
+                #field_name: DomProperty::new(#prop_name),
+            }
+        });
         let binding_props_init = self.attrs.iter().filter_map(|(field_name, _attr_name, attr)| {
-            if let Attr::Binding { ty_name, event, cb, .. } = attr {
+            if let Attr::Binding { ty_name, events, cb, .. } = attr {
                 let span = ty_name.span();
-                Some(quote_spanned! {span=>
-                    let binding_value_rc = self.#field_name.inner.clone();
-                    let cb = #cb;
-                    init_binding_prop(dom_element, #event, move |ev: web_sys::Event| {
-                        if let Some(target) = ev.target() {
-                            let binding_value: &mut BindingValue<_> = &mut binding_value_rc.borrow_mut();
-                            cb(binding_value, ev.unchecked_ref(), target.unchecked_ref());
+                let listeners = events.iter().map(|event| {
+                    quote_spanned! {span=>
+                        {
+                            let binding_value_rc = self.#field_name.inner.clone();
+                            let cb = cb.clone();
+                            init_binding_prop(dom_element, #event, move |ev: __web_sys::Event| {
+                                if let Some(target) = ev.target() {
+                                    let binding_value: &mut maomi::prop::BindingValue<_> = &mut binding_value_rc.borrow_mut();
+                                    cb(binding_value, ev.unchecked_ref(), target.unchecked_ref());
+                                }
+                            });
                         }
-                    });
+                    }
+                });
+                Some(quote_spanned! {span=>
+                    let cb = std::rc::Rc::new(#cb);
+                    #(#listeners)*
                 })
             } else {
                 None
@@ -311,98 +631,104 @@ impl ToTokens for DomElementDefinition {
             #[doc = #struct_doc_comment]
             #[allow(non_camel_case_types)]
             #s
+        });
+        tokens.append_all(quote! {
+            const _: () = {
+                use #krate::__element_macro::*;
 
-            impl #tag_name {
-                #[inline]
-                fn init_binding_props(&mut self, dom_element: &mut DomElement) {
-                    #(#binding_props_init)*
-                }
-            }
+                #dom_setters
 
-            impl DomElementBase for #tag_name {
-                #[inline]
-                fn dom_element_lazy(&self) -> &std::cell::UnsafeCell<dom_state_ty!(web_sys::Element, (), RematchedDomElem)> {
-                    &self.dom_elem_lazy
+                impl #tag_name {
+                    #[inline]
+                    fn init_binding_props(&mut self, dom_element: &mut DomElement) {
+                        #(#binding_props_init)*
+                    }
                 }
-            }
 
-            impl BackendComponent<DomBackend> for #tag_name {
-                type SlotData = ();
-                type UpdateTarget = Self;
-                type UpdateContext = DomElement;
-            
-                #[inline]
-                fn init<'b>(
-                    _backend_context: &'b BackendContext<DomBackend>,
-                    owner: &'b mut ForestNodeMut<DomGeneralElement>,
-                    _owner_weak: &'b Box<dyn OwnerWeak>,
-                ) -> Result<(Self, ForestNodeRc<DomGeneralElement>), Error>
-                where
-                    Self: Sized,
-                {
-                    thread_local! {
-                        static tag_name: &'static MaybeJsStr = MaybeJsStr::new_leaked(#tag_name_str);
+                impl DomElementBase for #tag_name {
+                    #[inline]
+                    fn dom_element_lazy(&self) -> &DomElementLazy {
+                        &self.dom_elem_lazy
                     }
-                    let elem = tag_name.with(|m| owner.create_dom_element_by_tag_name(m));
-                    let backend_element = crate::DomGeneralElement::wrap_dom_element(owner, &elem);
-                    let this = Self {
-                        backend_element_token: backend_element.token(),
-                        class: DomClassList::new(match &elem {
-                            DomState::Normal(x) => DomState::Normal(x.class_list()),
-                            #[cfg(feature = "prerendering")]
-                            DomState::Prerendering(_) => DomState::Prerendering(()),
-                            #[cfg(feature = "prerendering-apply")]
-                            DomState::PrerenderingApply(_) => DomState::PrerenderingApply(()),
-                        }),
-                        style: DomStyleList::new(),
-                        #(#attrs_init)*
-                        #(#events_init)*
-                        dom_elem_lazy: std::cell::UnsafeCell::new(DomGeneralElement::to_lazy(elem)),
-                    };
-                    Ok((this, backend_element))
-                }
-            
-                #[inline]
-                fn create<'b>(
-                    &'b mut self,
-                    _backend_context: &'b BackendContext<DomBackend>,
-                    owner: &'b mut ForestNodeMut<DomGeneralElement>,
-                    update_fn: Box<dyn 'b + FnOnce(&mut Self, &mut Self::UpdateContext)>,
-                    slot_fn: &mut dyn FnMut(
-                        &mut ForestNodeMut<DomGeneralElement>,
-                        &ForestToken,
-                        &Self::SlotData,
-                    ) -> Result<(), Error>,
-                ) -> Result<(), Error> {
-                    let mut node = owner.borrow_mut_token(&self.backend_element_token).ok_or(Error::TreeNodeReleased)?;
-                    let dom_element = &mut DomGeneralElement::as_dom_element_mut(&mut node).unwrap();
-                    self.init_binding_props(dom_element);
-                    update_fn(self, dom_element);
-                    slot_fn(&mut node, &self.backend_element_token, &())?;
-                    Ok(())
                 }
-            
-                #[inline]
-                fn apply_updates<'b>(
-                    &'b mut self,
-                    _backend_context: &'b BackendContext<DomBackend>,
-                    owner: &'b mut ForestNodeMut<<DomBackend as maomi::backend::Backend>::GeneralElement>,
-                    update_fn: Box<dyn 'b + FnOnce(&mut Self, &mut Self::UpdateContext)>,
-                    slot_fn: &mut dyn FnMut(
-                        SlotChange<&mut ForestNodeMut<DomGeneralElement>, &ForestToken, &Self::SlotData>,
-                    ) -> Result<(), Error>,
-                ) -> Result<(), Error> {
-                    let mut node = owner.borrow_mut_token(&self.backend_element_token).ok_or(Error::TreeNodeReleased)?;
-                    update_fn(self, &mut DomGeneralElement::as_dom_element_mut(&mut node).unwrap());
-                    slot_fn(SlotChange::Unchanged(&mut node, &self.backend_element_token, &()))?;
-                    Ok(())
+
+                impl BackendComponent<DomBackend> for #tag_name {
+                    type SlotData = ();
+                    type UpdateTarget = Self;
+                    type UpdateContext = DomElement;
+
+                    #[inline]
+                    fn init<'b>(
+                        _backend_context: &'b BackendContext<DomBackend>,
+                        owner: &'b mut ForestNodeMut<DomGeneralElement>,
+                        _owner_weak: &'b Box<dyn OwnerWeak>,
+                    ) -> Result<(Self, ForestNodeRc<DomGeneralElement>), Error>
+                    where
+                        Self: Sized,
+                    {
+                        thread_local! {
+                            static tag_name: &'static MaybeJsStr = MaybeJsStr::new_leaked(#tag_name_str);
+                        }
+                        let elem = #create_elem;
+                        let (backend_element, class, dom_elem_lazy) = attach_dom_element(owner, elem);
+                        let this = Self {
+                            backend_element_token: backend_element.token(),
+                            class,
+                            style: DomStyleList::new(),
+                            dataset: DomDataList::new(),
+                            attrs: DomAttrList::new(),
+                            #(#attrs_init)*
+                            #(#events_init)*
+                            #(#props_init)*
+                            dom_elem_lazy,
+                        };
+                        Ok((this, backend_element))
+                    }
+
+                    #[inline]
+                    fn create<'b>(
+                        &'b mut self,
+                        _backend_context: &'b BackendContext<DomBackend>,
+                        owner: &'b mut ForestNodeMut<DomGeneralElement>,
+                        update_fn: Box<dyn 'b + FnOnce(&mut Self, &mut Self::UpdateContext)>,
+                        slot_fn: &mut dyn FnMut(
+                            &mut ForestNodeMut<DomGeneralElement>,
+                            &ForestToken,
+                            &Self::SlotData,
+                        ) -> Result<(), Error>,
+                    ) -> Result<(), Error> {
+                        let mut node = owner.borrow_mut_token(&self.backend_element_token).ok_or(Error::TreeNodeReleased)?;
+                        let mut dom_element = dom_element_mut(&mut node)?;
+                        let dom_element: &mut DomElement = &mut dom_element;
+                        self.init_binding_props(dom_element);
+                        update_fn(self, dom_element);
+                        slot_fn(&mut node, &self.backend_element_token, &())?;
+                        Ok(())
+                    }
+
+                    #[inline]
+                    fn apply_updates<'b>(
+                        &'b mut self,
+                        _backend_context: &'b BackendContext<DomBackend>,
+                        owner: &'b mut ForestNodeMut<<DomBackend as maomi::backend::Backend>::GeneralElement>,
+                        update_fn: Box<dyn 'b + FnOnce(&mut Self, &mut Self::UpdateContext)>,
+                        slot_fn: &mut dyn FnMut(
+                            SlotChange<&mut ForestNodeMut<DomGeneralElement>, &ForestToken, &Self::SlotData>,
+                        ) -> Result<(), Error>,
+                    ) -> Result<(), Error> {
+                        let mut node = owner.borrow_mut_token(&self.backend_element_token).ok_or(Error::TreeNodeReleased)?;
+                        let mut dom_element = dom_element_mut(&mut node)?;
+                        update_fn(self, &mut dom_element);
+                        slot_fn(SlotChange::Unchanged(&mut node, &self.backend_element_token, &()))?;
+                        Ok(())
+                    }
                 }
-            }
 
-            impl SupportBackend for #tag_name {
-                type Target = Self;
-                type SlotChildren = StaticSingleSlot<ForestTokenAddr, maomi::node::DynNodeList>;
-            }
+                impl SupportBackend for #tag_name {
+                    type Target = Self;
+                    type SlotChildren = StaticSingleSlot<ForestTokenAddr, maomi::node::DynNodeList>;
+                }
+            };
         });
     }
 }
@@ -6,23 +6,76 @@ use maomi_skin::style_sheet::StyleSheet;
 mod css;
 use css::DomStyleSheet;
 mod element;
-use element::{DomElementDefinition, DomElementDefinitionAttribute};
+use element::{CustomElementDefinitionAttribute, DomElementDefinition, DomElementDefinitionAttribute};
+
+struct StyleSheetInput {
+    css_text: Option<(syn::Visibility, syn::Ident)>,
+    ss: StyleSheet<DomStyleSheet>,
+}
+
+impl syn::parse::Parse for StyleSheetInput {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut css_text = None;
+        for attr in input.call(syn::Attribute::parse_inner)? {
+            if attr.path.is_ident("css_text") {
+                if css_text.is_some() {
+                    return Err(syn::Error::new_spanned(attr, "duplicated `css_text` attribute"));
+                }
+                css_text = Some(attr.parse_args_with(|input: syn::parse::ParseStream| {
+                    Ok((input.parse()?, input.parse()?))
+                })?);
+            } else {
+                return Err(syn::Error::new_spanned(attr, "unknown stylesheet attribute"));
+            }
+        }
+        let ss = input.parse()?;
+        Ok(Self { css_text, ss })
+    }
+}
 
 #[proc_macro]
 pub fn stylesheet(item: TokenStream) -> TokenStream {
-    let ss = syn::parse_macro_input!(item as StyleSheet<DomStyleSheet>);
-    quote::quote! {
-        #ss
+    let StyleSheetInput { css_text, ss } = syn::parse_macro_input!(item as StyleSheetInput);
+    match css_text {
+        None => quote::quote! {
+            #ss
+        }
+        .into(),
+        Some((vis, name)) => {
+            let mut tokens = proc_macro2::TokenStream::new();
+            let mut s = String::new();
+            ss.style_sheet_constructor().to_tokens_with_css_text(&ss, &mut tokens, Some(&mut s));
+            quote::quote! {
+                #tokens
+                #vis const #name: &'static str = #s;
+            }
+            .into()
+        }
     }
-    .into()
 }
 
 #[proc_macro_attribute]
 pub fn dom_element_definition(attr: TokenStream, item: TokenStream) -> TokenStream {
-    let _ = syn::parse_macro_input!(attr as DomElementDefinitionAttribute);
-    let def = syn::parse_macro_input!(item as DomElementDefinition);
-    quote::quote! {
-        #def
+    let attr = syn::parse_macro_input!(attr as DomElementDefinitionAttribute);
+    let s = syn::parse_macro_input!(item as syn::ItemStruct);
+    match DomElementDefinition::new(attr, s) {
+        Ok(def) => quote::quote! {
+            #def
+        }
+        .into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+#[proc_macro_attribute]
+pub fn custom_element_definition(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attr = syn::parse_macro_input!(attr as CustomElementDefinitionAttribute);
+    let s = syn::parse_macro_input!(item as syn::ItemStruct);
+    match DomElementDefinition::new_custom(attr, s) {
+        Ok(def) => quote::quote! {
+            #def
+        }
+        .into(),
+        Err(err) => err.to_compile_error().into(),
     }
-    .into()
 }
@@ -43,7 +43,7 @@ struct HelloWorld {
             "Hello world!"
         </div>
         // use { ... } bindings in the template
-        <div title={ self.hello.to_locale_str() }>
+        <div title={ &self.hello }>
             { &self.hello }
         </div>
         // use classes in `class:xxx` form
@@ -0,0 +1,241 @@
+//! The WAI-ARIA attribute values.
+//!
+//! The ARIA states and properties are available in all elements, i.e. `aria_expanded` `aria_live` .
+//! The enumerated ones accept the types in this module.
+//!
+//! ```rust
+//! use maomi::prelude::*;
+//! use maomi_dom::{prelude::*, element::*, aria::*};
+//!
+//! #[component(Backend = DomBackend)]
+//! struct MyComponent {
+//!     template: template! {
+//!         <div role=&{Role::Button} aria_expanded={&self.expanded} aria_live=&{AriaLive::Polite}>
+//!             "Toggle"
+//!         </div>
+//!     },
+//!     expanded: bool,
+//! }
+//!
+//! impl Component for MyComponent {
+//!     fn new() -> Self {
+//!         Self {
+//!             template: Default::default(),
+//!             expanded: false,
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! The list of the states and properties is found in [WAI-ARIA](https://www.w3.org/TR/wai-aria-1.2/#state_prop_def) .
+
+use std::borrow::Cow;
+
+use crate::base_element::AttributeValue;
+
+macro_rules! attribute_value_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($variant:ident = $value:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                #[doc = concat!("The `", $value, "` value.")]
+                $variant,
+            )*
+        }
+
+        impl $name {
+            /// Get the attribute value string.
+            #[inline]
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $value,)*
+                }
+            }
+        }
+
+        impl AttributeValue for $name {
+            #[inline]
+            fn attribute_value(&self) -> Cow<'static, str> {
+                Cow::Borrowed(self.as_str())
+            }
+        }
+    };
+}
+
+attribute_value_enum!(
+    /// The `aria-live` value.
+    AriaLive {
+        Off = "off",
+        Polite = "polite",
+        Assertive = "assertive",
+    }
+);
+
+attribute_value_enum!(
+    /// The value of the tristate attributes, i.e. `aria-checked` and `aria-pressed` .
+    AriaTristate {
+        False = "false",
+        True = "true",
+        Mixed = "mixed",
+    }
+);
+
+attribute_value_enum!(
+    /// The `aria-current` value.
+    AriaCurrent {
+        False = "false",
+        True = "true",
+        Page = "page",
+        Step = "step",
+        Location = "location",
+        Date = "date",
+        Time = "time",
+    }
+);
+
+attribute_value_enum!(
+    /// The `aria-haspopup` value.
+    AriaHasPopup {
+        False = "false",
+        True = "true",
+        Menu = "menu",
+        Listbox = "listbox",
+        Tree = "tree",
+        Grid = "grid",
+        Dialog = "dialog",
+    }
+);
+
+attribute_value_enum!(
+    /// The `aria-invalid` value.
+    AriaInvalid {
+        False = "false",
+        True = "true",
+        Grammar = "grammar",
+        Spelling = "spelling",
+    }
+);
+
+attribute_value_enum!(
+    /// The `aria-autocomplete` value.
+    AriaAutocomplete {
+        None = "none",
+        Inline = "inline",
+        List = "list",
+        Both = "both",
+    }
+);
+
+attribute_value_enum!(
+    /// The `aria-orientation` value.
+    AriaOrientation {
+        Horizontal = "horizontal",
+        Vertical = "vertical",
+        Undefined = "undefined",
+    }
+);
+
+attribute_value_enum!(
+    /// The `aria-sort` value.
+    AriaSort {
+        None = "none",
+        Ascending = "ascending",
+        Descending = "descending",
+        Other = "other",
+    }
+);
+
+attribute_value_enum!(
+    /// The `role` value.
+    ///
+    /// The abstract roles are not included since they should not be used in documents.
+    Role {
+        Alert = "alert",
+        AlertDialog = "alertdialog",
+        Application = "application",
+        Article = "article",
+        Banner = "banner",
+        Blockquote = "blockquote",
+        Button = "button",
+        Caption = "caption",
+        Cell = "cell",
+        Checkbox = "checkbox",
+        Code = "code",
+        ColumnHeader = "columnheader",
+        Combobox = "combobox",
+        Complementary = "complementary",
+        ContentInfo = "contentinfo",
+        Definition = "definition",
+        Deletion = "deletion",
+        Dialog = "dialog",
+        Document = "document",
+        Emphasis = "emphasis",
+        Feed = "feed",
+        Figure = "figure",
+        Form = "form",
+        Generic = "generic",
+        Grid = "grid",
+        GridCell = "gridcell",
+        Group = "group",
+        Heading = "heading",
+        Img = "img",
+        Insertion = "insertion",
+        Link = "link",
+        List = "list",
+        Listbox = "listbox",
+        ListItem = "listitem",
+        Log = "log",
+        Main = "main",
+        Marquee = "marquee",
+        Math = "math",
+        Menu = "menu",
+        Menubar = "menubar",
+        MenuItem = "menuitem",
+        MenuItemCheckbox = "menuitemcheckbox",
+        MenuItemRadio = "menuitemradio",
+        Meter = "meter",
+        Navigation = "navigation",
+        None = "none",
+        Note = "note",
+        Option = "option",
+        Paragraph = "paragraph",
+        Presentation = "presentation",
+        ProgressBar = "progressbar",
+        Radio = "radio",
+        RadioGroup = "radiogroup",
+        Region = "region",
+        Row = "row",
+        RowGroup = "rowgroup",
+        RowHeader = "rowheader",
+        Scrollbar = "scrollbar",
+        Search = "search",
+        Searchbox = "searchbox",
+        Separator = "separator",
+        Slider = "slider",
+        SpinButton = "spinbutton",
+        Status = "status",
+        Strong = "strong",
+        Subscript = "subscript",
+        Superscript = "superscript",
+        Switch = "switch",
+        Tab = "tab",
+        Table = "table",
+        TabList = "tablist",
+        TabPanel = "tabpanel",
+        Term = "term",
+        Textbox = "textbox",
+        Time = "time",
+        Timer = "timer",
+        Toolbar = "toolbar",
+        Tooltip = "tooltip",
+        Tree = "tree",
+        TreeGrid = "treegrid",
+        TreeItem = "treeitem",
+    }
+);
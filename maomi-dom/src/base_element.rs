@@ -2,10 +2,11 @@
 
 use maomi::{
     backend::tree::*,
-    prop::{PropertyUpdate, BindingValue},
+    locale_string::{LocaleStaticStr, LocaleString, ToLocaleStr},
+    prop::{PropertyUpdate, BindingValue, StaticStrPropertyUpdate, UntranslatedPropertyUpdate},
 };
 use std::{
-    borrow::Borrow,
+    borrow::{Borrow, Cow},
     mem::{ManuallyDrop, MaybeUninit},
     ops::Deref, rc::Rc, cell::RefCell, num::NonZeroUsize,
 };
@@ -26,18 +27,24 @@ extern "C" {
 }
 
 #[cfg(feature = "prerendering")]
+const XHTML_NAMESPACE: &str = "http://www.w3.org/1999/xhtml";
+
+#[cfg(feature = "prerendering")]
+#[doc(hidden)]
 #[derive(Debug, Clone)]
-pub(crate) struct PrerenderingElement {
+pub struct PrerenderingElement {
+    namespace: Option<&'static str>,
     tag_name: &'static str,
     classes: Vec<&'static str>,
     styles: Vec<(&'static str, String)>,
-    attrs: Vec<(&'static str, String)>,
+    attrs: Vec<(Cow<'static, str>, String)>,
 }
 
 #[cfg(feature = "prerendering")]
 impl PrerenderingElement {
     pub(crate) fn new(tag_name: &'static str) -> Self {
         Self {
+            namespace: None,
             tag_name,
             classes: vec![],
             styles: vec![],
@@ -45,7 +52,15 @@ impl PrerenderingElement {
         }
     }
 
-    pub(crate) fn set_attribute(&mut self, name: &'static str, value: String) {
+    pub(crate) fn new_ns(namespace: &'static str, tag_name: &'static str) -> Self {
+        Self {
+            namespace: Some(namespace),
+            ..Self::new(tag_name)
+        }
+    }
+
+    pub(crate) fn set_attribute(&mut self, name: impl Into<Cow<'static, str>>, value: String) {
+        let name = name.into();
         if let Some((_, v)) = self.attrs.iter_mut().find(|(n, _)| *n == name) {
             *v = value;
         } else {
@@ -53,7 +68,7 @@ impl PrerenderingElement {
         }
     }
 
-    pub(crate) fn remove_attribute(&mut self, name: &'static str) {
+    pub(crate) fn remove_attribute(&mut self, name: &str) {
         if let Some(index) = self.attrs.iter_mut().position(|(n, _)| *n == name) {
             self.attrs.swap_remove(index);
         }
@@ -105,8 +120,25 @@ impl PrerenderingElement {
         w: &mut impl std::io::Write,
         this: &ForestNode<DomGeneralElement>,
         state: &mut WriteHtmlState,
+    ) -> std::io::Result<()> {
+        self.write_start_tag(w, state)?;
+        self.write_children_html(w, this, state)?;
+        self.write_end_tag(w, state)
+    }
+
+    #[cfg(feature = "prerendering")]
+    pub(crate) fn write_start_tag(
+        &self,
+        w: &mut impl std::io::Write,
+        state: &mut WriteHtmlState,
     ) -> std::io::Result<()> {
         write!(w, "<{}", self.tag_name)?;
+        // the `xmlns` is needed when the namespace changes, i.e. the `<svg>` element in HTML
+        let parent_namespace = state.namespaces.last().cloned().flatten();
+        if self.namespace != parent_namespace {
+            write!(w, r#" xmlns="{}""#, self.namespace.unwrap_or(XHTML_NAMESPACE))?;
+        }
+        state.namespaces.push(self.namespace);
         let mut has_class = false;
         for c in &self.classes {
             if c.len() == 0 {
@@ -144,25 +176,70 @@ impl PrerenderingElement {
         }
         write!(w, ">")?;
         state.prev_is_text_node = false;
-        self.write_children_html(w, this, state)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "prerendering")]
+    pub(crate) fn write_end_tag(
+        &self,
+        w: &mut impl std::io::Write,
+        state: &mut WriteHtmlState,
+    ) -> std::io::Result<()> {
         write!(w, "</{}>", self.tag_name)?;
+        state.namespaces.pop();
         state.prev_is_text_node = false;
         Ok(())
     }
 }
 
 #[cfg(feature = "prerendering-apply")]
+#[doc(hidden)]
 #[derive(Clone)]
-pub(crate) struct RematchedDomElem {
+pub struct RematchedDomElem {
     inner: std::rc::Rc<std::cell::Cell<Option<web_sys::Element>>>,
+    namespace: Option<&'static str>,
+    tag_name: &'static str,
+    recorded: Rc<RefCell<Vec<Box<dyn FnOnce(&web_sys::Element)>>>>,
 }
 
 #[cfg(feature = "prerendering-apply")]
 impl RematchedDomElem {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(tag_name: &'static str) -> Self {
         Self {
             inner: Default::default(),
+            namespace: None,
+            tag_name,
+            recorded: Default::default(),
+        }
+    }
+
+    pub(crate) fn new_ns(namespace: &'static str, tag_name: &'static str) -> Self {
+        Self {
+            namespace: Some(namespace),
+            ..Self::new(tag_name)
+        }
+    }
+
+    pub(crate) fn tag_name(&self) -> &'static str {
+        self.tag_name
+    }
+
+    // the updates are recorded, so that the DOM element can be re-created if the prerendered one mismatched
+    pub(crate) fn record(&self, f: impl 'static + FnOnce(&web_sys::Element)) {
+        self.recorded.borrow_mut().push(Box::new(f));
+    }
+
+    pub(crate) fn create_dom(&self) -> web_sys::Element {
+        let e = crate::DOCUMENT
+            .with(|document| match self.namespace {
+                None => document.create_element(self.tag_name),
+                Some(ns) => document.create_element_ns(Some(ns), self.tag_name),
+            })
+            .unwrap();
+        for f in self.recorded.take() {
+            f(&e);
         }
+        e
     }
 
     pub(crate) fn set(&mut self, e: web_sys::Element) {
@@ -180,6 +257,8 @@ pub struct DomElement {
     pub(crate) forest_token: ManuallyDrop<ForestToken>,
     hot_event_list: Option<Box<HotEventList>>,
     cold_event_list: Option<Box<ColdEventList>>,
+    // the listeners of a mount target are released when the node is dropped
+    pub(crate) mount_target: Option<crate::mount_target::MountTargetGuard>,
 }
 
 impl Drop for DomElement {
@@ -196,6 +275,7 @@ impl Drop for DomElement {
                 DomState::PrerenderingApply(_) => {}
             }
             crate::event::tap::remove_element_touch_state(&self.forest_token);
+            crate::event::drag::remove_element_drag_state(&self.forest_token);
         }
         unsafe {
             ManuallyDrop::drop(&mut self.forest_token);
@@ -226,6 +306,7 @@ impl DomElement {
             forest_token: ManuallyDrop::new(x.assume_init()),
             hot_event_list: None,
             cold_event_list: None,
+            mount_target: None,
         }
     }
 
@@ -253,9 +334,18 @@ impl DomElement {
         }
     }
 
+    #[cfg(feature = "prerendering-apply")]
+    pub(crate) fn rematched_dom_elem(&self) -> Option<&RematchedDomElem> {
+        match &self.elem {
+            DomState::PrerenderingApply(x) => Some(x),
+            _ => None,
+        }
+    }
+
     #[cfg(feature = "prerendering-apply")]
     pub(crate) fn rematch_dom(&mut self, e: web_sys::Node) {
         if let DomState::PrerenderingApply(x) = &mut self.elem {
+            x.recorded.borrow_mut().clear();
             x.set(e.clone().unchecked_into());
         }
         for item in self.cold_event_list_mut() {
@@ -380,20 +470,118 @@ impl DomElement {
     }
 }
 
-pub(crate) trait DomElementBase {
-    fn dom_element_lazy(&self) -> &std::cell::UnsafeCell<dom_state_ty!(web_sys::Element, (), RematchedDomElem)>;
+#[doc(hidden)]
+pub struct DomElementLazy {
+    elem: std::cell::UnsafeCell<dom_state_ty!(web_sys::Element, (), RematchedDomElem)>,
+}
+
+impl DomElementLazy {
+    pub(crate) fn new(elem: dom_state_ty!(web_sys::Element, (), RematchedDomElem)) -> Self {
+        Self {
+            elem: std::cell::UnsafeCell::new(elem),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub trait DomElementBase {
+    fn dom_element_lazy(&self) -> &DomElementLazy;
+}
+
+#[doc(hidden)]
+pub trait DomAttrInit {
+    type Setter;
+
+    fn init(f: Self::Setter, attr_name: &'static str) -> Self;
+}
+
+macro_rules! dom_attr_init {
+    ($t:ty, $setter:ty) => {
+        impl DomAttrInit for $t {
+            type Setter = $setter;
+
+            #[inline]
+            fn init(f: Self::Setter, _attr_name: &'static str) -> Self {
+                Self {
+                    inner: Default::default(),
+                    f,
+                    #[cfg(feature = "prerendering")]
+                    attr_name: _attr_name,
+                }
+            }
+        }
+    };
+}
+
+dom_attr_init!(DomStrAttr, fn(&web_sys::HtmlElement, &str));
+dom_attr_init!(DomLocaleStrAttr, fn(&web_sys::HtmlElement, &str));
+dom_attr_init!(DomBoolAttr, fn(&web_sys::HtmlElement, bool));
+dom_attr_init!(DomU32Attr, fn(&web_sys::HtmlElement, u32));
+dom_attr_init!(DomI32Attr, fn(&web_sys::HtmlElement, i32));
+dom_attr_init!(DomF64Attr, fn(&web_sys::HtmlElement, f64));
+dom_attr_init!(DomBindingStrAttr, fn(&web_sys::HtmlElement, &str));
+dom_attr_init!(DomBindingBoolAttr, fn(&web_sys::HtmlElement, bool));
+dom_attr_init!(DomBindingF64Attr, fn(&web_sys::HtmlElement, f64));
+
+impl<T> DomAttrInit for DomTypedAttr<T> {
+    type Setter = fn(&web_sys::HtmlElement, Option<&T>);
+
+    #[inline]
+    fn init(f: Self::Setter, _attr_name: &'static str) -> Self {
+        Self {
+            inner: None,
+            f,
+            #[cfg(feature = "prerendering")]
+            attr_name: _attr_name,
+        }
+    }
 }
 
 /// Some helper functions for DOM elements.
 pub trait DomElementExt {
     /// Get the underlying DOM element.
     fn dom_element(&self) -> &web_sys::Element;
+
+    /// Move focus to the element.
+    ///
+    /// The element should be focusable, i.e. an `input` or an element with `tabindex` attribute.
+    #[inline]
+    fn focus(&self) {
+        crate::focus::focus(self.dom_element())
+    }
+
+    /// Remove focus from the element.
+    #[inline]
+    fn blur(&self) {
+        crate::focus::blur(self.dom_element())
+    }
+
+    /// Check whether the element is focused.
+    #[inline]
+    fn has_focus(&self) -> bool {
+        crate::focus::has_focus(self.dom_element())
+    }
+
+    /// Check whether the element or one of its descendants is focused.
+    #[inline]
+    fn contains_focus(&self) -> bool {
+        crate::focus::contains_focus(self.dom_element())
+    }
+
+    /// Trap the tab-order inside the element.
+    ///
+    /// The focus is moved into the element if it is outside.
+    /// The trap is released when the returned `FocusTrap` is dropped.
+    #[inline]
+    fn trap_focus(&self) -> crate::focus::FocusTrap {
+        crate::focus::FocusTrap::new(self.dom_element())
+    }
 }
 
 impl<T: DomElementBase> DomElementExt for T {
     #[inline]
     fn dom_element(&self) -> &web_sys::Element {
-        let ptr = self.dom_element_lazy().get();
+        let ptr = self.dom_element_lazy().elem.get();
         #[cfg(feature = "prerendering-apply")]
         if let DomState::PrerenderingApply(x) = unsafe { &*ptr } {
             // it is safe
@@ -454,11 +642,135 @@ where
                 x.set_attribute(dest.attr_name, dest.inner.clone());
             }
             #[cfg(feature = "prerendering-apply")]
-            DomState::PrerenderingApply(_) => {}
+            DomState::PrerenderingApply(x) => {
+                let f = dest.f;
+                let v = dest.inner.clone();
+                x.record(move |e| f(e.unchecked_ref(), &v));
+            }
+        }
+    }
+}
+
+/// The attributes that accepts a translated string.
+///
+/// When i18n is enabled, it only accepts `LocaleString` or `LocaleStaticStr` ,
+/// and the static strings in templates are translated at compile time.
+/// When i18n is disabled, it accepts any string.
+///
+/// When i18n is enabled, a static string in templates is set through `StaticStrPropertyUpdate` .
+/// For `title="Hello!"` , the template does the following if the translation is found.
+///
+/// ```rust
+/// use maomi::{locale_string::LocaleStaticStr, prop::StaticStrPropertyUpdate};
+/// use maomi_dom::base_element::{DomElement, DomLocaleStrAttr};
+///
+/// fn set_title(attr: &mut DomLocaleStrAttr, ctx: &mut DomElement) {
+///     let translated = LocaleStaticStr::translated("你好！");
+///     StaticStrPropertyUpdate::compare_and_set_static_str(attr, "Hello!", translated, ctx);
+/// }
+/// ```
+///
+/// If the translation is not found, the static string is rejected by the compiler.
+///
+/// ```compile_fail,E0308
+/// use maomi::{locale_string::LackTranslation, prop::StaticStrPropertyUpdate};
+/// use maomi_dom::base_element::{DomElement, DomLocaleStrAttr};
+///
+/// fn set_title(attr: &mut DomLocaleStrAttr, ctx: &mut DomElement) {
+///     let translated = LackTranslation;
+///     StaticStrPropertyUpdate::compare_and_set_static_str(attr, "Hello!", translated, ctx);
+/// }
+/// ```
+pub struct DomLocaleStrAttr {
+    pub(crate) inner: String,
+    pub(crate) f: fn(&web_sys::HtmlElement, &str),
+    #[cfg(feature = "prerendering")]
+    pub(crate) attr_name: &'static str,
+}
+
+impl Deref for DomLocaleStrAttr {
+    type Target = String;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl DomLocaleStrAttr {
+    fn compare_and_set_str(dest: &mut Self, src: &str, ctx: &mut DomElement) {
+        if dest.inner.as_str() == src {
+            return;
+        }
+        dest.inner = src.to_string();
+        match &mut ctx.elem {
+            DomState::Normal(x) => {
+                (dest.f)(x.unchecked_ref(), &dest.inner);
+            }
+            #[cfg(feature = "prerendering")]
+            DomState::Prerendering(x) => {
+                x.set_attribute(dest.attr_name, dest.inner.clone());
+            }
+            #[cfg(feature = "prerendering-apply")]
+            DomState::PrerenderingApply(x) => {
+                let f = dest.f;
+                let v = dest.inner.clone();
+                x.record(move |e| f(e.unchecked_ref(), &v));
+            }
         }
     }
 }
 
+impl PropertyUpdate<LocaleString> for DomLocaleStrAttr {
+    type UpdateContext = DomElement;
+
+    #[inline]
+    fn compare_and_set_ref(dest: &mut Self, src: &LocaleString, ctx: &mut DomElement) {
+        Self::compare_and_set_str(dest, src.to_locale_str(), ctx)
+    }
+}
+
+impl PropertyUpdate<LocaleStaticStr> for DomLocaleStrAttr {
+    type UpdateContext = DomElement;
+
+    #[inline]
+    fn compare_and_set_ref(dest: &mut Self, src: &LocaleStaticStr, ctx: &mut DomElement) {
+        Self::compare_and_set_str(dest, src.to_locale_str(), ctx)
+    }
+}
+
+impl StaticStrPropertyUpdate<LocaleStaticStr> for DomLocaleStrAttr {
+    type UpdateContext = DomElement;
+
+    #[inline]
+    fn compare_and_set_static_str(
+        dest: &mut Self,
+        _src: &'static str,
+        translated: LocaleStaticStr,
+        ctx: &mut DomElement,
+    ) {
+        Self::compare_and_set_str(dest, translated.to_locale_str(), ctx)
+    }
+}
+
+impl UntranslatedPropertyUpdate<str> for DomLocaleStrAttr {
+    type UpdateContext = DomElement;
+
+    #[inline]
+    fn compare_and_set_untranslated(dest: &mut Self, src: &str, ctx: &mut DomElement) {
+        Self::compare_and_set_str(dest, src, ctx)
+    }
+}
+
+impl UntranslatedPropertyUpdate<String> for DomLocaleStrAttr {
+    type UpdateContext = DomElement;
+
+    #[inline]
+    fn compare_and_set_untranslated(dest: &mut Self, src: &String, ctx: &mut DomElement) {
+        Self::compare_and_set_str(dest, src, ctx)
+    }
+}
+
 /// The attributes that accepts a boolean value.
 /// 
 /// The boolean attributes are always default to `false` .
@@ -504,7 +816,11 @@ where
                 }
             }
             #[cfg(feature = "prerendering-apply")]
-            DomState::PrerenderingApply(_) => {}
+            DomState::PrerenderingApply(x) => {
+                let f = dest.f;
+                let v = dest.inner;
+                x.record(move |e| f(e.unchecked_ref(), v));
+            }
         }
     }
 }
@@ -547,7 +863,11 @@ where
                 x.set_attribute(dest.attr_name, dest.inner.to_string());
             }
             #[cfg(feature = "prerendering-apply")]
-            DomState::PrerenderingApply(_) => {}
+            DomState::PrerenderingApply(x) => {
+                let f = dest.f;
+                let v = dest.inner;
+                x.record(move |e| f(e.unchecked_ref(), v));
+            }
         }
     }
 }
@@ -590,7 +910,11 @@ where
                 x.set_attribute(dest.attr_name, dest.inner.to_string());
             }
             #[cfg(feature = "prerendering-apply")]
-            DomState::PrerenderingApply(_) => {}
+            DomState::PrerenderingApply(x) => {
+                let f = dest.f;
+                let v = dest.inner;
+                x.record(move |e| f(e.unchecked_ref(), v));
+            }
         }
     }
 }
@@ -633,7 +957,120 @@ where
                 x.set_attribute(dest.attr_name, dest.inner.to_string());
             }
             #[cfg(feature = "prerendering-apply")]
-            DomState::PrerenderingApply(_) => {}
+            DomState::PrerenderingApply(x) => {
+                let f = dest.f;
+                let v = dest.inner;
+                x.record(move |e| f(e.unchecked_ref(), v));
+            }
+        }
+    }
+}
+
+/// A value that can be written as an attribute value string.
+///
+/// It is used by the typed raw attributes, i.e. the ARIA attributes.
+pub trait AttributeValue {
+    /// Get the attribute value string.
+    fn attribute_value(&self) -> Cow<'static, str>;
+}
+
+impl AttributeValue for bool {
+    #[inline]
+    fn attribute_value(&self) -> Cow<'static, str> {
+        Cow::Borrowed(if *self { "true" } else { "false" })
+    }
+}
+
+impl AttributeValue for i32 {
+    #[inline]
+    fn attribute_value(&self) -> Cow<'static, str> {
+        Cow::Owned(self.to_string())
+    }
+}
+
+impl AttributeValue for u32 {
+    #[inline]
+    fn attribute_value(&self) -> Cow<'static, str> {
+        Cow::Owned(self.to_string())
+    }
+}
+
+impl AttributeValue for f64 {
+    #[inline]
+    fn attribute_value(&self) -> Cow<'static, str> {
+        Cow::Owned(self.to_string())
+    }
+}
+
+/// A value that can be set to a `DomTypedAttr` .
+///
+/// It is either a value or an `Option` of the value, and `None` removes the attribute.
+pub trait TypedAttrValue<T> {
+    /// Get the value to set.
+    fn typed_attr_value(&self) -> Option<&T>;
+}
+
+impl<T: AttributeValue> TypedAttrValue<T> for T {
+    #[inline]
+    fn typed_attr_value(&self) -> Option<&T> {
+        Some(self)
+    }
+}
+
+impl<T: AttributeValue> TypedAttrValue<T> for Option<T> {
+    #[inline]
+    fn typed_attr_value(&self) -> Option<&T> {
+        self.as_ref()
+    }
+}
+
+/// The attributes that accepts a typed value.
+///
+/// The attribute is absent until the value is set,
+/// and it is removed when set with `None` .
+/// Different from `DomBoolAttr` , a `bool` value is written as `true` or `false` .
+pub struct DomTypedAttr<T> {
+    pub(crate) inner: Option<T>,
+    pub(crate) f: fn(&web_sys::HtmlElement, Option<&T>),
+    #[cfg(feature = "prerendering")]
+    pub(crate) attr_name: &'static str,
+}
+
+impl<T> Deref for DomTypedAttr<T> {
+    type Target = Option<T>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<T: 'static + Clone + PartialEq + AttributeValue, S: TypedAttrValue<T>> PropertyUpdate<S> for DomTypedAttr<T> {
+    type UpdateContext = DomElement;
+
+    #[inline]
+    fn compare_and_set_ref(dest: &mut Self, src: &S, ctx: &mut DomElement) {
+        let src = src.typed_attr_value();
+        if dest.inner.as_ref() == src {
+            return;
+        }
+        dest.inner = src.cloned();
+        let v = dest.inner.as_ref();
+        match &mut ctx.elem {
+            DomState::Normal(x) => {
+                (dest.f)(x.unchecked_ref(), v);
+            }
+            #[cfg(feature = "prerendering")]
+            DomState::Prerendering(x) => match v {
+                Some(v) => x.set_attribute(dest.attr_name, v.attribute_value().into_owned()),
+                None => x.remove_attribute(dest.attr_name),
+            },
+            #[cfg(feature = "prerendering-apply")]
+            DomState::PrerenderingApply(x) => {
+                let f = dest.f;
+                let v = v.cloned();
+                x.record(move |e| f(e.unchecked_ref(), v.as_ref()));
+            }
         }
     }
 }
@@ -685,7 +1122,11 @@ impl PropertyUpdate<BindingValue<String>> for DomBindingStrAttr {
                     x.set_attribute(dest.attr_name, inner.to_string());
                 }
                 #[cfg(feature = "prerendering-apply")]
-                DomState::PrerenderingApply(_) => {}
+                DomState::PrerenderingApply(x) => {
+                    let f = dest.f;
+                    let v = inner.clone();
+                    x.record(move |e| f(e.unchecked_ref(), &v));
+                }
             }
         });
     }
@@ -742,7 +1183,11 @@ impl PropertyUpdate<BindingValue<bool>> for DomBindingBoolAttr {
                     }
                 }
                 #[cfg(feature = "prerendering-apply")]
-                DomState::PrerenderingApply(_) => {}
+                DomState::PrerenderingApply(x) => {
+                    let f = dest.f;
+                    let v = *inner;
+                    x.record(move |e| f(e.unchecked_ref(), v));
+                }
             }
         });
     }
@@ -795,7 +1240,11 @@ impl PropertyUpdate<BindingValue<f64>> for DomBindingF64Attr {
                     x.set_attribute(dest.attr_name, inner.to_string());
                 }
                 #[cfg(feature = "prerendering-apply")]
-                DomState::PrerenderingApply(_) => {}
+                DomState::PrerenderingApply(x) => {
+                    let f = dest.f;
+                    let v = *inner;
+                    x.record(move |e| f(e.unchecked_ref(), v));
+                }
             }
         });
     }
@@ -8,6 +8,10 @@ use wasm_bindgen::JsCast;
 use web_sys::DomTokenList;
 
 use crate::{base_element::DomElement, DomState, MaybeJsStr};
+#[cfg(feature = "prerendering")]
+use crate::base_element::PrerenderingElement;
+#[cfg(feature = "prerendering-apply")]
+use crate::base_element::RematchedDomElem;
 
 #[wasm_bindgen]
 extern "C" {
@@ -53,7 +57,12 @@ fn toggle_class_name(class_list: &mut DomClassListTy, _class_name: &MaybeJsStr,
                     x.remove_class(_class_name.s);
                 }
             }
-            DomState::PrerenderingApply(_) => {}
+            DomState::PrerenderingApply(x) => {
+                let class_name = _class_name.s;
+                x.record(move |e| {
+                    e.class_list().toggle_with_force(class_name, _v).unwrap();
+                });
+            }
         },
     }
 }
@@ -70,7 +79,14 @@ enum DomClassItem {
 }
 
 impl DomClassList {
-    pub(crate) fn new(class_list: DomClassListTy) -> Self {
+    pub(crate) fn new(elem: &dom_state_ty!(web_sys::Element, PrerenderingElement, RematchedDomElem)) -> Self {
+        let class_list = match elem {
+            DomState::Normal(x) => DomState::Normal(x.class_list()),
+            #[cfg(feature = "prerendering")]
+            DomState::Prerendering(_) => DomState::Prerendering(()),
+            #[cfg(feature = "prerendering-apply")]
+            DomState::PrerenderingApply(_) => DomState::PrerenderingApply(()),
+        };
         Self {
             class_list,
             enabled: Box::new([]),
@@ -67,7 +67,15 @@ pub(crate) fn remove_all_children<'a>(
                         crate::log_js_error(&x);
                     });
             }
-            DomGeneralElement::Virtual(_) => {
+            DomGeneralElement::Virtual(x) => {
+                let parent: &web_sys::Node = if x.is_portal() {
+                    match x.portal_target() {
+                        Some(x) => x,
+                        None => return,
+                    }
+                } else {
+                    parent
+                };
                 let mut cur_option = n.first_child();
                 while let Some(cur) = cur_option {
                     rec(parent, &cur);
@@ -88,7 +96,10 @@ pub(crate) fn collect_child_frag<'a>(n: ForestNode<'a, DomGeneralElement>) -> Ch
             DomGeneralElement::Text(x) => {
                 return ret.add(&x.composing_dom());
             }
-            DomGeneralElement::Virtual(_) => {
+            DomGeneralElement::Virtual(x) => {
+                if x.is_portal() {
+                    return;
+                }
                 let mut cur_option = n.first_child();
                 while let Some(cur) = cur_option {
                     rec(cur.clone(), ret);
@@ -116,7 +127,10 @@ pub(crate) fn find_nearest_dom_ancestor<'a>(
                 DomGeneralElement::Text(x) => {
                     return Some(x.composing_dom().clone());
                 }
-                DomGeneralElement::Virtual(_) => {
+                DomGeneralElement::Virtual(x) => {
+                    if x.is_portal() {
+                        return x.portal_target().map(|x| x.clone().into());
+                    }
                     if let Some(x) = cur.parent_rc() {
                         x
                     } else {
@@ -138,7 +152,10 @@ fn find_first_dom_child<'a>(parent: ForestNode<'a, DomGeneralElement>) -> Option
         DomGeneralElement::Text(x) => {
             return Some(x.composing_dom().clone());
         }
-        DomGeneralElement::Virtual(_) => {
+        DomGeneralElement::Virtual(x) => {
+            if x.is_portal() {
+                return None;
+            }
             let mut cur_option = parent.first_child();
             while let Some(cur) = cur_option {
                 if let Some(x) = find_first_dom_child(cur.clone()) {
@@ -168,7 +185,11 @@ pub(crate) fn find_next_dom_sibling<'a>(
                     DomGeneralElement::Element(_) | DomGeneralElement::Text(_) => {
                         break;
                     }
-                    DomGeneralElement::Virtual(_) => {}
+                    DomGeneralElement::Virtual(x) => {
+                        if x.is_portal() {
+                            break;
+                        }
+                    }
                 }
                 parent
             } else {
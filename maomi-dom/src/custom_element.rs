@@ -0,0 +1,465 @@
+//! Custom elements (a.k.a. web components) interop.
+//!
+//! ### Using Custom Elements
+//!
+//! A third-party custom element can be declared with `#[custom_element_definition]` ,
+//! and then it can be used in templates like other elements.
+//! The fields are declared in the same way as the built-in elements:
+//! * `attribute!(T)` is set as a DOM attribute (`T` can be `&str` or an `AttributeValue` );
+//! * `property!(T)` is set as a JS property (`T` should be convertible into `JsValue` );
+//! * `event!(CustomDomEvent<E>)` listens the DOM event named `E::NAME` .
+//!
+//! The attributes are named by the field name with `_` replaced by `-` ,
+//! while the properties are named in camel case.
+//! Both can be renamed with `as "name"` .
+//!
+//! ```rust
+//! use maomi::prelude::*;
+//! use maomi_dom::{prelude::*, element::*, event::*, custom_element::*};
+//!
+//! pub struct ValueChanged;
+//!
+//! impl CustomEventType for ValueChanged {
+//!     const NAME: &'static str = "value-changed";
+//! }
+//!
+//! #[custom_element_definition(tag = "x-slider")]
+//! pub struct x_slider {
+//!     pub label: attribute!(&str),
+//!     pub max_value: property!(f64),
+//!     pub value_changed: event!(CustomDomEvent<ValueChanged>),
+//! }
+//!
+//! #[component(Backend = DomBackend)]
+//! struct MyComponent {
+//!     template: template! {
+//!         <x_slider label="Volume" max_value=&{ 100. } value_changed=@changed() />
+//!     },
+//! }
+//!
+//! impl Component for MyComponent {
+//!     fn new() -> Self {
+//!         Self {
+//!             template: Default::default(),
+//!         }
+//!     }
+//! }
+//!
+//! impl MyComponent {
+//!     fn changed(_this: ComponentRc<Self>, detail: &mut CustomEvent) {
+//!         let _value = detail.detail().as_f64();
+//!     }
+//! }
+//! ```
+//!
+//! ### Exporting Components
+//!
+//! A component which implements `CustomElementComponent` can be registered as a custom element
+//! with `define_custom_element` .
+//! The attributes listed in `CustomElementComponent::attributes` are mapped onto the `Prop` s,
+//! and other observed attributes are provided to `CustomElementComponent::attribute_changed` .
+//! The events binded in `CustomElementComponent::dispatch_events` are dispatched as DOM `CustomEvent` s.
+//!
+//! ```rust
+//! use maomi::{prelude::*, prop::PropertyUpdate};
+//! use maomi_dom::{prelude::*, element::*, custom_element::*};
+//!
+//! #[component(Backend = DomBackend)]
+//! struct MyCounter {
+//!     template: template! {
+//!         <button click=@clicked()>{ &self.count.to_string() }</button>
+//!     },
+//!     count: Prop<i32>,
+//!     changed: Event<i32>,
+//! }
+//!
+//! impl Component for MyCounter {
+//!     fn new() -> Self {
+//!         Self {
+//!             template: Default::default(),
+//!             count: Prop::new(0),
+//!             changed: Event::new(),
+//!         }
+//!     }
+//! }
+//!
+//! impl MyCounter {
+//!     fn clicked(this: ComponentRc<Self>, _detail: &mut maomi_dom::event::MouseEvent) {
+//!         this.task(|this| {
+//!             let count = *this.count + 1;
+//!             PropertyUpdate::compare_and_set_ref(&mut this.count, &count, &mut false);
+//!             this.changed.trigger(&mut count.clone());
+//!         });
+//!     }
+//! }
+//!
+//! impl CustomElementComponent for MyCounter {
+//!     fn attributes(attrs: &mut CustomElementAttributes<Self>) {
+//!         // the `count` attribute is parsed and set to `self.count`
+//!         attrs.prop("count", |this| &mut this.count);
+//!     }
+//!
+//!     fn dispatch_events(&mut self, dispatcher: &CustomEventDispatcher) {
+//!         dispatcher.bind(&mut self.changed, "count-changed");
+//!     }
+//! }
+//!
+//! fn register() {
+//!     define_custom_element::<MyCounter>("my-counter").unwrap();
+//! }
+//! ```
+
+use maomi::{
+    backend::context::EnteredBackendContext,
+    component::Component,
+    error::Error,
+    event::{Event, EventHandler},
+    mount_point::MountPoint,
+    prop::{Prop, PropertyUpdate},
+    template::ComponentTemplate,
+    BackendContext,
+};
+pub use maomi_dom_macro::custom_element_definition;
+use std::{borrow::Borrow, cell::RefCell, rc::Rc, str::FromStr};
+use wasm_bindgen::{prelude::*, JsCast};
+
+use crate::{base_element::DomElement, DomBackend, DomState};
+
+fn set_property(elem: &web_sys::Element, prop_name: &str, v: &JsValue) {
+    if let Err(err) = js_sys::Reflect::set(elem, &JsValue::from_str(prop_name), v) {
+        crate::log_js_error(&err);
+    }
+}
+
+/// A JS property of a custom element.
+///
+/// The properties are not written in prerendering,
+/// since they cannot be represented in HTML.
+pub struct DomProperty<T> {
+    inner: Option<T>,
+    prop_name: &'static str,
+}
+
+impl<T> DomProperty<T> {
+    #[doc(hidden)]
+    pub fn new(prop_name: &'static str) -> Self {
+        Self {
+            inner: None,
+            prop_name,
+        }
+    }
+
+    /// Get the value set by templates.
+    #[inline]
+    pub fn get(&self) -> Option<&T> {
+        self.inner.as_ref()
+    }
+}
+
+impl<S: ?Sized + PartialEq + ToOwned<Owned = T>, T: 'static + Borrow<S> + Into<JsValue> + Clone> PropertyUpdate<S>
+    for DomProperty<T>
+{
+    type UpdateContext = DomElement;
+
+    #[inline]
+    fn compare_and_set_ref(dest: &mut Self, src: &S, ctx: &mut DomElement) {
+        if let Some(x) = &dest.inner {
+            if x.borrow() == src {
+                return;
+            }
+        }
+        let v = src.to_owned();
+        dest.inner = Some(v.clone());
+        match &mut ctx.elem {
+            DomState::Normal(x) => {
+                set_property(x, dest.prop_name, &v.into());
+            }
+            #[cfg(feature = "prerendering")]
+            DomState::Prerendering(_) => {}
+            #[cfg(feature = "prerendering-apply")]
+            DomState::PrerenderingApply(x) => {
+                let prop_name = dest.prop_name;
+                x.record(move |e| set_property(e, prop_name, &v.into()));
+            }
+        }
+    }
+}
+
+#[wasm_bindgen(inline_js = r#"
+export function define_custom_element(name, observed, connected) {
+    class MaomiCustomElement extends HTMLElement {
+        static get observedAttributes() {
+            return observed;
+        }
+        connectedCallback() {
+            if (!this.__maomi) this.__maomi = connected(this);
+        }
+        disconnectedCallback() {
+            const m = this.__maomi;
+            this.__maomi = null;
+            if (m) m.disconnected();
+        }
+        attributeChangedCallback(name, _oldValue, newValue) {
+            if (this.__maomi) this.__maomi.attributeChanged(name, newValue);
+        }
+    }
+    customElements.define(name, MaomiCustomElement);
+}
+"#)]
+extern "C" {
+    #[wasm_bindgen(catch, js_name = define_custom_element)]
+    fn js_define_custom_element(
+        name: &str,
+        observed: js_sys::Array,
+        connected: &JsValue,
+    ) -> Result<(), JsValue>;
+}
+
+/// The attributes of a custom element which are mapped onto the `Prop` s of the component.
+pub struct CustomElementAttributes<C> {
+    list: Vec<(&'static str, Box<dyn Fn(&mut C, Option<&str>)>)>,
+}
+
+impl<C: 'static> CustomElementAttributes<C> {
+    fn collect() -> Self
+    where
+        C: CustomElementComponent,
+    {
+        let mut ret = Self { list: vec![] };
+        C::attributes(&mut ret);
+        ret
+    }
+
+    /// Map the attribute `name` onto a `Prop` .
+    ///
+    /// The attribute value is parsed with `FromStr` .
+    /// The `Prop` is set to the default value if the attribute is removed or cannot be parsed.
+    pub fn prop<T: 'static + FromStr + Default + PartialEq + Clone>(
+        &mut self,
+        name: &'static str,
+        field: fn(&mut C) -> &mut Prop<T>,
+    ) -> &mut Self {
+        self.list.push((
+            name,
+            Box::new(move |comp, value| {
+                let v: T = value.and_then(|x| x.parse().ok()).unwrap_or_default();
+                PropertyUpdate::compare_and_set_ref(field(comp), &v, &mut false);
+            }),
+        ));
+        self
+    }
+
+    fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.list.iter().map(|(name, _)| *name)
+    }
+
+    fn set(&self, comp: &mut C, name: &str, value: Option<&str>) -> bool {
+        match self.list.iter().find(|(x, _)| *x == name) {
+            Some((_, f)) => {
+                f(comp, value);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// A component that can be registered as a custom element.
+pub trait CustomElementComponent: Component + ComponentTemplate<DomBackend> + 'static {
+    /// List the attributes which are mapped onto the `Prop` s.
+    ///
+    /// These attributes are observed and never provided to `attribute_changed` .
+    fn attributes(_attrs: &mut CustomElementAttributes<Self>)
+    where
+        Self: Sized,
+    {
+    }
+
+    /// The other attribute names which should be provided to `attribute_changed` .
+    fn observed_attributes() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Handles the changes of the observed attributes.
+    ///
+    /// It is also called for each observed attribute when the element is connected.
+    /// The `value` is `None` if the attribute is removed.
+    /// The template is always updated after this call.
+    fn attribute_changed(&mut self, _name: &str, _value: Option<&str>) {}
+
+    /// Bind the events that should be dispatched as DOM `CustomEvent` s.
+    ///
+    /// It is called once when the element is connected.
+    fn dispatch_events(&mut self, _dispatcher: &CustomEventDispatcher) {}
+}
+
+/// The helper for dispatching component events as DOM `CustomEvent` s.
+pub struct CustomEventDispatcher {
+    host: web_sys::Element,
+}
+
+impl CustomEventDispatcher {
+    /// Get the custom element.
+    #[inline]
+    pub fn host(&self) -> &web_sys::Element {
+        &self.host
+    }
+
+    /// Dispatch a `CustomEvent` named `name` when `event` is triggered.
+    ///
+    /// The event detail is converted into the `detail` of the `CustomEvent` .
+    pub fn bind<D: Clone + Into<JsValue>>(&self, event: &mut Event<D>, name: &'static str) {
+        self.bind_with(event, name, |x| x.clone().into())
+    }
+
+    /// Dispatch a `CustomEvent` named `name` when `event` is triggered, with a `detail` converter.
+    pub fn bind_with<D>(
+        &self,
+        event: &mut Event<D>,
+        name: &'static str,
+        f: impl 'static + Fn(&D) -> JsValue,
+    ) {
+        let host = self.host.clone();
+        EventHandler::set_handler_fn(
+            event,
+            Box::new(move |detail: &mut D| {
+                let init = web_sys::CustomEventInit::new();
+                init.set_bubbles(true);
+                init.set_composed(true);
+                init.set_detail(&f(detail));
+                match web_sys::CustomEvent::new_with_event_init_dict(name, &init) {
+                    Ok(ev) => {
+                        if let Err(err) = host.dispatch_event(&ev) {
+                            crate::log_js_error(&err);
+                        }
+                    }
+                    Err(err) => crate::log_js_error(&err),
+                }
+            }),
+            &mut false,
+        );
+    }
+}
+
+struct MountedCustomElement<C: CustomElementComponent> {
+    backend_context: BackendContext<DomBackend>,
+    mount_point: MountPoint<DomBackend, C>,
+}
+
+fn attribute_changed<C: CustomElementComponent>(
+    comp: &mut C,
+    attrs: &CustomElementAttributes<C>,
+    name: &str,
+    value: Option<&str>,
+) {
+    if !attrs.set(comp, name, value) {
+        comp.attribute_changed(name, value);
+    }
+}
+
+fn connect<C: CustomElementComponent>(
+    host: web_sys::Element,
+    attrs: Rc<CustomElementAttributes<C>>,
+) -> Result<JsValue, Error> {
+    let backend_context = BackendContext::new(DomBackend::new_with_element(host.clone())?);
+    let values: Vec<_> = attrs
+        .names()
+        .chain(C::observed_attributes().iter().copied())
+        .map(|name| (name, host.get_attribute(name)))
+        .collect();
+    let dispatcher = CustomEventDispatcher { host };
+    let init_attrs = attrs.clone();
+    let mount_point = backend_context
+        .enter_sync(move |ctx: &mut EnteredBackendContext<DomBackend>| {
+            ctx.attach(|comp: &mut C| {
+                for (name, value) in values {
+                    attribute_changed(comp, &init_attrs, name, value.as_deref());
+                }
+                comp.dispatch_events(&dispatcher);
+            })
+        })
+        .map_err(|_| Error::AlreadyEntered)??;
+    let mounted = Rc::new(RefCell::new(Some(MountedCustomElement {
+        backend_context,
+        mount_point,
+    })));
+    let handle = js_sys::Object::new();
+    let m = mounted.clone();
+    let disconnected = Closure::<dyn Fn()>::new(move || {
+        if let Some(m) = m.borrow_mut().take() {
+            let MountedCustomElement {
+                backend_context,
+                mut mount_point,
+            } = m;
+            let detach = move |ctx: &mut EnteredBackendContext<DomBackend>| {
+                ctx.detach(&mut mount_point);
+            };
+            // the backend context may be visited by other tasks, so wait if so
+            if let Err(detach) = backend_context.enter_sync(detach) {
+                crate::async_task(async move {
+                    backend_context.enter(detach).await;
+                });
+            }
+        }
+    });
+    let m = mounted;
+    let attribute_changed = Closure::<dyn Fn(String, Option<String>)>::new(move |name: String, value: Option<String>| {
+        if let Some(m) = &*RefCell::borrow(&m) {
+            let attrs = attrs.clone();
+            m.mount_point.root_component().rc().task(move |comp: &mut C| {
+                attribute_changed(comp, &attrs, &name, value.as_deref());
+            });
+        }
+    });
+    let set_handle_fn = |name: &str, f: JsValue| {
+        js_sys::Reflect::set(&handle, &JsValue::from_str(name), &f).map_err(|_| Error::BackendError {
+            msg: "Cannot create the custom element handle".into(),
+            err: None,
+        })
+    };
+    set_handle_fn("disconnected", disconnected.into_js_value())?;
+    set_handle_fn("attributeChanged", attribute_changed.into_js_value())?;
+    Ok(handle.into())
+}
+
+/// Register a component as a custom element.
+///
+/// When the custom element is connected, the component is attached under it
+/// (with a standalone `BackendContext` ),
+/// and it is detached when the custom element is disconnected.
+/// The `name` must be a valid custom element name, i.e. contains a `-` .
+/// An error is returned if the `name` has already been defined.
+pub fn define_custom_element<C: CustomElementComponent>(name: &str) -> Result<(), Error> {
+    if !name.contains('-') {
+        return Err(Error::BackendError {
+            msg: format!("Illegal custom element name {:?}", name),
+            err: None,
+        });
+    }
+    let attrs = Rc::new(CustomElementAttributes::<C>::collect());
+    let observed: js_sys::Array = attrs
+        .names()
+        .chain(C::observed_attributes().iter().copied())
+        .map(JsValue::from_str)
+        .collect();
+    let connected = Closure::<dyn Fn(web_sys::Element) -> JsValue>::new(move |host: web_sys::Element| {
+        match connect::<C>(host, attrs.clone()) {
+            Ok(x) => x,
+            Err(err) => {
+                log::error!("Failed connecting custom element: {}", err);
+                JsValue::NULL
+            }
+        }
+    });
+    js_define_custom_element(name, observed, connected.as_ref().unchecked_ref())
+        .map_err(|err| {
+            crate::log_js_error(&err);
+            Error::BackendError {
+                msg: format!("Failed defining custom element {:?}", name),
+                err: None,
+            }
+        })?;
+    // the definition cannot be removed, so the closure is never dropped
+    connected.forget();
+    Ok(())
+}
@@ -0,0 +1,258 @@
+//! The utilities for DOM `data-*` attributes and other arbitrary attributes handling.
+
+use maomi::prop::{ListPropertyInit, NamedListPropertyUpdate};
+use std::borrow::Cow;
+
+use crate::{
+    base_element::{AttributeValue, DomElement},
+    DomState,
+};
+
+// the template macro rejects illegal names, but the list can also be updated without templates
+fn is_valid_attr_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control() || matches!(c, '"' | '\'' | '>' | '/' | '=' | '<'))
+}
+
+fn set_attribute(attr_name: &str, value: Option<&str>, ctx: &mut DomElement) {
+    match &mut ctx.elem {
+        DomState::Normal(x) => {
+            let ret = match value {
+                Some(value) => x.set_attribute(attr_name, value),
+                None => x.remove_attribute(attr_name),
+            };
+            if let Err(err) = ret {
+                crate::log_js_error(&err);
+            }
+        }
+        #[cfg(feature = "prerendering")]
+        DomState::Prerendering(x) => match value {
+            Some(value) => x.set_attribute(Cow::Owned(attr_name.to_string()), value.to_string()),
+            None => x.remove_attribute(attr_name),
+        },
+        #[cfg(feature = "prerendering-apply")]
+        DomState::PrerenderingApply(x) => {
+            let attr_name = attr_name.to_string();
+            let value = value.map(|x| x.to_string());
+            x.record(move |e| {
+                let ret = match value {
+                    Some(value) => e.set_attribute(&attr_name, &value),
+                    None => e.remove_attribute(&attr_name),
+                };
+                if let Err(err) = ret {
+                    crate::log_js_error(&err);
+                }
+            });
+        }
+    }
+}
+
+/// A value that can be set to a `DomDataList` or a `DomAttrList` item.
+///
+/// `None` removes the attribute.
+pub trait NamedAttrValue {
+    /// Get the attribute value string.
+    fn named_attr_value(&self) -> Option<Cow<'_, str>>;
+}
+
+impl NamedAttrValue for str {
+    #[inline]
+    fn named_attr_value(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::Borrowed(self))
+    }
+}
+
+impl NamedAttrValue for String {
+    #[inline]
+    fn named_attr_value(&self) -> Option<Cow<'_, str>> {
+        Some(Cow::Borrowed(self))
+    }
+}
+
+impl<T: AttributeValue> NamedAttrValue for T {
+    #[inline]
+    fn named_attr_value(&self) -> Option<Cow<'_, str>> {
+        Some(self.attribute_value())
+    }
+}
+
+impl<T: NamedAttrValue> NamedAttrValue for Option<T> {
+    #[inline]
+    fn named_attr_value(&self) -> Option<Cow<'_, str>> {
+        self.as_ref().and_then(|x| x.named_attr_value())
+    }
+}
+
+#[derive(Default)]
+struct NamedAttrItem {
+    attr_name: Option<String>,
+    value: Option<String>,
+}
+
+struct NamedAttrList {
+    prefix: &'static str,
+    items: Box<[NamedAttrItem]>,
+}
+
+impl NamedAttrList {
+    fn new(prefix: &'static str) -> Self {
+        Self {
+            prefix,
+            items: Box::new([]),
+        }
+    }
+
+    fn get(&self, name: &str) -> Option<&str> {
+        self.items
+            .iter()
+            .find(|x| {
+                x.attr_name
+                    .as_deref()
+                    .and_then(|x| x.strip_prefix(self.prefix))
+                    == Some(name)
+            })
+            .and_then(|x| x.value.as_deref())
+    }
+
+    fn init_list(&mut self, count: usize) {
+        let mut v = Vec::with_capacity(count);
+        v.resize_with(count, Default::default);
+        self.items = v.into_boxed_slice();
+    }
+
+    fn set_item(&mut self, index: usize, name: &'static str, value: Option<&str>, ctx: &mut DomElement) {
+        let item = &mut self.items[index];
+        if item.value.as_deref() == value {
+            return;
+        }
+        let attr_name = match &item.attr_name {
+            Some(x) => x,
+            None => {
+                if !is_valid_attr_name(name) {
+                    log::error!("Illegal attribute name {:?}", name);
+                    return;
+                }
+                item.attr_name.insert(format!("{}{}", self.prefix, name))
+            }
+        };
+        set_attribute(attr_name, value, ctx);
+        item.value = value.map(|x| x.to_string());
+    }
+}
+
+macro_rules! named_attr_list {
+    ($(#[$meta:meta])* $name:ident, $prefix:expr) => {
+        $(#[$meta])*
+        pub struct $name {
+            inner: NamedAttrList,
+        }
+
+        impl $name {
+            #[doc(hidden)]
+            pub fn new() -> Self {
+                Self {
+                    inner: NamedAttrList::new($prefix),
+                }
+            }
+
+            /// Get the value of an attribute set by templates.
+            ///
+            /// The `name` is the item name used in templates.
+            pub fn get(&self, name: &str) -> Option<&str> {
+                self.inner.get(name)
+            }
+        }
+
+        impl ListPropertyInit for $name {
+            type UpdateContext = DomElement;
+
+            #[inline]
+            fn init_list(dest: &mut Self, count: usize, _ctx: &mut Self::UpdateContext) {
+                dest.inner.init_list(count);
+            }
+        }
+
+        impl<S: ?Sized + NamedAttrValue> NamedListPropertyUpdate<S> for $name {
+            #[inline]
+            fn compare_and_set_named_item_ref(
+                dest: &mut Self,
+                index: usize,
+                name: &'static str,
+                src: &S,
+                ctx: &mut Self::UpdateContext,
+            ) {
+                let value = src.named_attr_value();
+                dest.inner.set_item(index, name, value.as_deref(), ctx);
+            }
+        }
+    };
+}
+
+named_attr_list!(
+    /// The manager for DOM `data-*` attributes.
+    ///
+    /// It is updated with `data:xxx=` syntax in templates,
+    /// i.e. `data:user_id="1"` sets the `data-user-id` attribute.
+    /// The `_` in the name is replaced by `-` .
+    /// `dataset:"xxx"=` syntax can also be used when the name is not an identifier,
+    /// i.e. `dataset:"user.id"="1"` sets the `data-user.id` attribute.
+    /// The attribute is removed when set with `None` .
+    DomDataList,
+    "data-"
+);
+
+named_attr_list!(
+    /// The manager for arbitrary DOM attributes.
+    ///
+    /// It is updated with `attrs:"xxx"=` syntax in templates,
+    /// i.e. `attrs:"x-vendor-id"="1"` sets the `x-vendor-id` attribute.
+    /// It is designed for the attributes which are not declared in the element,
+    /// such as vendor attributes.
+    /// The attribute is removed when set with `None` .
+    ///
+    /// ```rust
+    /// use maomi::prelude::*;
+    /// use maomi_dom::{prelude::*, element::*};
+    ///
+    /// #[component(Backend = DomBackend)]
+    /// struct MyComp {
+    ///     template: template! {
+    ///         <div attrs:"x-vendor-id"="1" data:user_id="2" />
+    ///     },
+    /// }
+    ///
+    /// impl Component for MyComp {
+    ///     fn new() -> Self {
+    ///         Self {
+    ///             template: Default::default(),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// The illegal attribute names are rejected by the compiler.
+    ///
+    /// ```compile_fail
+    /// use maomi::prelude::*;
+    /// use maomi_dom::{prelude::*, element::*};
+    ///
+    /// #[component(Backend = DomBackend)]
+    /// struct MyComp {
+    ///     template: template! {
+    ///         <div attrs:"x vendor id"="1" />
+    ///     },
+    /// }
+    ///
+    /// impl Component for MyComp {
+    ///     fn new() -> Self {
+    ///         Self {
+    ///             template: Default::default(),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    DomAttrList,
+    ""
+);
@@ -18,7 +18,15 @@ pub fn set_style(name: &'static str, value: &str, ctx: &mut DomElement) {
             x.set_style(name, value);
         }
         #[cfg(feature = "prerendering-apply")]
-        DomState::PrerenderingApply(_) => {}
+        DomState::PrerenderingApply(x) => {
+            let value = value.to_string();
+            x.record(move |e| {
+                use wasm_bindgen::JsCast;
+                if let Some(e) = e.dyn_ref::<web_sys::HtmlElement>() {
+                    e.style().set_property(name, &value).unwrap();
+                }
+            });
+        }
     }
 }
 
@@ -36,7 +44,8 @@ enum DomStyleItemValue {
 }
 
 impl DomStyleList {
-    pub(crate) fn new() -> Self {
+    #[doc(hidden)]
+    pub fn new() -> Self {
         Self {
             values: Box::new([]),
         }
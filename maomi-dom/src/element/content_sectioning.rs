@@ -2,8 +2,6 @@
 
 use maomi_dom_macro::dom_element_definition;
 
-use super::*;
-
 #[dom_element_definition]
 pub struct address {}
 
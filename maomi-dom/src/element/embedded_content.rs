@@ -0,0 +1,63 @@
+//! The DOM elements about embedded content.
+
+use super::*;
+
+#[dom_element_definition]
+pub struct iframe {
+    pub allow: attribute!(&str),
+    pub allow_fullscreen: attribute!(bool in web_sys::HtmlIFrameElement as "allowfullscreen"),
+    pub height: attribute!(&str in web_sys::HtmlIFrameElement),
+    pub loading: attribute!(&str),
+    pub name: attribute!(&str in web_sys::HtmlIFrameElement),
+    pub referrer_policy: attribute!(&str in web_sys::HtmlIFrameElement as "referrerpolicy"),
+    pub sandbox: attribute!(&str),
+    pub src: attribute!(&str in web_sys::HtmlIFrameElement),
+    pub srcdoc: attribute!(&str in web_sys::HtmlIFrameElement),
+    pub width: attribute!(&str in web_sys::HtmlIFrameElement),
+    pub load: event!(event::load::Load),
+    pub error: event!(event::load::LoadError),
+}
+
+#[dom_element_definition]
+pub struct object {
+    pub data: attribute!(&str in web_sys::HtmlObjectElement),
+    pub height: attribute!(&str in web_sys::HtmlObjectElement),
+    pub name: attribute!(&str in web_sys::HtmlObjectElement),
+    pub r#type: attribute!(&str in web_sys::HtmlObjectElement),
+    pub width: attribute!(&str in web_sys::HtmlObjectElement),
+    pub load: event!(event::load::Load),
+    pub error: event!(event::load::LoadError),
+}
+
+#[dom_element_definition]
+pub struct picture {}
+
+#[dom_element_definition]
+pub struct source {
+    pub height: attribute!(&str),
+    pub media: attribute!(&str in web_sys::HtmlSourceElement),
+    pub sizes: attribute!(&str in web_sys::HtmlSourceElement),
+    pub src: attribute!(&str in web_sys::HtmlSourceElement),
+    pub srcset: attribute!(&str in web_sys::HtmlSourceElement),
+    pub r#type: attribute!(&str in web_sys::HtmlSourceElement),
+    pub width: attribute!(&str),
+    pub error: event!(event::load::LoadError),
+}
+
+#[dom_element_definition]
+pub struct embed {
+    pub height: attribute!(&str in web_sys::HtmlEmbedElement),
+    pub src: attribute!(&str in web_sys::HtmlEmbedElement),
+    pub r#type: attribute!(&str in web_sys::HtmlEmbedElement),
+    pub width: attribute!(&str in web_sys::HtmlEmbedElement),
+    pub load: event!(event::load::Load),
+    pub error: event!(event::load::LoadError),
+}
+
+#[dom_element_definition]
+pub struct portal {
+    pub referrer_policy: attribute!(&str as "referrerpolicy"),
+    pub src: attribute!(&str),
+    pub load: event!(event::load::Load),
+    pub error: event!(event::load::LoadError),
+}
@@ -44,7 +44,7 @@ pub struct form {
 #[dom_element_definition]
 pub struct input {
     pub accept: attribute!(&str in HtmlInputElement),
-    pub alt: attribute!(&str in HtmlInputElement),
+    pub alt: attribute!(LocaleStr in HtmlInputElement),
     pub autocomplete: attribute!(&str in HtmlInputElement),
     pub checked: attribute!(bool in HtmlInputElement while "change"
         |binding_value: &mut BindingValue<bool>, _ev: &web_sys::Event, target: &web_sys::HtmlInputElement| {
@@ -65,7 +65,7 @@ pub struct input {
     pub multiple: attribute!(bool in HtmlInputElement),
     pub name: attribute!(&str in HtmlInputElement),
     pub pattern: attribute!(&str in HtmlInputElement),
-    pub placeholder: attribute!(&str in HtmlInputElement),
+    pub placeholder: attribute!(LocaleStr in HtmlInputElement),
     pub read_only: attribute!(bool in HtmlInputElement),
     pub required: attribute!(bool in HtmlInputElement),
     pub size: attribute!(u32 in HtmlInputElement),
@@ -109,13 +109,13 @@ pub struct meter {
 #[dom_element_definition]
 pub struct optgroup {
     pub disabled: attribute!(bool in web_sys::HtmlOptionElement),
-    pub label: attribute!(&str in web_sys::HtmlOptionElement),
+    pub label: attribute!(LocaleStr in web_sys::HtmlOptionElement),
 }
 
 #[dom_element_definition]
 pub struct option {
     pub disabled: attribute!(bool in web_sys::HtmlOptionElement),
-    pub label: attribute!(&str in web_sys::HtmlOptionElement),
+    pub label: attribute!(LocaleStr in web_sys::HtmlOptionElement),
     pub selected: attribute!(bool in web_sys::HtmlOptionElement),
     pub value: attribute!(&str in web_sys::HtmlOptionElement),
     pub change: event!(crate::event::form::Change),
@@ -156,7 +156,7 @@ pub struct textarea {
     pub max_length: attribute!(i32 in web_sys::HtmlTextAreaElement),
     pub min_length: attribute!(i32 in web_sys::HtmlTextAreaElement),
     pub name: attribute!(&str in web_sys::HtmlTextAreaElement),
-    pub placeholder: attribute!(&str in web_sys::HtmlTextAreaElement),
+    pub placeholder: attribute!(LocaleStr in web_sys::HtmlTextAreaElement),
     pub read_only: attribute!(bool in web_sys::HtmlTextAreaElement),
     pub required: attribute!(bool in web_sys::HtmlTextAreaElement),
     pub rows: attribute!(u32 in web_sys::HtmlTextAreaElement),
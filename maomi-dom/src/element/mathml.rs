@@ -0,0 +1,134 @@
+//! The MathML elements.
+//!
+//! The element list is found in [MDN](https://developer.mozilla.org/en-US/docs/Web/MathML/Element) .
+//! These elements are created in the MathML namespace.
+//! Like the `svg` module, they are not exported in the `element` module.
+//! Use them with the module prefix, i.e. `<mathml::math> <mathml::mi> "x" </mi> </math>` .
+
+use super::*;
+
+/// The namespace of MathML elements.
+pub const NAMESPACE: &str = "http://www.w3.org/1998/Math/MathML";
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct math {
+    pub display: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct mi {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct mn {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct mo {
+    pub accent: attribute!(&str),
+    pub fence: attribute!(&str),
+    pub form: attribute!(&str),
+    pub largeop: attribute!(&str),
+    pub lspace: attribute!(&str),
+    pub maxsize: attribute!(&str),
+    pub minsize: attribute!(&str),
+    pub movablelimits: attribute!(&str),
+    pub rspace: attribute!(&str),
+    pub separator: attribute!(&str),
+    pub stretchy: attribute!(&str),
+    pub symmetric: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct ms {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct mtext {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct mspace {
+    pub depth: attribute!(&str),
+    pub height: attribute!(&str),
+    pub width: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct mrow {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct mfrac {
+    pub linethickness: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct msqrt {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct mroot {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct msup {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct msub {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct msubsup {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct munder {
+    pub accentunder: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct mover {
+    pub accent: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct munderover {
+    pub accent: attribute!(&str),
+    pub accentunder: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct mmultiscripts {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct mprescripts {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct mtable {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct mtr {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct mtd {
+    pub columnspan: attribute!(&str),
+    pub rowspan: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct mstyle {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct mpadded {
+    pub depth: attribute!(&str),
+    pub height: attribute!(&str),
+    pub lspace: attribute!(&str),
+    pub voffset: attribute!(&str),
+    pub width: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct mphantom {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct merror {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct semantics {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/1998/Math/MathML")]
+pub struct annotation {
+    pub encoding: attribute!(&str),
+}
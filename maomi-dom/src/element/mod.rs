@@ -2,29 +2,9 @@
 //!
 //! The element list is found in [MDN](https://developer.mozilla.org/en-US/docs/Web/HTML/Element) .
 
-use maomi::{
-    backend::{BackendComponent, SupportBackend},
-    error::Error,
-    node::{OwnerWeak, SlotChange, StaticSingleSlot},
-    BackendContext,
-};
 use maomi_dom_macro::dom_element_definition;
-use wasm_bindgen::JsCast;
-use crate::MaybeJsStr;
 
-use crate::{
-    base_element::*,
-    class_list::DomClassList,
-    dynamic_style::DomStyleList,
-    event,
-    event::DomEvent,
-    tree::*,
-    DomBackend,
-    DomGeneralElement,
-    DomState,
-};
-
-// TODO add embedded content, svg, MathML support
+use crate::event;
 
 pub mod content_sectioning;
 pub use content_sectioning::*;
@@ -34,9 +14,13 @@ pub mod inline_text;
 pub use inline_text::*;
 pub mod multimedia;
 pub use multimedia::*;
+pub mod embedded_content;
+pub use embedded_content::*;
 pub mod demarcating_edits;
 pub use demarcating_edits::*;
 pub mod table_content;
 pub use table_content::*;
 pub mod forms;
 pub use forms::*;
+pub mod svg;
+pub mod mathml;
@@ -1,7 +1,22 @@
 //! The DOM elements about multimedia.
 
+use maomi::prop::BindingValue;
+
 use super::*;
 
+// the playback state cannot be set directly, so it is changed by `play` and `pause`
+mod media_playback {
+    pub(super) fn set_paused(elem: &web_sys::HtmlMediaElement, v: bool) {
+        if v {
+            if let Err(err) = elem.pause() {
+                crate::log_js_error(&err);
+            }
+        } else if let Err(err) = elem.play() {
+            crate::log_js_error(&err);
+        }
+    }
+}
+
 #[dom_element_definition]
 pub struct canvas {
     pub width: attribute!(u32 in web_sys::HtmlInputElement),
@@ -10,46 +25,99 @@ pub struct canvas {
 
 #[dom_element_definition]
 pub struct img {
-    pub alt: attribute!(&str in web_sys::HtmlImageElement),
+    pub alt: attribute!(LocaleStr in web_sys::HtmlImageElement),
     pub decoding: attribute!(&str in web_sys::HtmlImageElement),
     pub height: attribute!(u32 in web_sys::HtmlImageElement),
     pub is_map: attribute!(bool in web_sys::HtmlImageElement),
-    pub referrer_policy: attribute!(&str in web_sys::HtmlImageElement),
+    pub loading: attribute!(&str),
+    pub referrer_policy: attribute!(&str in web_sys::HtmlImageElement as "referrerpolicy"),
     pub sizes: attribute!(&str in web_sys::HtmlImageElement),
     pub src: attribute!(&str in web_sys::HtmlImageElement),
     pub srcset: attribute!(&str in web_sys::HtmlImageElement),
     pub width: attribute!(u32 in web_sys::HtmlImageElement),
     pub use_map: attribute!(&str in web_sys::HtmlImageElement),
+    pub load: event!(event::load::Load),
+    pub error: event!(event::load::LoadError),
 }
 
 #[dom_element_definition]
 pub struct audio {
     pub autoplay: attribute!(bool in web_sys::HtmlMediaElement),
     pub controls: attribute!(bool in web_sys::HtmlMediaElement),
+    pub current_time: attribute!(f64 in web_sys::HtmlMediaElement while "timeupdate", "seeked"
+        |binding_value: &mut BindingValue<f64>, _ev: &web_sys::Event, target: &web_sys::HtmlMediaElement| {
+            binding_value.set(target.current_time());
+        }
+    ),
+    pub paused: attribute!(bool in media_playback while "play", "pause"
+        |binding_value: &mut BindingValue<bool>, _ev: &web_sys::Event, target: &web_sys::HtmlMediaElement| {
+            binding_value.set(target.paused());
+        }
+    ),
+    pub volume: attribute!(f64 in web_sys::HtmlMediaElement while "volumechange"
+        |binding_value: &mut BindingValue<f64>, _ev: &web_sys::Event, target: &web_sys::HtmlMediaElement| {
+            binding_value.set(target.volume());
+        }
+    ),
     pub r#loop: attribute!(bool in web_sys::HtmlMediaElement),
-    pub muted: attribute!(bool in web_sys::HtmlMediaElement),
+    pub muted: attribute!(bool in web_sys::HtmlMediaElement while "volumechange"
+        |binding_value: &mut BindingValue<bool>, _ev: &web_sys::Event, target: &web_sys::HtmlMediaElement| {
+            binding_value.set(target.muted());
+        }
+    ),
     pub preload: attribute!(&str in web_sys::HtmlMediaElement),
     pub src: attribute!(&str in web_sys::HtmlMediaElement),
+    pub play: event!(event::media::Play),
+    pub pause: event!(event::media::Pause),
+    pub ended: event!(event::media::Ended),
+    pub time_update: event!(event::media::TimeUpdate),
+    pub loaded_metadata: event!(event::media::LoadedMetadata),
+    pub error: event!(event::media::MediaError),
 }
 
 #[dom_element_definition]
 pub struct video {
     pub autoplay: attribute!(bool in web_sys::HtmlMediaElement),
     pub controls: attribute!(bool in web_sys::HtmlMediaElement),
+    pub current_time: attribute!(f64 in web_sys::HtmlMediaElement while "timeupdate", "seeked"
+        |binding_value: &mut BindingValue<f64>, _ev: &web_sys::Event, target: &web_sys::HtmlMediaElement| {
+            binding_value.set(target.current_time());
+        }
+    ),
+    pub paused: attribute!(bool in media_playback while "play", "pause"
+        |binding_value: &mut BindingValue<bool>, _ev: &web_sys::Event, target: &web_sys::HtmlMediaElement| {
+            binding_value.set(target.paused());
+        }
+    ),
+    pub volume: attribute!(f64 in web_sys::HtmlMediaElement while "volumechange"
+        |binding_value: &mut BindingValue<f64>, _ev: &web_sys::Event, target: &web_sys::HtmlMediaElement| {
+            binding_value.set(target.volume());
+        }
+    ),
     pub height: attribute!(u32 in web_sys::HtmlVideoElement),
     pub r#loop: attribute!(bool in web_sys::HtmlMediaElement),
-    pub muted: attribute!(bool in web_sys::HtmlMediaElement),
+    pub muted: attribute!(bool in web_sys::HtmlMediaElement while "volumechange"
+        |binding_value: &mut BindingValue<bool>, _ev: &web_sys::Event, target: &web_sys::HtmlMediaElement| {
+            binding_value.set(target.muted());
+        }
+    ),
     pub poster: attribute!(&str in web_sys::HtmlVideoElement),
     pub preload: attribute!(&str in web_sys::HtmlMediaElement),
     pub src: attribute!(&str in web_sys::HtmlMediaElement),
     pub width: attribute!(u32 in web_sys::HtmlVideoElement),
+    pub play: event!(event::media::Play),
+    pub pause: event!(event::media::Pause),
+    pub ended: event!(event::media::Ended),
+    pub time_update: event!(event::media::TimeUpdate),
+    pub loaded_metadata: event!(event::media::LoadedMetadata),
+    pub error: event!(event::media::MediaError),
 }
 
 #[dom_element_definition]
 pub struct track {
     pub default: attribute!(bool in web_sys::HtmlTrackElement),
     pub kind: attribute!(&str in web_sys::HtmlTrackElement),
-    pub label: attribute!(&str in web_sys::HtmlTrackElement),
+    pub label: attribute!(LocaleStr in web_sys::HtmlTrackElement),
     pub src: attribute!(&str in web_sys::HtmlTrackElement),
     pub srclang: attribute!(&str in web_sys::HtmlTrackElement),
 }
@@ -62,7 +130,7 @@ pub struct map {
 #[dom_element_definition]
 pub struct area {
     pub name: attribute!(&str in web_sys::HtmlInputElement),
-    pub alt: attribute!(&str in web_sys::HtmlAreaElement),
+    pub alt: attribute!(LocaleStr in web_sys::HtmlAreaElement),
     pub coords: attribute!(&str in web_sys::HtmlAreaElement),
     pub download: attribute!(&str in web_sys::HtmlAreaElement),
     pub href: attribute!(&str in web_sys::HtmlAreaElement),
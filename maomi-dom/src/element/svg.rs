@@ -0,0 +1,220 @@
+//! The SVG elements.
+//!
+//! The element list is found in [MDN](https://developer.mozilla.org/en-US/docs/Web/SVG/Element) .
+//! These elements are created in the SVG namespace.
+//! Since some of them have the same names as HTML elements (like `a` and `text` ),
+//! they are not exported in the `element` module.
+//! Use them with the module prefix, i.e. `<svg::svg> <svg::circle r="5" /> </svg>` .
+
+use super::*;
+
+/// The namespace of SVG elements.
+pub const NAMESPACE: &str = "http://www.w3.org/2000/svg";
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct svg {
+    pub width: attribute!(&str),
+    pub height: attribute!(&str),
+    pub x: attribute!(&str),
+    pub y: attribute!(&str),
+    pub view_box: attribute!(&str as "viewBox"),
+    pub preserve_aspect_ratio: attribute!(&str as "preserveAspectRatio"),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct g {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct defs {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct symbol {
+    pub width: attribute!(&str),
+    pub height: attribute!(&str),
+    pub x: attribute!(&str),
+    pub y: attribute!(&str),
+    pub view_box: attribute!(&str as "viewBox"),
+    pub preserve_aspect_ratio: attribute!(&str as "preserveAspectRatio"),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct r#use {
+    pub href: attribute!(&str),
+    pub width: attribute!(&str),
+    pub height: attribute!(&str),
+    pub x: attribute!(&str),
+    pub y: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct a {
+    pub href: attribute!(&str),
+    pub target: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct title {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct desc {}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct path {
+    pub d: attribute!(&str),
+    pub path_length: attribute!(&str as "pathLength"),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct circle {
+    pub cx: attribute!(&str),
+    pub cy: attribute!(&str),
+    pub r: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct ellipse {
+    pub cx: attribute!(&str),
+    pub cy: attribute!(&str),
+    pub rx: attribute!(&str),
+    pub ry: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct rect {
+    pub width: attribute!(&str),
+    pub height: attribute!(&str),
+    pub x: attribute!(&str),
+    pub y: attribute!(&str),
+    pub rx: attribute!(&str),
+    pub ry: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct line {
+    pub x1: attribute!(&str),
+    pub y1: attribute!(&str),
+    pub x2: attribute!(&str),
+    pub y2: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct polyline {
+    pub points: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct polygon {
+    pub points: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct text {
+    pub x: attribute!(&str),
+    pub y: attribute!(&str),
+    pub dx: attribute!(&str),
+    pub dy: attribute!(&str),
+    pub text_anchor: attribute!(&str),
+    pub dominant_baseline: attribute!(&str),
+    pub font_family: attribute!(&str),
+    pub font_size: attribute!(&str),
+    pub font_weight: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct tspan {
+    pub x: attribute!(&str),
+    pub y: attribute!(&str),
+    pub dx: attribute!(&str),
+    pub dy: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct textPath {
+    pub href: attribute!(&str),
+    pub start_offset: attribute!(&str as "startOffset"),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct image {
+    pub href: attribute!(&str),
+    pub width: attribute!(&str),
+    pub height: attribute!(&str),
+    pub x: attribute!(&str),
+    pub y: attribute!(&str),
+    pub preserve_aspect_ratio: attribute!(&str as "preserveAspectRatio"),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct foreignObject {
+    pub width: attribute!(&str),
+    pub height: attribute!(&str),
+    pub x: attribute!(&str),
+    pub y: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct linearGradient {
+    pub x1: attribute!(&str),
+    pub y1: attribute!(&str),
+    pub x2: attribute!(&str),
+    pub y2: attribute!(&str),
+    pub gradient_units: attribute!(&str as "gradientUnits"),
+    pub gradient_transform: attribute!(&str as "gradientTransform"),
+    pub spread_method: attribute!(&str as "spreadMethod"),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct radialGradient {
+    pub cx: attribute!(&str),
+    pub cy: attribute!(&str),
+    pub r: attribute!(&str),
+    pub fx: attribute!(&str),
+    pub fy: attribute!(&str),
+    pub gradient_units: attribute!(&str as "gradientUnits"),
+    pub gradient_transform: attribute!(&str as "gradientTransform"),
+    pub spread_method: attribute!(&str as "spreadMethod"),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct stop {
+    pub offset: attribute!(&str),
+    pub stop_color: attribute!(&str),
+    pub stop_opacity: attribute!(&str),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct clipPath {
+    pub clip_path_units: attribute!(&str as "clipPathUnits"),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct mask {
+    pub width: attribute!(&str),
+    pub height: attribute!(&str),
+    pub x: attribute!(&str),
+    pub y: attribute!(&str),
+    pub mask_units: attribute!(&str as "maskUnits"),
+    pub mask_content_units: attribute!(&str as "maskContentUnits"),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct pattern {
+    pub width: attribute!(&str),
+    pub height: attribute!(&str),
+    pub x: attribute!(&str),
+    pub y: attribute!(&str),
+    pub view_box: attribute!(&str as "viewBox"),
+    pub pattern_units: attribute!(&str as "patternUnits"),
+    pub pattern_content_units: attribute!(&str as "patternContentUnits"),
+    pub pattern_transform: attribute!(&str as "patternTransform"),
+}
+
+#[dom_element_definition(namespace = "http://www.w3.org/2000/svg")]
+pub struct marker {
+    pub marker_width: attribute!(&str as "markerWidth"),
+    pub marker_height: attribute!(&str as "markerHeight"),
+    pub ref_x: attribute!(&str as "refX"),
+    pub ref_y: attribute!(&str as "refY"),
+    pub orient: attribute!(&str),
+    pub view_box: attribute!(&str as "viewBox"),
+}
@@ -0,0 +1,151 @@
+use std::marker::PhantomData;
+use wasm_bindgen::{prelude::*, JsCast};
+
+use super::{utils, BubbleEvent, ColdEventItem, DomEventRegister};
+
+/// The custom event detail.
+///
+/// It is used in the events of custom elements,
+/// which are usually dispatched as DOM `CustomEvent` s.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomEvent {
+    propagation_stopped: bool,
+    default_prevented: bool,
+    dom_event: web_sys::Event,
+}
+
+impl CustomEvent {
+    /// Get the `detail` of the DOM `CustomEvent` .
+    ///
+    /// It is `undefined` if the event is not a `CustomEvent` .
+    #[inline]
+    pub fn detail(&self) -> JsValue {
+        match self.dom_event.dyn_ref::<web_sys::CustomEvent>() {
+            Some(x) => x.detail(),
+            None => JsValue::UNDEFINED,
+        }
+    }
+
+    /// Get the underlying DOM event.
+    #[inline]
+    pub fn dom_event(&self) -> &web_sys::Event {
+        &self.dom_event
+    }
+}
+
+impl BubbleEvent for CustomEvent {
+    #[inline]
+    fn stop_propagation(&mut self) {
+        if self.propagation_stopped {
+            return;
+        };
+        self.propagation_stopped = true;
+        self.dom_event.stop_propagation()
+    }
+
+    #[inline]
+    fn propagation_stopped(&self) -> bool {
+        self.propagation_stopped
+    }
+
+    #[inline]
+    fn prevent_default(&mut self) {
+        if self.default_prevented {
+            return;
+        };
+        self.default_prevented = true;
+        self.dom_event.prevent_default()
+    }
+
+    #[inline]
+    fn default_prevented(&self) -> bool {
+        self.default_prevented
+    }
+}
+
+/// The name of a custom event.
+///
+/// ```rust
+/// use maomi_dom::event::CustomEventType;
+///
+/// pub struct ValueChanged;
+///
+/// impl CustomEventType for ValueChanged {
+///     const NAME: &'static str = "value-changed";
+/// }
+/// ```
+pub trait CustomEventType: 'static {
+    /// The DOM event name.
+    const NAME: &'static str;
+}
+
+/// A custom event which can be used as `event!(CustomDomEvent<T>)` in custom element definitions.
+pub struct CustomDomEvent<T: CustomEventType> {
+    _phantom: PhantomData<T>,
+}
+
+fn trigger_ev(name: &'static str, dom_event: web_sys::Event) {
+    utils::trigger_on_current_target(dom_event, |x, dom_event| {
+        trigger(
+            x,
+            name,
+            &mut CustomEvent {
+                propagation_stopped: false,
+                default_prevented: false,
+                dom_event,
+            },
+        );
+    });
+}
+
+fn trigger(target: &mut crate::base_element::DomElement, name: &'static str, detail: &mut CustomEvent) {
+    if let Some(list) = target.cold_event_list() {
+        let f = list.iter().find_map(|x| match x {
+            ColdEventItem::Custom(n, x, _) if *n == name => Some(x),
+            _ => None,
+        });
+        if let Some(f) = f {
+            f(detail);
+        }
+    }
+}
+
+impl<T: CustomEventType> DomEventRegister for CustomDomEvent<T> {
+    type Detail = CustomEvent;
+
+    #[inline]
+    fn bind(
+        target: &mut crate::base_element::DomElement,
+        f: Box<dyn 'static + Fn(&mut Self::Detail)>,
+    ) {
+        for item in target.cold_event_list_mut() {
+            if let ColdEventItem::Custom(n, x, _) = item {
+                if *n == T::NAME {
+                    *x = f;
+                    return;
+                }
+            }
+        }
+        #[cfg(feature = "prerendering")]
+        if let crate::DomState::Prerendering(_) = &target.elem {
+            return;
+        }
+        let cb = Closure::new(move |dom_event: web_sys::Event| {
+            trigger_ev(T::NAME, dom_event);
+        });
+        let item = ColdEventItem::Custom(T::NAME, f, cb);
+        match &target.elem {
+            crate::DomState::Normal(x) => item.apply(x),
+            #[cfg(feature = "prerendering")]
+            crate::DomState::Prerendering(_) => unreachable!(),
+            #[cfg(feature = "prerendering-apply")]
+            crate::DomState::PrerenderingApply(_) => {}
+        }
+        target.cold_event_list_mut().push(item);
+    }
+
+    #[inline]
+    fn trigger(target: &mut crate::base_element::DomElement, detail: &mut Self::Detail) {
+        trigger(target, T::NAME, detail);
+    }
+}
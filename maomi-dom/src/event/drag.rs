@@ -0,0 +1,220 @@
+use maomi::backend::tree::{ForestNodeRc, ForestToken};
+
+use super::{tap::CANCEL_TAP_DIST, touch::TouchIdentifier, utils, BubbleEvent, DomEventRegister};
+use crate::DomGeneralElement;
+
+thread_local! {
+    pub(super) static DRAG_TRACKER: std::cell::RefCell<DragTracker>  = Default::default();
+}
+
+pub(crate) fn remove_element_drag_state(target: &ForestToken) {
+    DRAG_TRACKER.with(|this| this.borrow_mut().interrupt_by_elem(target))
+}
+
+#[derive(Default)]
+pub(super) struct DragTracker {
+    cur: Vec<CurrentDrag>,
+}
+
+struct CurrentDrag {
+    identifier: TouchIdentifier,
+    target: ForestToken,
+    start_x: i32,
+    start_y: i32,
+    started: bool,
+}
+
+impl CurrentDrag {
+    fn event(&self, client_x: i32, client_y: i32, canceled: bool) -> DragEvent {
+        DragEvent {
+            propagation_stopped: false,
+            default_prevented: false,
+            identifier: self.identifier,
+            start_x: self.start_x,
+            start_y: self.start_y,
+            client_x,
+            client_y,
+            canceled,
+        }
+    }
+
+    fn bubble<T: DomEventRegister<Detail = DragEvent>>(&self, ev: &mut DragEvent) {
+        if let Some(target) = unsafe { self.target.unsafe_resolve_token() } {
+            utils::bubble_event::<T>(target, ev);
+        }
+    }
+}
+
+impl DragTracker {
+    pub(super) fn add(
+        &mut self,
+        identifier: TouchIdentifier,
+        target: ForestNodeRc<DomGeneralElement>,
+        client_x: i32,
+        client_y: i32,
+    ) {
+        self.cur.retain(|x| x.identifier != identifier);
+        self.cur.push(CurrentDrag {
+            identifier,
+            target: target.token(),
+            start_x: client_x,
+            start_y: client_y,
+            started: false,
+        });
+    }
+
+    pub(super) fn update(&mut self, identifier: TouchIdentifier, client_x: i32, client_y: i32) {
+        if let Some((i, t)) = self
+            .cur
+            .iter_mut()
+            .enumerate()
+            .find(|(_, x)| x.identifier == identifier)
+        {
+            if t.started {
+                // generate drag_move event
+                let mut ev = t.event(client_x, client_y, false);
+                t.bubble::<DragMove>(&mut ev);
+            } else if (t.start_x - client_x).abs() > CANCEL_TAP_DIST
+                || (t.start_y - client_y).abs() > CANCEL_TAP_DIST
+            {
+                // generate drag_start event
+                let mut ev = t.event(client_x, client_y, false);
+                t.bubble::<DragStart>(&mut ev);
+                if ev.default_prevented {
+                    self.cur.swap_remove(i);
+                } else {
+                    t.started = true;
+                }
+            }
+        }
+    }
+
+    fn end(&mut self, identifier: TouchIdentifier, client_x: i32, client_y: i32, canceled: bool) {
+        if let Some((i, t)) = self
+            .cur
+            .iter_mut()
+            .enumerate()
+            .find(|(_, x)| x.identifier == identifier)
+        {
+            if t.started {
+                // generate drag_end event
+                let mut ev = t.event(client_x, client_y, canceled);
+                t.bubble::<DragEnd>(&mut ev);
+            }
+            self.cur.swap_remove(i);
+        }
+    }
+
+    pub(super) fn remove(&mut self, identifier: TouchIdentifier, client_x: i32, client_y: i32) {
+        self.end(identifier, client_x, client_y, false)
+    }
+
+    pub(super) fn interrupt(&mut self, identifier: TouchIdentifier, client_x: i32, client_y: i32) {
+        self.end(identifier, client_x, client_y, true)
+    }
+
+    pub fn interrupt_by_elem(&mut self, forest_token: &ForestToken) {
+        self.cur
+            .retain(|x| x.target.stable_addr() != forest_token.stable_addr());
+    }
+}
+
+/// The drag event detail.
+///
+/// Drag events are generated from DOM `touch*` or `mouse*` events automatically.
+/// * `drag_start` event is triggered when a finger (or mouse) moves far enough after pressed,
+///   i.e. when the `cancel_tap` event is triggered;
+/// * `drag_move` event is triggered when the finger (or mouse) moves after `drag_start` ;
+/// * `drag_end` event is triggered when the finger (or mouse) is released or interrupted.
+///
+/// These events are triggered on the element which is pressed at first.
+/// Calling `detail.preventDefault()` in the `drag_start` handler will prevent the whole drag.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DragEvent {
+    propagation_stopped: bool,
+    default_prevented: bool,
+    identifier: TouchIdentifier,
+    start_x: i32,
+    start_y: i32,
+    client_x: i32,
+    client_y: i32,
+    canceled: bool,
+}
+
+impl DragEvent {
+    /// Get the identifier of the finger (or mouse).
+    #[inline]
+    pub fn identifier(&self) -> TouchIdentifier {
+        self.identifier
+    }
+
+    /// Get the x-position reletive to the viewport.
+    #[inline]
+    pub fn client_x(&self) -> i32 {
+        self.client_x
+    }
+
+    /// Get the y-position reletive to the viewport.
+    #[inline]
+    pub fn client_y(&self) -> i32 {
+        self.client_y
+    }
+
+    /// Get the x-position reletive to the viewport when the finger (or mouse) is pressed.
+    #[inline]
+    pub fn start_client_x(&self) -> i32 {
+        self.start_x
+    }
+
+    /// Get the y-position reletive to the viewport when the finger (or mouse) is pressed.
+    #[inline]
+    pub fn start_client_y(&self) -> i32 {
+        self.start_y
+    }
+
+    /// Get the x-distance moved since the finger (or mouse) is pressed.
+    #[inline]
+    pub fn delta_x(&self) -> i32 {
+        self.client_x - self.start_x
+    }
+
+    /// Get the y-distance moved since the finger (or mouse) is pressed.
+    #[inline]
+    pub fn delta_y(&self) -> i32 {
+        self.client_y - self.start_y
+    }
+
+    /// Check whether the drag is interrupted, i.e. by `touchcancel` .
+    ///
+    /// It can only be `true` in `drag_end` event.
+    #[inline]
+    pub fn canceled(&self) -> bool {
+        self.canceled
+    }
+}
+
+impl BubbleEvent for DragEvent {
+    #[inline]
+    fn stop_propagation(&mut self) {
+        self.propagation_stopped = true;
+    }
+
+    #[inline]
+    fn propagation_stopped(&self) -> bool {
+        self.propagation_stopped
+    }
+
+    #[inline]
+    fn prevent_default(&mut self) {
+        self.default_prevented = true;
+    }
+
+    #[inline]
+    fn default_prevented(&self) -> bool {
+        self.default_prevented
+    }
+}
+
+hot_event!(DragStart, drag_start, DragEvent);
+hot_event!(DragMove, drag_move, DragEvent);
+hot_event!(DragEnd, drag_end, DragEvent);
@@ -0,0 +1,110 @@
+use wasm_bindgen::{prelude::*, JsCast};
+
+use super::{utils, BubbleEvent, ColdEventItem, DomEventRegister};
+
+/// The focus-related event detail.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FocusEvent {
+    propagation_stopped: bool,
+    default_prevented: bool,
+    dom_event: web_sys::FocusEvent,
+}
+
+impl FocusEvent {
+    /// Get the other element involved in the focus change.
+    ///
+    /// For `focus` and `focus_in` , it is the element losing focus;
+    /// for `blur` and `focus_out` , it is the element receiving focus.
+    /// It is `None` if there is no such element (or it is not allowed to be exposed).
+    #[inline]
+    pub fn related_target(&self) -> Option<web_sys::Element> {
+        self.dom_event
+            .related_target()
+            .and_then(|x| x.dyn_into::<web_sys::Element>().ok())
+    }
+
+    /// Get the element which actually receives or loses focus.
+    ///
+    /// For `focus_in` and `focus_out` , it may be a descendant of the listening element.
+    #[inline]
+    pub fn target(&self) -> Option<web_sys::Element> {
+        self.dom_event
+            .target()
+            .and_then(|x| x.dyn_into::<web_sys::Element>().ok())
+    }
+}
+
+impl BubbleEvent for FocusEvent {
+    #[inline]
+    fn stop_propagation(&mut self) {
+        if self.propagation_stopped {
+            return;
+        };
+        self.propagation_stopped = true;
+        self.dom_event.stop_propagation()
+    }
+
+    #[inline]
+    fn propagation_stopped(&self) -> bool {
+        self.propagation_stopped
+    }
+
+    #[inline]
+    fn prevent_default(&mut self) {
+        if self.default_prevented {
+            return;
+        };
+        self.default_prevented = true;
+        self.dom_event.prevent_default()
+    }
+
+    #[inline]
+    fn default_prevented(&self) -> bool {
+        self.default_prevented
+    }
+}
+
+fn trigger_ev<T: DomEventRegister<Detail = FocusEvent>>(dom_event: web_sys::FocusEvent) {
+    utils::trigger_on_current_target(dom_event, |x, dom_event| {
+        T::trigger(
+            x,
+            &mut FocusEvent {
+                propagation_stopped: false,
+                default_prevented: false,
+                dom_event,
+            },
+        );
+    });
+}
+
+cold_event!(
+    Focus,
+    FocusEvent,
+    Closure::new(move |dom_event: web_sys::FocusEvent| {
+        trigger_ev::<Focus>(dom_event);
+    })
+);
+
+cold_event!(
+    Blur,
+    FocusEvent,
+    Closure::new(move |dom_event: web_sys::FocusEvent| {
+        trigger_ev::<Blur>(dom_event);
+    })
+);
+
+cold_event!(
+    FocusIn,
+    FocusEvent,
+    Closure::new(move |dom_event: web_sys::FocusEvent| {
+        trigger_ev::<FocusIn>(dom_event);
+    })
+);
+
+cold_event!(
+    FocusOut,
+    FocusEvent,
+    Closure::new(move |dom_event: web_sys::FocusEvent| {
+        trigger_ev::<FocusOut>(dom_event);
+    })
+);
@@ -0,0 +1,124 @@
+use wasm_bindgen::prelude::*;
+
+use super::{utils, BubbleEvent, ColdEventItem, DomEventRegister};
+
+/// The keyboard event detail.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyboardEvent {
+    propagation_stopped: bool,
+    default_prevented: bool,
+    dom_event: web_sys::KeyboardEvent,
+}
+
+impl KeyboardEvent {
+    /// Get the key value, i.e. `a` `Enter` `ArrowUp` .
+    ///
+    /// It respects the keyboard layout and the modifier keys.
+    #[inline]
+    pub fn key(&self) -> String {
+        self.dom_event.key()
+    }
+
+    /// Get the physical key code, i.e. `KeyA` `Enter` `ArrowUp` .
+    ///
+    /// It is not affected by the keyboard layout and the modifier keys.
+    #[inline]
+    pub fn code(&self) -> String {
+        self.dom_event.code()
+    }
+
+    /// Check whether keyboard alt key is pressed.
+    #[inline]
+    pub fn alt_key(&self) -> bool {
+        self.dom_event.alt_key()
+    }
+
+    /// Check whether keyboard ctrl key is pressed.
+    #[inline]
+    pub fn ctrl_key(&self) -> bool {
+        self.dom_event.ctrl_key()
+    }
+
+    /// Check whether keyboard meta key is pressed.
+    #[inline]
+    pub fn meta_key(&self) -> bool {
+        self.dom_event.meta_key()
+    }
+
+    /// Check whether keyboard shift key is pressed.
+    #[inline]
+    pub fn shift_key(&self) -> bool {
+        self.dom_event.shift_key()
+    }
+
+    /// Check whether the key is being held down and the event is auto-repeated.
+    #[inline]
+    pub fn repeat(&self) -> bool {
+        self.dom_event.repeat()
+    }
+
+    /// Check whether the event is fired during an IME composition.
+    #[inline]
+    pub fn is_composing(&self) -> bool {
+        self.dom_event.is_composing()
+    }
+}
+
+impl BubbleEvent for KeyboardEvent {
+    #[inline]
+    fn stop_propagation(&mut self) {
+        if self.propagation_stopped {
+            return;
+        };
+        self.propagation_stopped = true;
+        self.dom_event.stop_propagation()
+    }
+
+    #[inline]
+    fn propagation_stopped(&self) -> bool {
+        self.propagation_stopped
+    }
+
+    #[inline]
+    fn prevent_default(&mut self) {
+        if self.default_prevented {
+            return;
+        };
+        self.default_prevented = true;
+        self.dom_event.prevent_default()
+    }
+
+    #[inline]
+    fn default_prevented(&self) -> bool {
+        self.default_prevented
+    }
+}
+
+fn trigger_ev<T: DomEventRegister<Detail = KeyboardEvent>>(dom_event: web_sys::KeyboardEvent) {
+    utils::trigger_on_current_target(dom_event, |x, dom_event| {
+        T::trigger(
+            x,
+            &mut KeyboardEvent {
+                propagation_stopped: false,
+                default_prevented: false,
+                dom_event,
+            },
+        );
+    });
+}
+
+cold_event!(
+    KeyDown,
+    KeyboardEvent,
+    Closure::new(move |dom_event: web_sys::KeyboardEvent| {
+        trigger_ev::<KeyDown>(dom_event);
+    })
+);
+
+cold_event!(
+    KeyUp,
+    KeyboardEvent,
+    Closure::new(move |dom_event: web_sys::KeyboardEvent| {
+        trigger_ev::<KeyUp>(dom_event);
+    })
+);
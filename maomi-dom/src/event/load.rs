@@ -0,0 +1,50 @@
+use wasm_bindgen::{prelude::*, JsCast};
+
+use super::{ColdEventItem, DomEventRegister};
+use crate::DomGeneralElement;
+
+/// The resource loading event detail.
+///
+/// It is used in `load` and `error` events of elements with external resources,
+/// i.e. `<img>` and `<iframe>` .
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadEvent {
+    dom_event: web_sys::Event,
+}
+
+impl LoadEvent {
+    /// Get the element which loads the resource.
+    #[inline]
+    pub fn target(&self) -> Option<web_sys::Element> {
+        self.dom_event
+            .target()
+            .and_then(|x| x.dyn_into::<web_sys::Element>().ok())
+    }
+}
+
+fn trigger_ev<T: DomEventRegister<Detail = LoadEvent>>(dom_event: web_sys::Event) {
+    let target = dom_event
+        .target()
+        .and_then(|x| crate::DomElement::from_event_dom_elem(x.unchecked_ref(), false));
+    if let Some(n) = target {
+        if let DomGeneralElement::Element(x) = &mut *n.borrow_mut() {
+            T::trigger(x, &mut LoadEvent { dom_event });
+        }
+    }
+}
+
+cold_event!(
+    Load,
+    LoadEvent,
+    Closure::new(move |dom_event: web_sys::Event| {
+        trigger_ev::<Load>(dom_event);
+    })
+);
+
+cold_event!(
+    LoadError,
+    LoadEvent,
+    Closure::new(move |dom_event: web_sys::Event| {
+        trigger_ev::<LoadError>(dom_event);
+    })
+);
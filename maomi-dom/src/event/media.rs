@@ -0,0 +1,102 @@
+use wasm_bindgen::{prelude::*, JsCast};
+
+use super::{ColdEventItem, DomEventRegister};
+use crate::DomGeneralElement;
+
+/// The media playback event detail.
+///
+/// It is used in events of `<audio>` and `<video>` ,
+/// i.e. `play` `pause` `ended` `timeupdate` `loadedmetadata` and `error` .
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaEvent {
+    dom_event: web_sys::Event,
+}
+
+impl MediaEvent {
+    /// Get the media element.
+    #[inline]
+    pub fn target(&self) -> Option<web_sys::HtmlMediaElement> {
+        self.dom_event
+            .target()
+            .and_then(|x| x.dyn_into::<web_sys::HtmlMediaElement>().ok())
+    }
+
+    /// Get the current playback time in seconds.
+    #[inline]
+    pub fn current_time(&self) -> f64 {
+        self.target().map(|x| x.current_time()).unwrap_or_default()
+    }
+
+    /// Get the media duration in seconds.
+    ///
+    /// It is `NaN` if the metadata is not loaded yet.
+    #[inline]
+    pub fn duration(&self) -> f64 {
+        self.target().map(|x| x.duration()).unwrap_or(f64::NAN)
+    }
+
+    /// Check whether the playback is paused.
+    #[inline]
+    pub fn paused(&self) -> bool {
+        self.target().map(|x| x.paused()).unwrap_or(true)
+    }
+}
+
+fn trigger_ev<T: DomEventRegister<Detail = MediaEvent>>(dom_event: web_sys::Event) {
+    let target = dom_event
+        .target()
+        .and_then(|x| crate::DomElement::from_event_dom_elem(x.unchecked_ref(), false));
+    if let Some(n) = target {
+        if let DomGeneralElement::Element(x) = &mut *n.borrow_mut() {
+            T::trigger(x, &mut MediaEvent { dom_event });
+        }
+    }
+}
+
+cold_event!(
+    Play,
+    MediaEvent,
+    Closure::new(move |dom_event: web_sys::Event| {
+        trigger_ev::<Play>(dom_event);
+    })
+);
+
+cold_event!(
+    Pause,
+    MediaEvent,
+    Closure::new(move |dom_event: web_sys::Event| {
+        trigger_ev::<Pause>(dom_event);
+    })
+);
+
+cold_event!(
+    Ended,
+    MediaEvent,
+    Closure::new(move |dom_event: web_sys::Event| {
+        trigger_ev::<Ended>(dom_event);
+    })
+);
+
+cold_event!(
+    TimeUpdate,
+    MediaEvent,
+    Closure::new(move |dom_event: web_sys::Event| {
+        trigger_ev::<TimeUpdate>(dom_event);
+    })
+);
+
+cold_event!(
+    LoadedMetadata,
+    MediaEvent,
+    Closure::new(move |dom_event: web_sys::Event| {
+        trigger_ev::<LoadedMetadata>(dom_event);
+    })
+);
+
+cold_event!(
+    MediaError,
+    MediaEvent,
+    Closure::new(move |dom_event: web_sys::Event| {
+        trigger_ev::<MediaError>(dom_event);
+    })
+);
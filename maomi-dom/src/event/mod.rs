@@ -6,6 +6,11 @@
 //! * `cancal_tap` event refers to a finger (or mouse) that moved;
 //! * `long_tap` event refers to a long finger tap (or mouse hold).
 //! Calling `detail.preventDefault()` in the `long_tap` handler will prevent the `tap` event.
+//!
+//! Drag events are generated in the same way.
+//! * `drag_start` event refers to a finger (or mouse) that moved after pressed;
+//! * `drag_move` event refers to a further movement;
+//! * `drag_end` event refers to a finger (or mouse) released after `drag_start` .
 
 use maomi::event::EventHandler;
 use std::marker::PhantomData;
@@ -19,16 +24,34 @@ pub(crate) mod tap;
 pub use tap::TapEvent;
 pub(crate) mod touch;
 pub use touch::TouchEvent;
+pub(crate) mod drag;
+pub use drag::DragEvent;
 pub(crate) mod mouse;
 pub use mouse::{MouseButton, MouseEvent};
+pub(crate) mod pointer;
+pub use pointer::{PointerEvent, PointerType};
+pub(crate) mod keyboard;
+pub use keyboard::KeyboardEvent;
+pub(crate) mod focus;
+pub use focus::FocusEvent;
 pub(crate) mod scroll;
 pub use scroll::ScrollEvent;
+pub(crate) mod wheel;
+pub use wheel::{WheelDeltaMode, WheelEvent};
+pub(crate) mod observer;
+pub use observer::{IntersectionEvent, ResizeEvent};
 pub(crate) mod animation;
 pub use animation::AnimationEvent;
 pub(crate) mod transition;
 pub use transition::TransitionEvent;
+pub(crate) mod load;
+pub use load::LoadEvent;
+pub(crate) mod media;
+pub use media::MediaEvent;
 pub(crate) mod form;
 pub use form::{SubmitEvent, ChangeEvent, InputEvent};
+pub(crate) mod custom;
+pub use custom::{CustomDomEvent, CustomEvent, CustomEventType};
 
 pub(crate) struct DomListeners {
     #[allow(dead_code)]
@@ -53,6 +76,9 @@ pub(crate) struct HotEventList {
     tap: Option<Box<dyn 'static + Fn(&mut TapEvent)>>,
     long_tap: Option<Box<dyn 'static + Fn(&mut TapEvent)>>,
     cancel_tap: Option<Box<dyn 'static + Fn(&mut TapEvent)>>,
+    drag_start: Option<Box<dyn 'static + Fn(&mut DragEvent)>>,
+    drag_move: Option<Box<dyn 'static + Fn(&mut DragEvent)>>,
+    drag_end: Option<Box<dyn 'static + Fn(&mut DragEvent)>>,
 }
 
 // code event list is slow to visit but memory-efficient
@@ -87,10 +113,62 @@ pub(crate) enum ColdEventItem {
         Box<dyn 'static + Fn(&mut MouseEvent)>,
         Closure<dyn Fn(web_sys::MouseEvent)>,
     ),
+    PointerDown(
+        Box<dyn 'static + Fn(&mut PointerEvent)>,
+        Closure<dyn Fn(web_sys::PointerEvent)>,
+    ),
+    PointerMove(
+        Box<dyn 'static + Fn(&mut PointerEvent)>,
+        Closure<dyn Fn(web_sys::PointerEvent)>,
+    ),
+    PointerUp(
+        Box<dyn 'static + Fn(&mut PointerEvent)>,
+        Closure<dyn Fn(web_sys::PointerEvent)>,
+    ),
+    PointerCancel(
+        Box<dyn 'static + Fn(&mut PointerEvent)>,
+        Closure<dyn Fn(web_sys::PointerEvent)>,
+    ),
+    KeyDown(
+        Box<dyn 'static + Fn(&mut KeyboardEvent)>,
+        Closure<dyn Fn(web_sys::KeyboardEvent)>,
+    ),
+    KeyUp(
+        Box<dyn 'static + Fn(&mut KeyboardEvent)>,
+        Closure<dyn Fn(web_sys::KeyboardEvent)>,
+    ),
+    Focus(
+        Box<dyn 'static + Fn(&mut FocusEvent)>,
+        Closure<dyn Fn(web_sys::FocusEvent)>,
+    ),
+    Blur(
+        Box<dyn 'static + Fn(&mut FocusEvent)>,
+        Closure<dyn Fn(web_sys::FocusEvent)>,
+    ),
+    FocusIn(
+        Box<dyn 'static + Fn(&mut FocusEvent)>,
+        Closure<dyn Fn(web_sys::FocusEvent)>,
+    ),
+    FocusOut(
+        Box<dyn 'static + Fn(&mut FocusEvent)>,
+        Closure<dyn Fn(web_sys::FocusEvent)>,
+    ),
     Scroll(
         Box<dyn 'static + Fn(&mut ScrollEvent)>,
         Closure<dyn Fn(web_sys::Event)>,
     ),
+    Wheel(
+        Box<dyn 'static + Fn(&mut WheelEvent)>,
+        Closure<dyn Fn(web_sys::WheelEvent)>,
+    ),
+    Resize(
+        Box<dyn 'static + Fn(&mut ResizeEvent)>,
+        observer::ResizeObserverCb,
+    ),
+    Intersection(
+        Box<dyn 'static + Fn(&mut IntersectionEvent)>,
+        observer::IntersectionObserverCb,
+    ),
     AnimationStart(
         Box<dyn 'static + Fn(&mut AnimationEvent)>,
         Closure<dyn Fn(web_sys::AnimationEvent)>,
@@ -123,6 +201,38 @@ pub(crate) enum ColdEventItem {
         Box<dyn 'static + Fn(&mut TransitionEvent)>,
         Closure<dyn Fn(web_sys::TransitionEvent)>,
     ),
+    Load(
+        Box<dyn 'static + Fn(&mut LoadEvent)>,
+        Closure<dyn Fn(web_sys::Event)>,
+    ),
+    LoadError(
+        Box<dyn 'static + Fn(&mut LoadEvent)>,
+        Closure<dyn Fn(web_sys::Event)>,
+    ),
+    Play(
+        Box<dyn 'static + Fn(&mut MediaEvent)>,
+        Closure<dyn Fn(web_sys::Event)>,
+    ),
+    Pause(
+        Box<dyn 'static + Fn(&mut MediaEvent)>,
+        Closure<dyn Fn(web_sys::Event)>,
+    ),
+    Ended(
+        Box<dyn 'static + Fn(&mut MediaEvent)>,
+        Closure<dyn Fn(web_sys::Event)>,
+    ),
+    TimeUpdate(
+        Box<dyn 'static + Fn(&mut MediaEvent)>,
+        Closure<dyn Fn(web_sys::Event)>,
+    ),
+    LoadedMetadata(
+        Box<dyn 'static + Fn(&mut MediaEvent)>,
+        Closure<dyn Fn(web_sys::Event)>,
+    ),
+    MediaError(
+        Box<dyn 'static + Fn(&mut MediaEvent)>,
+        Closure<dyn Fn(web_sys::Event)>,
+    ),
     Submit(
         Box<dyn 'static + Fn(&mut SubmitEvent)>,
         Closure<dyn Fn(web_sys::SubmitEvent)>,
@@ -135,6 +245,11 @@ pub(crate) enum ColdEventItem {
         Box<dyn 'static + Fn(&mut InputEvent)>,
         Closure<dyn Fn(web_sys::InputEvent)>,
     ),
+    Custom(
+        &'static str,
+        Box<dyn 'static + Fn(&mut CustomEvent)>,
+        Closure<dyn Fn(web_sys::Event)>,
+    ),
 }
 
 impl ColdEventItem {
@@ -147,7 +262,26 @@ impl ColdEventItem {
             Self::MouseEnter(_, cb) => ("mouseenter", cb.as_ref()),
             Self::MouseLeave(_, cb) => ("mouseleave", cb.as_ref()),
             Self::Click(_, cb) => ("click", cb.as_ref()),
+            Self::PointerDown(_, cb) => ("pointerdown", cb.as_ref()),
+            Self::PointerMove(_, cb) => ("pointermove", cb.as_ref()),
+            Self::PointerUp(_, cb) => ("pointerup", cb.as_ref()),
+            Self::PointerCancel(_, cb) => ("pointercancel", cb.as_ref()),
+            Self::KeyDown(_, cb) => ("keydown", cb.as_ref()),
+            Self::KeyUp(_, cb) => ("keyup", cb.as_ref()),
+            Self::Focus(_, cb) => ("focus", cb.as_ref()),
+            Self::Blur(_, cb) => ("blur", cb.as_ref()),
+            Self::FocusIn(_, cb) => ("focusin", cb.as_ref()),
+            Self::FocusOut(_, cb) => ("focusout", cb.as_ref()),
             Self::Scroll(_, cb) => ("scroll", cb.as_ref()),
+            Self::Wheel(_, cb) => ("wheel", cb.as_ref()),
+            Self::Resize(_, observer) => {
+                observer.observe(elem);
+                return;
+            }
+            Self::Intersection(_, observer) => {
+                observer.observe(elem);
+                return;
+            }
             Self::AnimationStart(_, cb) => ("animationstart", cb.as_ref()),
             Self::AnimationIteration(_, cb) => ("animationiteration", cb.as_ref()),
             Self::AnimationEnd(_, cb) => ("animationend", cb.as_ref()),
@@ -156,9 +290,18 @@ impl ColdEventItem {
             Self::TransitionStart(_, cb) => ("transitionstart", cb.as_ref()),
             Self::TransitionEnd(_, cb) => ("transitionend", cb.as_ref()),
             Self::TransitionCancel(_, cb) => ("transitioncancel", cb.as_ref()),
+            Self::Load(_, cb) => ("load", cb.as_ref()),
+            Self::LoadError(_, cb) => ("error", cb.as_ref()),
+            Self::Play(_, cb) => ("play", cb.as_ref()),
+            Self::Pause(_, cb) => ("pause", cb.as_ref()),
+            Self::Ended(_, cb) => ("ended", cb.as_ref()),
+            Self::TimeUpdate(_, cb) => ("timeupdate", cb.as_ref()),
+            Self::LoadedMetadata(_, cb) => ("loadedmetadata", cb.as_ref()),
+            Self::MediaError(_, cb) => ("error", cb.as_ref()),
             Self::Submit(_, cb) => ("submit", cb.as_ref()),
             Self::Change(_, cb) => ("change", cb.as_ref()),
             Self::Input(_, cb) => ("input", cb.as_ref()),
+            Self::Custom(name, _, cb) => (name, cb.as_ref()),
         };
         // Seriously, there should be a removal on the element dropped,
         // otherwise the closure is lost and a js error is displayed in console.
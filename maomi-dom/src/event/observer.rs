@@ -0,0 +1,176 @@
+use wasm_bindgen::{prelude::*, JsCast};
+
+use super::{ColdEventItem, DomEventRegister};
+use crate::DomGeneralElement;
+
+/// A `ResizeObserver` which observes only one element.
+///
+/// It is disconnected when dropped,
+/// so the observer lives as long as the `DomElement` which holds it.
+pub(crate) struct ResizeObserverCb {
+    observer: Option<web_sys::ResizeObserver>,
+    #[allow(dead_code)]
+    cb: Closure<dyn Fn(js_sys::Array)>,
+}
+
+impl ResizeObserverCb {
+    fn new(f: impl 'static + Fn(web_sys::ResizeObserverEntry)) -> Self {
+        let cb = Closure::new(move |entries: js_sys::Array| {
+            for entry in entries.iter() {
+                f(entry.unchecked_into());
+            }
+        });
+        let observer = match web_sys::ResizeObserver::new(cb.as_ref().unchecked_ref()) {
+            Ok(x) => Some(x),
+            Err(err) => {
+                crate::log_js_error(&err);
+                log::error!("Failed creating ResizeObserver. The resize event will not be triggered.");
+                None
+            }
+        };
+        Self { observer, cb }
+    }
+
+    pub(crate) fn observe(&self, elem: &web_sys::Element) {
+        if let Some(x) = &self.observer {
+            x.observe(elem);
+        }
+    }
+}
+
+impl Drop for ResizeObserverCb {
+    fn drop(&mut self) {
+        if let Some(x) = &self.observer {
+            x.disconnect();
+        }
+    }
+}
+
+/// An `IntersectionObserver` which observes only one element.
+///
+/// It is disconnected when dropped,
+/// so the observer lives as long as the `DomElement` which holds it.
+pub(crate) struct IntersectionObserverCb {
+    observer: Option<web_sys::IntersectionObserver>,
+    #[allow(dead_code)]
+    cb: Closure<dyn Fn(js_sys::Array)>,
+}
+
+impl IntersectionObserverCb {
+    fn new(f: impl 'static + Fn(web_sys::IntersectionObserverEntry)) -> Self {
+        let cb = Closure::new(move |entries: js_sys::Array| {
+            for entry in entries.iter() {
+                f(entry.unchecked_into());
+            }
+        });
+        let observer = match web_sys::IntersectionObserver::new(cb.as_ref().unchecked_ref()) {
+            Ok(x) => Some(x),
+            Err(err) => {
+                crate::log_js_error(&err);
+                log::error!("Failed creating IntersectionObserver. The intersection event will not be triggered.");
+                None
+            }
+        };
+        Self { observer, cb }
+    }
+
+    pub(crate) fn observe(&self, elem: &web_sys::Element) {
+        if let Some(x) = &self.observer {
+            x.observe(elem);
+        }
+    }
+}
+
+impl Drop for IntersectionObserverCb {
+    fn drop(&mut self) {
+        if let Some(x) = &self.observer {
+            x.disconnect();
+        }
+    }
+}
+
+/// The resize event detail.
+///
+/// It is generated by a `ResizeObserver` ,
+/// and triggered once after binding and then whenever the element size changes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResizeEvent {
+    width: f64,
+    height: f64,
+}
+
+impl ResizeEvent {
+    /// Get the width of the content box.
+    #[inline]
+    pub fn width(&self) -> f64 {
+        self.width
+    }
+
+    /// Get the height of the content box.
+    #[inline]
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+}
+
+/// The intersection event detail.
+///
+/// It is generated by an `IntersectionObserver` with the viewport as root,
+/// and triggered once after binding and then whenever the element enters or leaves the viewport.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntersectionEvent {
+    is_intersecting: bool,
+    intersection_ratio: f64,
+}
+
+impl IntersectionEvent {
+    /// Check whether the element is (partially) visible in the viewport.
+    #[inline]
+    pub fn is_intersecting(&self) -> bool {
+        self.is_intersecting
+    }
+
+    /// Get the visible ratio of the element in range `[0, 1]` .
+    #[inline]
+    pub fn intersection_ratio(&self) -> f64 {
+        self.intersection_ratio
+    }
+}
+
+fn trigger_ev<T: DomEventRegister>(target: &web_sys::Element, mut detail: T::Detail) {
+    let target = crate::DomElement::from_event_dom_elem(target, false);
+    if let Some(n) = target {
+        if let DomGeneralElement::Element(x) = &mut *n.borrow_mut() {
+            T::trigger(x, &mut detail);
+        }
+    }
+}
+
+cold_event!(
+    Resize,
+    ResizeEvent,
+    ResizeObserverCb::new(move |entry| {
+        let rect = entry.content_rect();
+        trigger_ev::<Resize>(
+            &entry.target(),
+            ResizeEvent {
+                width: rect.width(),
+                height: rect.height(),
+            },
+        );
+    })
+);
+
+cold_event!(
+    Intersection,
+    IntersectionEvent,
+    IntersectionObserverCb::new(move |entry| {
+        trigger_ev::<Intersection>(
+            &entry.target(),
+            IntersectionEvent {
+                is_intersecting: entry.is_intersecting(),
+                intersection_ratio: entry.intersection_ratio(),
+            },
+        );
+    })
+);
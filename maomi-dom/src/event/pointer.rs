@@ -0,0 +1,228 @@
+use wasm_bindgen::{prelude::*, JsCast};
+
+use super::{utils, BubbleEvent, ColdEventItem, DomEventRegister, MouseButton};
+
+/// The type of the device that generates a pointer event.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PointerType {
+    /// A mouse.
+    Mouse,
+    /// A pen or stylus.
+    Pen,
+    /// A finger touch.
+    Touch,
+    /// Other unknown device.
+    Unknown(String),
+}
+
+/// The pointer event detail.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PointerEvent {
+    propagation_stopped: bool,
+    default_prevented: bool,
+    dom_event: web_sys::PointerEvent,
+}
+
+impl PointerEvent {
+    /// Get the identifier of the pointer.
+    ///
+    /// It is unique among the active pointers.
+    #[inline]
+    pub fn pointer_id(&self) -> i32 {
+        self.dom_event.pointer_id()
+    }
+
+    /// Get the device type of the pointer.
+    #[inline]
+    pub fn pointer_type(&self) -> PointerType {
+        match self.dom_event.pointer_type().as_str() {
+            "mouse" => PointerType::Mouse,
+            "pen" => PointerType::Pen,
+            "touch" => PointerType::Touch,
+            x => PointerType::Unknown(x.to_string()),
+        }
+    }
+
+    /// Check whether the pointer is the primary pointer of its type.
+    #[inline]
+    pub fn is_primary(&self) -> bool {
+        self.dom_event.is_primary()
+    }
+
+    /// Get the button that triggers `pointer_down` or `pointer_up` .
+    #[inline]
+    pub fn button(&self) -> MouseButton {
+        match self.dom_event.button() {
+            0 => MouseButton::Main,
+            1 => MouseButton::Auxiliary,
+            2 => MouseButton::Secondary,
+            3 => MouseButton::Fourth,
+            4 => MouseButton::Fifth,
+            x => MouseButton::Unknown(x),
+        }
+    }
+
+    /// Get the normalized pressure in range `[0, 1]` .
+    #[inline]
+    pub fn pressure(&self) -> f32 {
+        self.dom_event.pressure()
+    }
+
+    /// Get the width of the contact geometry in CSS pixels.
+    #[inline]
+    pub fn width(&self) -> i32 {
+        self.dom_event.width()
+    }
+
+    /// Get the height of the contact geometry in CSS pixels.
+    #[inline]
+    pub fn height(&self) -> i32 {
+        self.dom_event.height()
+    }
+
+    /// Check whether keyboard alt key is pressed.
+    #[inline]
+    pub fn alt_key(&self) -> bool {
+        self.dom_event.alt_key()
+    }
+
+    /// Check whether keyboard ctrl key is pressed.
+    #[inline]
+    pub fn ctrl_key(&self) -> bool {
+        self.dom_event.ctrl_key()
+    }
+
+    /// Check whether keyboard meta key is pressed.
+    #[inline]
+    pub fn meta_key(&self) -> bool {
+        self.dom_event.meta_key()
+    }
+
+    /// Check whether keyboard shift key is pressed.
+    #[inline]
+    pub fn shift_key(&self) -> bool {
+        self.dom_event.shift_key()
+    }
+
+    /// Get the x-position reletive to the viewport.
+    #[inline]
+    pub fn client_x(&self) -> i32 {
+        self.dom_event.client_x()
+    }
+
+    /// Get the y-position reletive to the viewport.
+    #[inline]
+    pub fn client_y(&self) -> i32 {
+        self.dom_event.client_y()
+    }
+
+    fn listening_element(&self) -> Option<web_sys::Element> {
+        self.dom_event
+            .current_target()
+            .and_then(|x| x.dyn_into::<web_sys::Element>().ok())
+    }
+
+    /// Capture the pointer to the listening element.
+    ///
+    /// Following pointer events of this pointer will be targeted at the listening element,
+    /// even if the pointer moves outside of it.
+    /// The capture is released automatically after `pointer_up` or `pointer_cancel` .
+    pub fn set_pointer_capture(&self) {
+        if let Some(elem) = self.listening_element() {
+            if let Err(err) = elem.set_pointer_capture(self.pointer_id()) {
+                crate::log_js_error(&err);
+            }
+        }
+    }
+
+    /// Release the pointer capture of the listening element.
+    pub fn release_pointer_capture(&self) {
+        if let Some(elem) = self.listening_element() {
+            if let Err(err) = elem.release_pointer_capture(self.pointer_id()) {
+                crate::log_js_error(&err);
+            }
+        }
+    }
+
+    /// Check whether the pointer is captured by the listening element.
+    pub fn has_pointer_capture(&self) -> bool {
+        self.listening_element()
+            .map(|elem| elem.has_pointer_capture(self.pointer_id()))
+            .unwrap_or(false)
+    }
+}
+
+impl BubbleEvent for PointerEvent {
+    #[inline]
+    fn stop_propagation(&mut self) {
+        if self.propagation_stopped {
+            return;
+        };
+        self.propagation_stopped = true;
+        self.dom_event.stop_propagation()
+    }
+
+    #[inline]
+    fn propagation_stopped(&self) -> bool {
+        self.propagation_stopped
+    }
+
+    #[inline]
+    fn prevent_default(&mut self) {
+        if self.default_prevented {
+            return;
+        };
+        self.default_prevented = true;
+        self.dom_event.prevent_default()
+    }
+
+    #[inline]
+    fn default_prevented(&self) -> bool {
+        self.default_prevented
+    }
+}
+
+fn trigger_ev<T: DomEventRegister<Detail = PointerEvent>>(dom_event: web_sys::PointerEvent) {
+    utils::trigger_on_current_target(dom_event, |x, dom_event| {
+        T::trigger(
+            x,
+            &mut PointerEvent {
+                propagation_stopped: false,
+                default_prevented: false,
+                dom_event,
+            },
+        );
+    });
+}
+
+cold_event!(
+    PointerDown,
+    PointerEvent,
+    Closure::new(move |dom_event: web_sys::PointerEvent| {
+        trigger_ev::<PointerDown>(dom_event);
+    })
+);
+
+cold_event!(
+    PointerMove,
+    PointerEvent,
+    Closure::new(move |dom_event: web_sys::PointerEvent| {
+        trigger_ev::<PointerMove>(dom_event);
+    })
+);
+
+cold_event!(
+    PointerUp,
+    PointerEvent,
+    Closure::new(move |dom_event: web_sys::PointerEvent| {
+        trigger_ev::<PointerUp>(dom_event);
+    })
+);
+
+cold_event!(
+    PointerCancel,
+    PointerEvent,
+    Closure::new(move |dom_event: web_sys::PointerEvent| {
+        trigger_ev::<PointerCancel>(dom_event);
+    })
+);
@@ -4,7 +4,7 @@ use wasm_bindgen::{prelude::*, JsCast};
 use super::{touch::TouchIdentifier, utils, BubbleEvent, DomEventRegister};
 use crate::DomGeneralElement;
 
-const CANCEL_TAP_DIST: i32 = 5;
+pub(super) const CANCEL_TAP_DIST: i32 = 5;
 const LONG_TAP_TIME_MS: i32 = 500;
 
 thread_local! {
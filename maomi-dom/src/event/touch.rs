@@ -1,7 +1,7 @@
 use maomi::backend::tree::ForestNodeRc;
 use wasm_bindgen::{prelude::*, JsCast};
 
-use super::{tap::TOUCH_TRACKER, utils, BubbleEvent, DomEventRegister};
+use super::{drag::DRAG_TRACKER, tap::TOUCH_TRACKER, utils, BubbleEvent, DomEventRegister};
 use crate::{DomGeneralElement, DOCUMENT};
 
 pub(super) struct TouchEventCbs {
@@ -153,6 +153,10 @@ fn add_mouse_event_listener_without_target(
 
 pub(super) fn init_dom_listeners(root: &web_sys::Element) -> TouchEventCbs {
     let touchstart = add_touch_event_listener::<TouchStart>(root, "touchstart", |target, ev| {
+        DRAG_TRACKER.with(|tracker| {
+            let tracker = &mut tracker.borrow_mut();
+            tracker.add(ev.identifier, target.clone(), ev.client_x, ev.client_y);
+        });
         TOUCH_TRACKER.with(|tracker| {
             let tracker = &mut tracker.borrow_mut();
             tracker.add(ev.identifier, target, ev.client_x, ev.client_y, true);
@@ -162,18 +166,30 @@ pub(super) fn init_dom_listeners(root: &web_sys::Element) -> TouchEventCbs {
         TOUCH_TRACKER.with(|tracker| {
             let tracker = &mut tracker.borrow_mut();
             tracker.update(ev.identifier, ev.client_x, ev.client_y);
+        });
+        DRAG_TRACKER.with(|tracker| {
+            let tracker = &mut tracker.borrow_mut();
+            tracker.update(ev.identifier, ev.client_x, ev.client_y);
         })
     });
     let touchend = add_touch_event_listener::<TouchEnd>(root, "touchend", |_, ev| {
         TOUCH_TRACKER.with(|tracker| {
             let tracker = &mut tracker.borrow_mut();
             tracker.remove(ev.identifier);
+        });
+        DRAG_TRACKER.with(|tracker| {
+            let tracker = &mut tracker.borrow_mut();
+            tracker.remove(ev.identifier, ev.client_x, ev.client_y);
         })
     });
     let touchcancel = add_touch_event_listener::<TouchCancel>(root, "touchcancel", |_, ev| {
         TOUCH_TRACKER.with(|tracker| {
             let tracker = &mut tracker.borrow_mut();
             tracker.interrupt(ev.identifier);
+        });
+        DRAG_TRACKER.with(|tracker| {
+            let tracker = &mut tracker.borrow_mut();
+            tracker.interrupt(ev.identifier, ev.client_x, ev.client_y);
         })
     });
     let mousedown = add_mouse_event_listener(root, "mousedown", |target, ev| {
@@ -183,6 +199,10 @@ pub(super) fn init_dom_listeners(root: &web_sys::Element) -> TouchEventCbs {
         TOUCH_TRACKER.with(|tracker| {
             let tracker = &mut tracker.borrow_mut();
             if !tracker.touch_mode() {
+                DRAG_TRACKER.with(|tracker| {
+                    let tracker = &mut tracker.borrow_mut();
+                    tracker.add(TouchIdentifier(0), target.clone(), ev.client_x(), ev.client_y());
+                });
                 tracker.add(
                     TouchIdentifier(0),
                     target,
@@ -203,6 +223,11 @@ pub(super) fn init_dom_listeners(root: &web_sys::Element) -> TouchEventCbs {
                 if !tracker.touch_mode() {
                     tracker.update(TouchIdentifier(0), ev.client_x(), ev.client_y());
                     tracker.remove(TouchIdentifier(0));
+                    DRAG_TRACKER.with(|tracker| {
+                        let tracker = &mut tracker.borrow_mut();
+                        tracker.update(TouchIdentifier(0), ev.client_x(), ev.client_y());
+                        tracker.remove(TouchIdentifier(0), ev.client_x(), ev.client_y());
+                    });
                 }
             })
         })
@@ -213,6 +238,10 @@ pub(super) fn init_dom_listeners(root: &web_sys::Element) -> TouchEventCbs {
                 let tracker = &mut tracker.borrow_mut();
                 if !tracker.touch_mode() {
                     tracker.update(TouchIdentifier(0), ev.client_x(), ev.client_y());
+                    DRAG_TRACKER.with(|tracker| {
+                        let tracker = &mut tracker.borrow_mut();
+                        tracker.update(TouchIdentifier(0), ev.client_x(), ev.client_y());
+                    });
                 }
             })
         })
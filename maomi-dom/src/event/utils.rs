@@ -1,10 +1,12 @@
 use maomi::backend::tree::ForestNodeRc;
+use wasm_bindgen::JsCast;
 
 use super::{BubbleEvent, DomEventRegister};
 use crate::DomGeneralElement;
 
 macro_rules! hot_event {
     ($t:ident, $field:ident, $detail:ty) => {
+        #[doc(hidden)]
         pub struct $t {}
 
         impl DomEventRegister for $t {
@@ -31,6 +33,7 @@ macro_rules! hot_event {
 
 macro_rules! cold_event {
     ($arm:ident, $detail:ty, $listen:expr) => {
+        #[doc(hidden)]
         pub struct $arm {}
 
         impl DomEventRegister for $arm {
@@ -84,6 +87,24 @@ macro_rules! cold_event {
     };
 }
 
+pub(super) fn trigger_on_current_target<E: AsRef<web_sys::Event>>(
+    dom_event: E,
+    f: impl FnOnce(&mut crate::base_element::DomElement, E),
+) {
+    // the event target may be a descendant of the listening element
+    // (i.e. a focused descendant or a node in the shadow tree),
+    // so the listening element is used instead
+    let target = dom_event
+        .as_ref()
+        .current_target()
+        .and_then(|x| crate::DomElement::from_event_dom_elem(x.unchecked_ref(), false));
+    if let Some(n) = target {
+        if let DomGeneralElement::Element(x) = &mut *n.borrow_mut() {
+            f(x, dom_event);
+        }
+    }
+}
+
 pub(super) fn bubble_event<T>(target: ForestNodeRc<DomGeneralElement>, detail: &mut T::Detail)
 where
     T: DomEventRegister,
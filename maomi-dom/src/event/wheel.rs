@@ -0,0 +1,139 @@
+use wasm_bindgen::prelude::*;
+
+use super::{utils, BubbleEvent, ColdEventItem, DomEventRegister};
+
+/// The unit of the delta values in a wheel event.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WheelDeltaMode {
+    /// The delta values are in pixels.
+    Pixel,
+    /// The delta values are in lines.
+    Line,
+    /// The delta values are in pages.
+    Page,
+}
+
+/// The wheel event detail.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WheelEvent {
+    propagation_stopped: bool,
+    default_prevented: bool,
+    dom_event: web_sys::WheelEvent,
+}
+
+impl WheelEvent {
+    /// Get the horizontal scroll amount.
+    #[inline]
+    pub fn delta_x(&self) -> f64 {
+        self.dom_event.delta_x()
+    }
+
+    /// Get the vertical scroll amount.
+    #[inline]
+    pub fn delta_y(&self) -> f64 {
+        self.dom_event.delta_y()
+    }
+
+    /// Get the z-axis scroll amount.
+    #[inline]
+    pub fn delta_z(&self) -> f64 {
+        self.dom_event.delta_z()
+    }
+
+    /// Get the unit of the delta values.
+    #[inline]
+    pub fn delta_mode(&self) -> WheelDeltaMode {
+        match self.dom_event.delta_mode() {
+            web_sys::WheelEvent::DOM_DELTA_LINE => WheelDeltaMode::Line,
+            web_sys::WheelEvent::DOM_DELTA_PAGE => WheelDeltaMode::Page,
+            _ => WheelDeltaMode::Pixel,
+        }
+    }
+
+    /// Check whether keyboard alt key is pressed.
+    #[inline]
+    pub fn alt_key(&self) -> bool {
+        self.dom_event.alt_key()
+    }
+
+    /// Check whether keyboard ctrl key is pressed.
+    #[inline]
+    pub fn ctrl_key(&self) -> bool {
+        self.dom_event.ctrl_key()
+    }
+
+    /// Check whether keyboard meta key is pressed.
+    #[inline]
+    pub fn meta_key(&self) -> bool {
+        self.dom_event.meta_key()
+    }
+
+    /// Check whether keyboard shift key is pressed.
+    #[inline]
+    pub fn shift_key(&self) -> bool {
+        self.dom_event.shift_key()
+    }
+
+    /// Get the x-position reletive to the viewport.
+    #[inline]
+    pub fn client_x(&self) -> i32 {
+        self.dom_event.client_x()
+    }
+
+    /// Get the y-position reletive to the viewport.
+    #[inline]
+    pub fn client_y(&self) -> i32 {
+        self.dom_event.client_y()
+    }
+}
+
+impl BubbleEvent for WheelEvent {
+    #[inline]
+    fn stop_propagation(&mut self) {
+        if self.propagation_stopped {
+            return;
+        };
+        self.propagation_stopped = true;
+        self.dom_event.stop_propagation()
+    }
+
+    #[inline]
+    fn propagation_stopped(&self) -> bool {
+        self.propagation_stopped
+    }
+
+    #[inline]
+    fn prevent_default(&mut self) {
+        if self.default_prevented {
+            return;
+        };
+        self.default_prevented = true;
+        self.dom_event.prevent_default()
+    }
+
+    #[inline]
+    fn default_prevented(&self) -> bool {
+        self.default_prevented
+    }
+}
+
+fn trigger_ev<T: DomEventRegister<Detail = WheelEvent>>(dom_event: web_sys::WheelEvent) {
+    utils::trigger_on_current_target(dom_event, |x, dom_event| {
+        T::trigger(
+            x,
+            &mut WheelEvent {
+                propagation_stopped: false,
+                default_prevented: false,
+                dom_event,
+            },
+        );
+    });
+}
+
+cold_event!(
+    Wheel,
+    WheelEvent,
+    Closure::new(move |dom_event: web_sys::WheelEvent| {
+        trigger_ev::<Wheel>(dom_event);
+    })
+);
@@ -0,0 +1,176 @@
+//! Focus management utilities.
+//!
+//! The focus of an element can be moved with `DomElementExt::focus` and `DomElementExt::blur` .
+//! A `FocusTrap` keeps the tab-order inside an element,
+//! which is useful for modals and dialogs.
+
+use wasm_bindgen::{prelude::*, JsCast};
+
+/// The CSS selector which matches the elements that may be focused by tab key.
+///
+/// The matched elements are further filtered by `tabindex` and `hidden` .
+pub const FOCUSABLE_SELECTOR: &str = "a[href], area[href], button:not([disabled]), \
+    input:not([disabled]):not([type=\"hidden\"]), select:not([disabled]), textarea:not([disabled]), \
+    iframe, audio[controls], video[controls], [contenteditable]:not([contenteditable=\"false\"]), \
+    [tabindex]";
+
+pub(crate) fn focus(elem: &web_sys::Element) {
+    // `focus` is defined in both HTML and SVG elements
+    if let Err(err) = elem.unchecked_ref::<web_sys::HtmlElement>().focus() {
+        crate::log_js_error(&err);
+    }
+}
+
+pub(crate) fn blur(elem: &web_sys::Element) {
+    if let Err(err) = elem.unchecked_ref::<web_sys::HtmlElement>().blur() {
+        crate::log_js_error(&err);
+    }
+}
+
+fn active_element() -> Option<web_sys::Element> {
+    crate::DOCUMENT.with(|document| document.active_element())
+}
+
+pub(crate) fn has_focus(elem: &web_sys::Element) -> bool {
+    active_element().map(|x| &x == elem).unwrap_or(false)
+}
+
+pub(crate) fn contains_focus(elem: &web_sys::Element) -> bool {
+    match active_element() {
+        None => false,
+        Some(x) => elem.contains(Some(&x)),
+    }
+}
+
+fn focusable_elements(container: &web_sys::Element) -> Vec<web_sys::HtmlElement> {
+    let list = match container.query_selector_all(FOCUSABLE_SELECTOR) {
+        Ok(x) => x,
+        Err(err) => {
+            crate::log_js_error(&err);
+            return vec![];
+        }
+    };
+    let mut ret = Vec::with_capacity(list.length() as usize);
+    for i in 0..list.length() {
+        let e = match list.item(i).and_then(|x| x.dyn_into::<web_sys::HtmlElement>().ok()) {
+            Some(x) => x,
+            None => continue,
+        };
+        if e.tab_index() < 0 || e.hidden() {
+            continue;
+        }
+        ret.push(e);
+    }
+    ret
+}
+
+/// A tab-order trap.
+///
+/// When the trap is active, the tab key (and shift-tab) moves focus
+/// between the focusable descendants of the container in a loop.
+/// It is created by `DomElementExt::trap_focus` .
+/// When dropped, the trap is released and the focus is restored to the element focused before.
+pub struct FocusTrap {
+    container: web_sys::Element,
+    listener: Closure<dyn Fn(web_sys::KeyboardEvent)>,
+    prev_focused: Option<web_sys::Element>,
+}
+
+impl FocusTrap {
+    pub(crate) fn new(container: &web_sys::Element) -> Self {
+        let prev_focused = active_element();
+        let c = container.clone();
+        let listener = Closure::new(move |ev: web_sys::KeyboardEvent| {
+            if ev.key() != "Tab" || ev.default_prevented() {
+                return;
+            }
+            let list = focusable_elements(&c);
+            let (first, last) = match (list.first(), list.last()) {
+                (Some(first), Some(last)) => (first, last),
+                _ => {
+                    // nothing can be focused, so keep the focus unchanged
+                    ev.prevent_default();
+                    return;
+                }
+            };
+            let active = active_element().filter(|x| c.contains(Some(x)));
+            let next = match active {
+                None => Some(if ev.shift_key() { last } else { first }),
+                Some(active) if ev.shift_key() && first.is_same_node(Some(&active)) => Some(last),
+                Some(active) if !ev.shift_key() && last.is_same_node(Some(&active)) => Some(first),
+                Some(_) => None,
+            };
+            if let Some(next) = next {
+                ev.prevent_default();
+                focus(next);
+            }
+        });
+        if let Err(err) = container.add_event_listener_with_callback("keydown", listener.as_ref().unchecked_ref()) {
+            crate::log_js_error(&err);
+            log::error!("Failed adding listener for focus trap. The focus will not be trapped.");
+        }
+        let ret = Self {
+            container: container.clone(),
+            listener,
+            prev_focused,
+        };
+        if !contains_focus(container) {
+            ret.focus_first();
+        }
+        ret
+    }
+
+    /// Get the container element.
+    #[inline]
+    pub fn container(&self) -> &web_sys::Element {
+        &self.container
+    }
+
+    /// Move focus to the first focusable descendant.
+    ///
+    /// Returns `false` if there is no focusable descendant.
+    pub fn focus_first(&self) -> bool {
+        match focusable_elements(&self.container).first() {
+            Some(x) => {
+                focus(x);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Move focus to the last focusable descendant.
+    ///
+    /// Returns `false` if there is no focusable descendant.
+    pub fn focus_last(&self) -> bool {
+        match focusable_elements(&self.container).last() {
+            Some(x) => {
+                focus(x);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Drop for FocusTrap {
+    fn drop(&mut self) {
+        let _ = self
+            .container
+            .remove_event_listener_with_callback("keydown", self.listener.as_ref().unchecked_ref());
+        if let Some(prev) = self.prev_focused.take() {
+            // do not steal the focus if it has been moved outside by others
+            let focus_lost = match active_element() {
+                None => true,
+                Some(x) => {
+                    self.container.contains(Some(&x))
+                        || crate::DOCUMENT.with(|document| document.body().map(|b| b.is_same_node(Some(&x))))
+                            .unwrap_or(false)
+                }
+            };
+            if prev.is_connected() && focus_lost {
+                focus(&prev);
+            }
+        }
+    }
+}
@@ -105,9 +105,131 @@ mod text_node;
 use text_node::DomTextNode;
 pub mod class_list;
 pub mod dynamic_style;
+pub mod data_list;
+pub mod focus;
+pub mod aria;
+pub mod custom_element;
+pub mod portal;
 mod composing;
+mod mount_target;
 pub mod event;
 use event::DomListeners;
+#[cfg(feature = "prerendering")]
+mod prerendering;
+#[cfg(feature = "prerendering-apply")]
+mod rematch;
+#[cfg(feature = "prerendering-apply")]
+pub use rematch::{PrerenderedMismatch, PrerenderedMismatchKind, PrerenderedMismatchMode};
+
+// the items used by the code generated by `dom_element_definition` and `custom_element_definition`
+//
+// The generated code may live in other crates,
+// so it should only use the functions here instead of the backend internals.
+#[doc(hidden)]
+pub mod __element_macro {
+    use super::*;
+
+    pub use crate::{
+        base_element::{
+            AttributeValue, DomAttrInit, DomBindingBoolAttr, DomBindingF64Attr, DomBindingStrAttr,
+            DomBoolAttr, DomElement, DomElementBase, DomElementLazy, DomF64Attr, DomI32Attr,
+            DomLocaleStrAttr, DomStrAttr, DomTypedAttr, DomU32Attr,
+        },
+        class_list::DomClassList,
+        custom_element::DomProperty,
+        data_list::{DomAttrList, DomDataList},
+        dynamic_style::DomStyleList,
+        event::DomEvent,
+        DomBackend, DomGeneralElement, MaybeJsStr,
+    };
+
+    /// The event types of the common events.
+    pub mod events {
+        pub use crate::event::{
+            animation::{AnimationCancel, AnimationEnd, AnimationIteration, AnimationStart},
+            drag::{DragEnd, DragMove, DragStart},
+            focus::{Blur, Focus, FocusIn, FocusOut},
+            keyboard::{KeyDown, KeyUp},
+            mouse::{Click, MouseDown, MouseEnter, MouseLeave, MouseMove, MouseUp},
+            observer::{Intersection, Resize},
+            pointer::{PointerCancel, PointerDown, PointerMove, PointerUp},
+            scroll::Scroll,
+            tap::{CancelTap, LongTap, Tap},
+            touch::{TouchCancel, TouchEnd, TouchMove, TouchStart},
+            transition::{TransitionCancel, TransitionEnd, TransitionRun, TransitionStart},
+            wheel::Wheel,
+        };
+    }
+
+    /// A DOM element which is created but not attached to the backend tree yet.
+    pub struct NewDomElement {
+        elem: dom_state_ty!(web_sys::Element, PrerenderingElement, RematchedDomElem),
+    }
+
+    /// Create a DOM element with the tag name.
+    #[inline]
+    pub fn create_dom_element(
+        owner: &ForestNodeMut<DomGeneralElement>,
+        tag_name: &MaybeJsStr,
+    ) -> NewDomElement {
+        NewDomElement {
+            elem: owner.create_dom_element_by_tag_name(tag_name),
+        }
+    }
+
+    /// Create a DOM element with the namespace and the tag name.
+    #[inline]
+    pub fn create_dom_element_ns(
+        owner: &ForestNodeMut<DomGeneralElement>,
+        namespace: &MaybeJsStr,
+        tag_name: &MaybeJsStr,
+    ) -> NewDomElement {
+        NewDomElement {
+            elem: owner.create_dom_element_by_tag_name_ns(namespace, tag_name),
+        }
+    }
+
+    /// Attach a DOM element to the backend tree.
+    ///
+    /// Returns the backend tree node, the class list and the lazy ref of the element.
+    #[inline]
+    pub fn attach_dom_element(
+        owner: &mut ForestNodeMut<DomGeneralElement>,
+        elem: NewDomElement,
+    ) -> (ForestNodeRc<DomGeneralElement>, DomClassList, DomElementLazy) {
+        let NewDomElement { elem } = elem;
+        let backend_element = DomGeneralElement::wrap_dom_element(owner, &elem);
+        let class = DomClassList::new(&elem);
+        let lazy = DomElementLazy::new(DomGeneralElement::to_lazy(elem));
+        (backend_element, class, lazy)
+    }
+
+    /// Get the DOM element in a backend tree node.
+    #[inline]
+    pub fn dom_element_mut<'b>(
+        node: &'b mut ForestNodeMut<DomGeneralElement>,
+    ) -> Result<ForestValueMut<'b, DomElement>, Error> {
+        DomGeneralElement::as_dom_element_mut(node).ok_or(Error::TreeNodeTypeWrong)
+    }
+
+    /// Listen an event for a binding attribute.
+    #[inline]
+    pub fn init_binding_prop(
+        target: &mut DomElement,
+        name: &'static str,
+        f: impl 'static + Fn(web_sys::Event),
+    ) {
+        crate::base_element::init_binding_prop(target, name, f)
+    }
+    pub use maomi::{
+        backend::{tree::*, BackendComponent, SupportBackend},
+        error::Error,
+        node::{OwnerWeak, SlotChange, StaticSingleSlot},
+        BackendContext,
+    };
+    pub use wasm_bindgen::JsCast;
+    pub use web_sys as __web_sys;
+}
 
 /// The types that should usually be imported.
 /// 
@@ -149,30 +271,38 @@ pub(crate) enum DomState<T> {
 
 #[cfg(all(not(feature = "prerendering"), feature = "prerendering-apply"))]
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub enum DomState<T, V> {
+pub(crate) enum DomState<T, V> {
     Normal(T),
     PrerenderingApply(V),
 }
 
 #[cfg(all(feature = "prerendering", not(feature = "prerendering-apply")))]
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub enum DomState<T, U> {
+pub(crate) enum DomState<T, U> {
     Normal(T),
     Prerendering(U),
 }
 
 #[cfg(all(feature = "prerendering", feature = "prerendering-apply"))]
 #[derive(Debug, Clone, Copy, PartialEq)]
-enum DomState<T, U, V> {
+pub(crate) enum DomState<T, U, V> {
     Normal(T),
     Prerendering(U),
     PrerenderingApply(V),
 }
 
+/// The default element id of the inline script written by `DomBackend::write_prerendering_data`
+///
+/// When there are multiple mount points in a page, each of them should use a different id.
+#[cfg(feature = "serde")]
+pub const PRERENDERING_DATA_SCRIPT_ID: &str = "maomi-prerendering-data";
+
 #[derive(Debug, Default)]
 pub(crate) struct WriteHtmlState {
     #[allow(dead_code)]
     prev_is_text_node: bool,
+    #[allow(dead_code)]
+    namespaces: Vec<Option<&'static str>>,
 }
 
 /// A DOM backend
@@ -181,6 +311,10 @@ pub struct DomBackend {
     tree: tree::ForestNodeRc<DomGeneralElement>,
     #[allow(dead_code)]
     listeners: dom_state_ty!(DomListeners, (), ()),
+    shadow_root: Option<web_sys::ShadowRoot>,
+    mount_target_listeners: mount_target::MountTargetListeners,
+    #[cfg(feature = "prerendering-apply")]
+    prerendered_mismatch_mode: PrerenderedMismatchMode,
 }
 
 impl DomBackend {
@@ -215,6 +349,131 @@ impl DomBackend {
         Ok(Self::wrap_root_element(dom_elem.into())?)
     }
 
+    /// Create a backend that rendering in a new shadow root of the specified DOM element
+    ///
+    /// The content in the shadow root is not affected by the styles of the host page.
+    /// The CSS of the components should be added with `adopt_style_sheet` .
+    /// The content is placed in a `<div style="display: contents">` container in the shadow root.
+    pub fn new_with_shadow_root(
+        host: &web_sys::Element,
+        mode: web_sys::ShadowRootMode,
+    ) -> Result<Self, Error> {
+        let shadow_root = host
+            .attach_shadow(&web_sys::ShadowRootInit::new(mode))
+            .map_err(|err| {
+                log_js_error(&err);
+                Error::BackendError {
+                    msg: "Cannot attach a shadow root".into(),
+                    err: None,
+                }
+            })?;
+        // the shadow root is not an element,
+        // so a container element is placed in it as the backend root
+        let container = DOCUMENT
+            .with(|document| document.create_element("div"))
+            .and_then(|x| {
+                x.set_attribute("style", "display: contents")?;
+                shadow_root.append_child(&x)?;
+                Ok(x)
+            })
+            .map_err(|err| {
+                log_js_error(&err);
+                Error::BackendError {
+                    msg: "Cannot create the container in the shadow root".into(),
+                    err: None,
+                }
+            })?;
+        let mut ret = Self::wrap_root_element(container)?;
+        ret.shadow_root = Some(shadow_root);
+        Ok(ret)
+    }
+
+    /// Get the shadow root if the backend is created by `new_with_shadow_root`
+    #[inline]
+    pub fn shadow_root(&self) -> Option<&web_sys::ShadowRoot> {
+        self.shadow_root.as_ref()
+    }
+
+    /// Add the CSS text as a constructed stylesheet
+    ///
+    /// The stylesheet is adopted by the shadow root if the backend is created by `new_with_shadow_root` ,
+    /// otherwise it is adopted by the document.
+    /// The CSS text of a `stylesheet!` can be generated with the `#![css_text(NAME)]` attribute,
+    /// which contains the classes and the keyframes defined in it.
+    /// It does nothing in prerendering.
+    pub fn adopt_style_sheet(&self, css_text: &str) -> Result<(), Error> {
+        #[cfg(feature = "prerendering")]
+        if self.backend_stage == BackendStage::Prerendering {
+            return Ok(());
+        }
+        let style_sheet = web_sys::CssStyleSheet::new()
+            .and_then(|x| x.replace_sync(css_text).map(|_| x))
+            .map_err(|err| {
+                log_js_error(&err);
+                Error::BackendError {
+                    msg: "Cannot construct the stylesheet".into(),
+                    err: None,
+                }
+            })?;
+        match &self.shadow_root {
+            Some(shadow_root) => {
+                let list = shadow_root.adopted_style_sheets();
+                list.push(&style_sheet);
+                shadow_root.set_adopted_style_sheets(&list);
+            }
+            None => DOCUMENT.with(|document| {
+                let list = document.adopted_style_sheets();
+                list.push(&style_sheet);
+                document.set_adopted_style_sheets(&list);
+            }),
+        }
+        Ok(())
+    }
+
+    /// Wrap an existing DOM element as a mount target
+    ///
+    /// The returned node can be used in `EnteredBackendContext::attach_at` ,
+    /// so that a component can be mounted under a DOM element outside the backend root.
+    /// This allows several islands in a page sharing one backend context.
+    /// The tap, touch and drag events are also listened in the `dom_elem` if it is not in the backend root,
+    /// until the returned node is dropped.
+    /// If mount targets are nested, these events are only listened in the outermost one.
+    /// It is only available in the normal backend stage.
+    pub fn mount_target(
+        &mut self,
+        dom_elem: web_sys::Element,
+    ) -> Result<ForestNodeRc<DomGeneralElement>, Error> {
+        if self.backend_stage != BackendStage::Normal {
+            return Err(Error::BackendError {
+                msg: "Mount targets are only available in the normal backend stage".into(),
+                err: None,
+            });
+        }
+        let mut root = self.tree.try_borrow_mut().ok_or(Error::BackendError {
+            msg: "Cannot create mount targets while visiting".to_string(),
+            err: None,
+        })?;
+        let in_root = match &*root {
+            DomGeneralElement::Element(x) => x.composing_dom().contains(Some(&dom_elem)),
+            _ => unreachable!(),
+        };
+        let guard = match in_root {
+            true => None,
+            false => Some(self.mount_target_listeners.add(&dom_elem)),
+        };
+        let ret = root.new_tree(DomGeneralElement::Element(unsafe {
+            DomElement::new(DomState::Normal(dom_elem))
+        }));
+        let token = ret.token();
+        if let DomGeneralElement::Element(x) = &mut *root.borrow_mut(&ret) {
+            x.init(token);
+            x.mount_target = guard;
+        } else {
+            unreachable!()
+        }
+        Ok(ret)
+    }
+
     fn wrap_root_element(dom_elem: web_sys::Element) -> Result<Self, Error> {
         let listeners = DomState::Normal(event::DomListeners::new(&dom_elem));
         let tree_root = {
@@ -233,6 +492,10 @@ impl DomBackend {
             backend_stage: BackendStage::Normal,
             tree: tree_root,
             listeners,
+            shadow_root: None,
+            mount_target_listeners: Default::default(),
+            #[cfg(feature = "prerendering-apply")]
+            prerendered_mismatch_mode: Default::default(),
         })
     }
 
@@ -259,6 +522,10 @@ impl DomBackend {
             backend_stage: BackendStage::Prerendering,
             tree: tree_root,
             listeners: DomState::Prerendering(()),
+            shadow_root: None,
+            mount_target_listeners: Default::default(),
+            #[cfg(feature = "prerendering-apply")]
+            prerendered_mismatch_mode: Default::default(),
         }
     }
 
@@ -273,6 +540,103 @@ impl DomBackend {
         DomGeneralElement::write_inner_html(&self.root(), w, &mut state)
     }
 
+    /// Write the prerendering result to a `Write` progressively
+    ///
+    /// Unlike `write_prerendering_html` ,
+    /// this function can be called before the prerendering tasks
+    /// (returned by `Component::prerendering_created` ) finished.
+    /// The content before a component with pending task is written and flushed immediately,
+    /// and the rest is written after the task finished.
+    #[cfg(feature = "prerendering")]
+    pub async fn write_prerendering_html_streaming(
+        backend_context: &maomi::BackendContext<Self>,
+        w: &mut impl std::io::Write,
+    ) -> std::io::Result<()> {
+        let mut chunks = prerendering::PrerenderingHtmlChunks::new(backend_context);
+        while let Some(chunk) = chunks.next_chunk().await {
+            w.write_all(chunk.as_bytes())?;
+            w.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Get the prerendering result as a `Stream` of HTML chunks
+    ///
+    /// This is similar to `write_prerendering_html_streaming` ,
+    /// but the chunks are provided as a `Stream` ,
+    /// which can be used as the response body of many HTTP servers.
+    #[cfg(feature = "prerendering")]
+    pub fn prerendering_html_stream(
+        backend_context: &maomi::BackendContext<Self>,
+    ) -> impl futures::Stream<Item = String> {
+        let chunks = prerendering::PrerenderingHtmlChunks::new(backend_context);
+        futures::stream::unfold(chunks, |mut chunks| async move {
+            let chunk = chunks.next_chunk().await?;
+            Some((chunk, chunks))
+        })
+    }
+
+    /// Write the prerendering data as an inline script to a `Write`
+    ///
+    /// The data is serialized as JSON and placed in a `<script type="application/json">` element,
+    /// which can be placed anywhere in the full HTML file.
+    /// The `id` is the element id of the script, i.e. `PRERENDERING_DATA_SCRIPT_ID` ,
+    /// and different mount points in the same page should use different ids.
+    /// In the prerendering-apply process (client-side),
+    /// `new_prerendered_with_data` can read it back with the same id.
+    #[cfg(all(feature = "prerendering", feature = "serde"))]
+    pub fn write_prerendering_data<C: maomi::prelude::PrerenderableComponent>(
+        prerendering_data: &maomi::PrerenderingData<C>,
+        id: &str,
+        w: &mut impl std::io::Write,
+    ) -> std::io::Result<()>
+    where
+        C::PrerenderingData: serde::Serialize,
+    {
+        let json = serde_json::to_string(prerendering_data.get())?;
+        write!(w, r#"<script type="application/json" id=""#)?;
+        html_escape::encode_double_quoted_attribute_to_writer(id, w)?;
+        write!(w, r#"">"#)?;
+        // `<` `>` `&` can only appear in JSON strings, so escaping them is safe
+        for c in json.chars() {
+            match c {
+                '<' => write!(w, r"\u003c")?,
+                '>' => write!(w, r"\u003e")?,
+                '&' => write!(w, r"\u0026")?,
+                c => write!(w, "{}", c)?,
+            }
+        }
+        write!(w, "</script>")
+    }
+
+    /// Prepare a backend for using the prerendering result, and read the prerendering data
+    ///
+    /// The prerendering data should be written by `write_prerendering_data` in the prerendering process,
+    /// and the `id` should be the same as the one used in `write_prerendering_data` .
+    /// The inline script element is removed from the document after read,
+    /// and the returned data can be used in `EnteredBackendContext::prerendering_attach` .
+    #[cfg(all(feature = "prerendering-apply", feature = "serde"))]
+    pub fn new_prerendered_with_data<C: maomi::prelude::PrerenderableComponent>(
+        id: &str,
+    ) -> Result<(Self, maomi::PrerenderingData<C>), Error>
+    where
+        C::PrerenderingData: serde::de::DeserializeOwned,
+    {
+        let script = DOCUMENT
+            .with(|document| document.get_element_by_id(id))
+            .ok_or_else(|| Error::BackendError {
+                msg: "Cannot find the prerendering data".into(),
+                err: None,
+            })?;
+        let json = script.text_content().unwrap_or_default();
+        script.remove();
+        let data = serde_json::from_str(&json).map_err(|err| Error::BackendError {
+            msg: "Failed to parse the prerendering data".into(),
+            err: Some(Box::new(err)),
+        })?;
+        Ok((Self::new_prerendered(), maomi::PrerenderingData::new(data)))
+    }
+
     /// Prepare a backend for using the prerendering result
     ///
     /// The prerendering result can be attached later with one of the `apply_prerendered_*` method.
@@ -282,7 +646,7 @@ impl DomBackend {
         let tree_root = {
             let ret = tree::ForestNodeRc::new_forest(DomGeneralElement::Element(unsafe {
                 DomElement::new(DomState::PrerenderingApply(
-                    base_element::RematchedDomElem::new(),
+                    base_element::RematchedDomElem::new(""),
                 ))
             }));
             let token = ret.token();
@@ -297,9 +661,23 @@ impl DomBackend {
             backend_stage: BackendStage::PrerenderingApply,
             tree: tree_root,
             listeners: DomState::PrerenderingApply(()),
+            shadow_root: None,
+            mount_target_listeners: Default::default(),
+            #[cfg(feature = "prerendering-apply")]
+            prerendered_mismatch_mode: Default::default(),
         }
     }
 
+    /// Set the behavior when the prerendering result does not match the component tree
+    ///
+    /// The `apply_prerendered_*` methods check the tag names, the text content and the child nodes.
+    /// By default, an error is returned on mismatches.
+    #[cfg(feature = "prerendering-apply")]
+    #[inline]
+    pub fn set_prerendered_mismatch_mode(&mut self, mode: PrerenderedMismatchMode) {
+        self.prerendered_mismatch_mode = mode;
+    }
+
     /// Attach the prerendering result with the specified DOM element
     #[cfg(feature = "prerendering-apply")]
     #[inline]
@@ -342,86 +720,11 @@ impl DomBackend {
         }
         self.backend_stage = BackendStage::Normal;
         self.listeners = DomState::Normal(event::DomListeners::new(&dom_elem));
-        fn rematch_dom<'a>(
-            n: &mut ForestNodeMut<'a, DomGeneralElement>,
-            next_dom_elem: Option<web_sys::Node>,
-            state: &mut WriteHtmlState,
-        ) -> Result<Option<web_sys::Node>, Error> {
-            enum ChildMatchKind {
-                Virtual(Option<web_sys::Node>),
-                NonVirtual(web_sys::Node),
-            }
-            let child_match_kind = {
-                let ge: &mut DomGeneralElement = n;
-                match ge {
-                    DomGeneralElement::Text(x) => {
-                        let mut e = next_dom_elem.ok_or(Error::BackendError {
-                            msg: "Failed to apply a prerendered node".to_string(),
-                            err: None,
-                        })?;
-                        if state.prev_is_text_node {
-                            e = e.next_sibling().ok_or(Error::BackendError {
-                                msg: "Failed to apply a prerendered node".to_string(),
-                                err: None,
-                            })?;
-                        } else {
-                            state.prev_is_text_node = true;
-                        };
-                        let ret = e.next_sibling();
-                        x.rematch_dom(e);
-                        return Ok(ret);
-                    }
-                    DomGeneralElement::Virtual(x) => {
-                        x.rematch_dom();
-                        ChildMatchKind::Virtual(next_dom_elem)
-                    },
-                    DomGeneralElement::Element(x) => {
-                        let e = next_dom_elem.ok_or(Error::BackendError {
-                            msg: "Failed to apply a prerendered node".to_string(),
-                            err: None,
-                        })?;
-                        x.rematch_dom(e.clone());
-                        state.prev_is_text_node = false;
-                        ChildMatchKind::NonVirtual(e)
-                    }
-                }
-            };
-            let mut rematch_child = |mut child_dom_elem| -> Result<_, Error> {
-                if let Some(mut child) = n.first_child_rc() {
-                    loop {
-                        let c = {
-                            let child_mut = &mut n.borrow_mut(&child);
-                            child_dom_elem = rematch_dom(child_mut, child_dom_elem, state)?;
-                            child_mut.next_sibling_rc()
-                        };
-                        match c {
-                            None => break,
-                            Some(c) => {
-                                child = c;
-                            }
-                        }
-                    }
-                }
-                Ok(child_dom_elem)
-            };
-            match child_match_kind {
-                ChildMatchKind::Virtual(x) => rematch_child(x),
-                ChildMatchKind::NonVirtual(x) => {
-                    rematch_child(x.first_child())?;
-                    state.prev_is_text_node = false;
-                    Ok(x.next_sibling())
-                }
-            }
-        }
         let mut tree = self.tree.try_borrow_mut().ok_or(Error::BackendError {
             msg: "Cannot apply prerendered tree while visiting".to_string(),
             err: None,
         })?;
-        rematch_dom(
-            &mut tree,
-            Some(dom_elem.unchecked_into()),
-            &mut Default::default(),
-        )?;
+        rematch::rematch_root(&mut tree, dom_elem, self.prerendered_mismatch_mode)?;
         Ok(())
     }
 }
@@ -463,6 +766,8 @@ pub enum DomGeneralElement {
 extern "C" {
     #[wasm_bindgen(js_namespace = document, js_name = createElement)]
     fn document_create_element(n: &JsString) -> web_sys::Element;
+    #[wasm_bindgen(js_namespace = document, js_name = createElementNS)]
+    fn document_create_element_ns(ns: &JsString, n: &JsString) -> web_sys::Element;
 }
 
 impl std::fmt::Debug for DomGeneralElement {
@@ -496,7 +801,24 @@ impl DomGeneralElement {
             #[cfg(feature = "prerendering")]
             DomState::Prerendering(_) => DomState::Prerendering(PrerenderingElement::new(_tag_name.s)),
             #[cfg(feature = "prerendering-apply")]
-            DomState::PrerenderingApply(_) => DomState::PrerenderingApply(RematchedDomElem::new()),
+            DomState::PrerenderingApply(_) => DomState::PrerenderingApply(RematchedDomElem::new(_tag_name.s)),
+        }
+    }
+
+    pub(crate) fn create_dom_element_by_tag_name_ns(
+        &self,
+        _namespace: &MaybeJsStr,
+        _tag_name: &MaybeJsStr,
+    ) -> dom_state_ty!(web_sys::Element, PrerenderingElement, RematchedDomElem) {
+        match self.is_prerendering() {
+            #[cfg(target_arch = "wasm32")]
+            DomState::Normal(_) => DomState::Normal(document_create_element_ns(&_namespace.js, &_tag_name.js)),
+            #[cfg(not(target_arch = "wasm32"))]
+            DomState::Normal(_) => panic!("not available in non-web environment"),
+            #[cfg(feature = "prerendering")]
+            DomState::Prerendering(_) => DomState::Prerendering(PrerenderingElement::new_ns(_namespace.s, _tag_name.s)),
+            #[cfg(feature = "prerendering-apply")]
+            DomState::PrerenderingApply(_) => DomState::PrerenderingApply(RematchedDomElem::new_ns(_namespace.s, _tag_name.s)),
         }
     }
 
@@ -554,7 +876,11 @@ impl DomGeneralElement {
             Self::Element(x) => {
                 x.write_inner_html(this, w, state)?;
             }
-            Self::Virtual(_) => {
+            Self::Virtual(x) => {
+                // the portal content is not placed here
+                if x.is_portal() {
+                    return Ok(());
+                }
                 let mut cur = this.first_child();
                 while let Some(c) = &cur {
                     Self::write_outer_html(&c, w, state)?;
@@ -577,7 +903,11 @@ impl DomGeneralElement {
             Self::Element(x) => {
                 x.write_outer_html(this, w, state)?;
             }
-            Self::Virtual(_) => {
+            Self::Virtual(x) => {
+                // the portal content is not placed here
+                if x.is_portal() {
+                    return Ok(());
+                }
                 let mut cur = this.first_child();
                 while let Some(c) = &cur {
                     Self::write_outer_html(&c, w, state)?;
@@ -0,0 +1,82 @@
+use std::{
+    cell::RefCell,
+    rc::{Rc, Weak},
+};
+
+use crate::event::DomListeners;
+
+struct MountTargetEntry {
+    elem: web_sys::Element,
+    refs: usize,
+    listeners: Option<DomListeners>,
+}
+
+// the tap, touch and drag listeners of the mount targets outside the backend root
+//
+// Only the outermost targets are listened,
+// otherwise the events of the nested targets are triggered more than once.
+#[derive(Default)]
+pub(crate) struct MountTargetListeners {
+    entries: Rc<RefCell<Vec<MountTargetEntry>>>,
+}
+
+impl MountTargetListeners {
+    pub(crate) fn add(&self, elem: &web_sys::Element) -> MountTargetGuard {
+        let mut entries = self.entries.borrow_mut();
+        if let Some(entry) = entries.iter_mut().find(|x| &x.elem == elem) {
+            entry.refs += 1;
+        } else {
+            entries.push(MountTargetEntry {
+                elem: elem.clone(),
+                refs: 1,
+                listeners: None,
+            });
+            refresh(&mut entries);
+        }
+        MountTargetGuard {
+            entries: Rc::downgrade(&self.entries),
+            elem: elem.clone(),
+        }
+    }
+}
+
+fn refresh(entries: &mut [MountTargetEntry]) {
+    for i in 0..entries.len() {
+        let elem = &entries[i].elem;
+        let outermost = entries
+            .iter()
+            .enumerate()
+            .all(|(j, x)| i == j || !x.elem.contains(Some(elem)));
+        let entry = &mut entries[i];
+        match (outermost, entry.listeners.is_some()) {
+            (true, false) => entry.listeners = Some(DomListeners::new(&entry.elem)),
+            (false, true) => entry.listeners = None,
+            _ => {}
+        }
+    }
+}
+
+// it releases the listeners when the mount target node is dropped
+pub(crate) struct MountTargetGuard {
+    entries: Weak<RefCell<Vec<MountTargetEntry>>>,
+    elem: web_sys::Element,
+}
+
+impl Drop for MountTargetGuard {
+    fn drop(&mut self) {
+        let entries = match self.entries.upgrade() {
+            Some(x) => x,
+            None => return,
+        };
+        let mut entries = entries.borrow_mut();
+        let index = match entries.iter().position(|x| x.elem == self.elem) {
+            Some(x) => x,
+            None => return,
+        };
+        entries[index].refs -= 1;
+        if entries[index].refs == 0 {
+            entries.remove(index);
+            refresh(&mut entries);
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Portals, which place the content into another DOM element.
+//!
+//! The content of a `<portal>` is still a part of the component tree,
+//! so it is updated with the component and removed when the branch is removed,
+//! but its DOM nodes are placed in the `target` element.
+//! This is useful for modals, tooltips and dropdowns.
+//!
+//! The tap, touch and drag events of the content bubble along the component tree,
+//! while the other DOM events bubble along the DOM tree.
+//! Note that the tap, touch and drag events are listened in the backend root,
+//! so the `target` should be inside the backend root if these events are needed.
+//!
+//! ```rust
+//! use maomi::prelude::*;
+//! use maomi_dom::{prelude::*, element::*, portal::portal};
+//!
+//! #[component(Backend = DomBackend)]
+//! struct MyComponent {
+//!     template: template! {
+//!         <div>
+//!             if self.show_modal {
+//!                 <portal target={ &self.modal_container }>
+//!                     <div> "The modal content" </div>
+//!                 </portal>
+//!             }
+//!         </div>
+//!     },
+//!     show_modal: bool,
+//!     modal_container: Option<web_sys::Element>,
+//! }
+//!
+//! impl Component for MyComponent {
+//!     fn new() -> Self {
+//!         Self {
+//!             template: Default::default(),
+//!             show_modal: false,
+//!             modal_container: None,
+//!         }
+//!     }
+//! }
+//! ```
+
+use maomi::{
+    backend::{tree::*, BackendComponent, SupportBackend},
+    error::Error,
+    node::{OwnerWeak, SlotChange, StaticSingleSlot},
+    prop::PropertyUpdate,
+    BackendContext,
+};
+
+use crate::{composing, virtual_element::DomVirtualElement, DomBackend, DomGeneralElement, DomState};
+
+/// The target DOM element of a `portal`
+///
+/// If it is `None` , the content is not placed in the DOM tree.
+#[derive(Default)]
+pub struct PortalTarget {
+    inner: Option<web_sys::Element>,
+    changed: bool,
+}
+
+impl PortalTarget {
+    /// Get the target DOM element
+    #[inline]
+    pub fn get(&self) -> Option<&web_sys::Element> {
+        self.inner.as_ref()
+    }
+
+    fn set(&mut self, v: Option<&web_sys::Element>) {
+        if self.inner.as_ref() == v {
+            return;
+        }
+        self.inner = v.cloned();
+        self.changed = true;
+    }
+}
+
+impl PropertyUpdate<web_sys::Element> for PortalTarget {
+    type UpdateContext = ();
+
+    #[inline]
+    fn compare_and_set_ref(dest: &mut Self, src: &web_sys::Element, _ctx: &mut ()) {
+        dest.set(Some(src));
+    }
+}
+
+impl PropertyUpdate<Option<web_sys::Element>> for PortalTarget {
+    type UpdateContext = ();
+
+    #[inline]
+    fn compare_and_set_ref(dest: &mut Self, src: &Option<web_sys::Element>, _ctx: &mut ()) {
+        dest.set(src.as_ref());
+    }
+}
+
+/// A portal, which places its content into the `target` DOM element
+#[allow(non_camel_case_types)]
+pub struct portal {
+    backend_element_token: ForestToken,
+    /// The DOM element which contains the content
+    pub target: PortalTarget,
+}
+
+impl portal {
+    fn apply_target(&mut self, node: &mut ForestNodeMut<DomGeneralElement>) {
+        if !self.target.changed {
+            return;
+        }
+        self.target.changed = false;
+        let target = self.target.inner.clone();
+        let old = match &mut **node {
+            DomGeneralElement::Virtual(x) => x.set_portal_target(target.clone()),
+            _ => unreachable!(),
+        };
+        if node.is_prerendering() != DomState::Normal(()) {
+            return;
+        }
+        let node = node.as_ref();
+        let mut cur_option = node.first_child();
+        while let Some(cur) = cur_option {
+            match &target {
+                Some(target) => {
+                    // the DOM nodes are moved from the old target
+                    if let Some(frag) = composing::collect_child_frag(cur.clone()).dom() {
+                        if let Err(err) = target.append_child(frag) {
+                            crate::log_js_error(&err);
+                        }
+                    }
+                }
+                None => {
+                    if let Some(old) = &old {
+                        composing::remove_all_children(old, cur.clone());
+                    }
+                }
+            }
+            cur_option = cur.next_sibling();
+        }
+    }
+}
+
+impl BackendComponent<DomBackend> for portal {
+    type SlotData = ();
+    type UpdateTarget = Self;
+    type UpdateContext = ();
+
+    #[inline]
+    fn init<'b>(
+        _backend_context: &'b BackendContext<DomBackend>,
+        owner: &'b mut ForestNodeMut<DomGeneralElement>,
+        _owner_weak: &'b Box<dyn OwnerWeak>,
+    ) -> Result<(Self, ForestNodeRc<DomGeneralElement>), Error>
+    where
+        Self: Sized,
+    {
+        let elem = DomVirtualElement::new_portal(owner);
+        let backend_element = owner.new_tree(DomGeneralElement::Virtual(elem));
+        let this = Self {
+            backend_element_token: backend_element.token(),
+            target: Default::default(),
+        };
+        Ok((this, backend_element))
+    }
+
+    #[inline]
+    fn create<'b>(
+        &'b mut self,
+        _backend_context: &'b BackendContext<DomBackend>,
+        owner: &'b mut ForestNodeMut<DomGeneralElement>,
+        update_fn: Box<dyn 'b + FnOnce(&mut Self, &mut Self::UpdateContext)>,
+        slot_fn: &mut dyn FnMut(
+            &mut ForestNodeMut<DomGeneralElement>,
+            &ForestToken,
+            &Self::SlotData,
+        ) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let mut node = owner
+            .borrow_mut_token(&self.backend_element_token)
+            .ok_or(Error::TreeNodeReleased)?;
+        update_fn(self, &mut ());
+        self.apply_target(&mut node);
+        slot_fn(&mut node, &self.backend_element_token, &())?;
+        Ok(())
+    }
+
+    #[inline]
+    fn apply_updates<'b>(
+        &'b mut self,
+        _backend_context: &'b BackendContext<DomBackend>,
+        owner: &'b mut ForestNodeMut<DomGeneralElement>,
+        update_fn: Box<dyn 'b + FnOnce(&mut Self, &mut Self::UpdateContext)>,
+        slot_fn: &mut dyn FnMut(
+            SlotChange<&mut ForestNodeMut<DomGeneralElement>, &ForestToken, &Self::SlotData>,
+        ) -> Result<(), Error>,
+    ) -> Result<(), Error> {
+        let mut node = owner
+            .borrow_mut_token(&self.backend_element_token)
+            .ok_or(Error::TreeNodeReleased)?;
+        update_fn(self, &mut ());
+        self.apply_target(&mut node);
+        slot_fn(SlotChange::Unchanged(&mut node, &self.backend_element_token, &()))?;
+        Ok(())
+    }
+}
+
+impl SupportBackend for portal {
+    type Target = Self;
+    type SlotChildren = StaticSingleSlot<ForestTokenAddr, maomi::node::DynNodeList>;
+}
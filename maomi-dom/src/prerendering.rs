@@ -0,0 +1,146 @@
+use maomi::{
+    backend::{tree::*, BackendStage},
+    BackendContext,
+};
+
+use crate::{DomBackend, DomGeneralElement, DomState, WriteHtmlState};
+
+enum Cursor {
+    Start,
+    Enter(ForestNodeRc<DomGeneralElement>),
+    Leave(ForestNodeRc<DomGeneralElement>),
+    Pending(ForestNodeRc<DomGeneralElement>),
+    Done,
+}
+
+/// The state of a streaming prerendering writer.
+///
+/// The tree is written in order.
+/// When a component with pending prerendering task is met,
+/// the content before it is returned as a chunk,
+/// and the writer continues after the task finished.
+pub(crate) struct PrerenderingHtmlChunks {
+    backend_context: BackendContext<DomBackend>,
+    cursor: Cursor,
+    state: WriteHtmlState,
+}
+
+impl PrerenderingHtmlChunks {
+    pub(crate) fn new(backend_context: &BackendContext<DomBackend>) -> Self {
+        if backend_context.initial_backend_stage() != BackendStage::Prerendering {
+            panic!("The backend is not in prerendering stage");
+        }
+        Self {
+            backend_context: backend_context.clone(),
+            cursor: Cursor::Start,
+            state: Default::default(),
+        }
+    }
+
+    pub(crate) async fn next_chunk(&mut self) -> Option<String> {
+        loop {
+            let cursor = match std::mem::replace(&mut self.cursor, Cursor::Done) {
+                Cursor::Done => return None,
+                Cursor::Pending(n) => {
+                    self.backend_context
+                        .prerendering_task_settled(&n.token())
+                        .await;
+                    Cursor::Enter(n)
+                }
+                x => x,
+            };
+            let state = std::mem::take(&mut self.state);
+            let backend_context = self.backend_context.clone();
+            let (cursor, state, chunk) = self
+                .backend_context
+                .enter(move |ctx| {
+                    let root = ctx.root().rc();
+                    let mut state = state;
+                    let mut chunk = vec![];
+                    let cursor =
+                        write_until_pending(&backend_context, &root, cursor, &mut chunk, &mut state)
+                            .unwrap();
+                    (cursor, state, chunk)
+                })
+                .await;
+            self.cursor = cursor;
+            self.state = state;
+            if !chunk.is_empty() {
+                // since all str sources are valid UTF-8, this operation is safe
+                return Some(unsafe { String::from_utf8_unchecked(chunk) });
+            }
+        }
+    }
+}
+
+fn next_cursor(
+    root: &ForestNodeRc<DomGeneralElement>,
+    node: &ForestNode<DomGeneralElement>,
+) -> Cursor {
+    if let Some(next) = node.next_sibling_rc() {
+        return Cursor::Enter(next);
+    }
+    match node.parent_rc() {
+        Some(parent) if !parent.ptr_eq(root) => Cursor::Leave(parent),
+        _ => Cursor::Done,
+    }
+}
+
+fn write_until_pending(
+    backend_context: &BackendContext<DomBackend>,
+    root: &ForestNodeRc<DomGeneralElement>,
+    mut cursor: Cursor,
+    w: &mut impl std::io::Write,
+    state: &mut WriteHtmlState,
+) -> std::io::Result<Cursor> {
+    loop {
+        cursor = match cursor {
+            Cursor::Start => match root.borrow().first_child_rc() {
+                Some(child) => Cursor::Enter(child),
+                None => Cursor::Done,
+            },
+            Cursor::Enter(n) => {
+                if backend_context.has_pending_prerendering_task(&n.token()) {
+                    return Ok(Cursor::Pending(n));
+                }
+                let node = n.borrow();
+                let first_child = match &*node {
+                    DomGeneralElement::Text(x) => {
+                        x.write_inner_html(w, state)?;
+                        None
+                    }
+                    DomGeneralElement::Element(x) => {
+                        if let DomState::Prerendering(x) = &x.elem {
+                            x.write_start_tag(w, state)?;
+                        }
+                        node.first_child_rc()
+                    }
+                    // the portal content is not prerendered
+                    DomGeneralElement::Virtual(x) if x.is_portal() => None,
+                    DomGeneralElement::Virtual(_) => node.first_child_rc(),
+                };
+                match first_child {
+                    Some(child) => Cursor::Enter(child),
+                    None => {
+                        if let DomGeneralElement::Element(x) = &*node {
+                            if let DomState::Prerendering(x) = &x.elem {
+                                x.write_end_tag(w, state)?;
+                            }
+                        }
+                        next_cursor(root, &node)
+                    }
+                }
+            }
+            Cursor::Leave(n) => {
+                let node = n.borrow();
+                if let DomGeneralElement::Element(x) = &*node {
+                    if let DomState::Prerendering(x) = &x.elem {
+                        x.write_end_tag(w, state)?;
+                    }
+                }
+                next_cursor(root, &node)
+            }
+            x => return Ok(x),
+        };
+    }
+}
@@ -0,0 +1,350 @@
+use maomi::{backend::tree::*, error::Error};
+use web_sys::Node;
+
+use crate::DomGeneralElement;
+
+/// The behavior when the prerendered DOM does not match the component tree
+///
+/// It can be set with `DomBackend::set_prerendered_mismatch_mode` .
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PrerenderedMismatchMode {
+    /// Stop applying and return an `Error` which contains a `PrerenderedMismatch`
+    #[default]
+    Error,
+    /// Log a warning and re-create the mismatched subtree
+    Recreate,
+}
+
+/// The kind of a `PrerenderedMismatch`
+#[derive(Debug, Clone, PartialEq)]
+pub enum PrerenderedMismatchKind {
+    /// The DOM node is not an element with the expected tag name
+    TagName {
+        /// The expected tag name
+        expected: String,
+        /// The node name of the DOM node
+        found: String,
+    },
+    /// The DOM node is not a text node with the expected content
+    TextContent {
+        /// The expected text content
+        expected: String,
+        /// The text content (or the node name if it is not a text node) of the DOM node
+        found: String,
+    },
+    /// Some DOM nodes are missing in the parent
+    MissingNode,
+    /// There are more DOM nodes than expected in the parent
+    ExtraNode,
+}
+
+/// A mismatch between the prerendered DOM and the component tree
+///
+/// In `PrerenderedMismatchMode::Error` mode,
+/// it can be found in the `err` field of `Error::BackendError` .
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrerenderedMismatch {
+    /// The path of the mismatched DOM node, i.e. `div[0] > #text[1]`
+    ///
+    /// Each segment is the node name and its index in the parent child nodes.
+    /// For `PrerenderedMismatchKind::MissingNode` , it is the path of the parent.
+    pub path: String,
+    /// The mismatch detail
+    pub kind: PrerenderedMismatchKind,
+}
+
+impl std::fmt::Display for PrerenderedMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Prerendered DOM mismatched at {}: ", self.path)?;
+        match &self.kind {
+            PrerenderedMismatchKind::TagName { expected, found } => {
+                write!(f, "expected <{}>, found {:?}", expected, found)
+            }
+            PrerenderedMismatchKind::TextContent { expected, found } => {
+                write!(f, "expected text {:?}, found {:?}", expected, found)
+            }
+            PrerenderedMismatchKind::MissingNode => write!(f, "some child nodes are missing"),
+            PrerenderedMismatchKind::ExtraNode => write!(f, "unexpected extra nodes"),
+        }
+    }
+}
+
+impl std::error::Error for PrerenderedMismatch {}
+
+struct RematchState {
+    prev_is_text_node: bool,
+    mode: PrerenderedMismatchMode,
+    root: Node,
+}
+
+impl RematchState {
+    fn mismatch(&self, node: &Node, kind: PrerenderedMismatchKind) -> Result<(), Error> {
+        let m = PrerenderedMismatch {
+            path: dom_path(&self.root, node),
+            kind,
+        };
+        match self.mode {
+            PrerenderedMismatchMode::Error => Err(Error::BackendError {
+                msg: m.to_string(),
+                err: Some(Box::new(m)),
+            }),
+            PrerenderedMismatchMode::Recreate => {
+                log::warn!("{}", m);
+                Ok(())
+            }
+        }
+    }
+}
+
+fn node_name(node: &Node) -> String {
+    node.node_name().to_ascii_lowercase()
+}
+
+fn dom_path(root: &Node, node: &Node) -> String {
+    let mut segs = vec![];
+    let mut cur = node.clone();
+    while !cur.is_same_node(Some(root)) {
+        let parent = match cur.parent_node() {
+            Some(x) => x,
+            None => break,
+        };
+        let mut index = 0;
+        let mut prev = cur.previous_sibling();
+        while let Some(x) = prev {
+            index += 1;
+            prev = x.previous_sibling();
+        }
+        segs.push(format!("{}[{}]", node_name(&cur), index));
+        cur = parent;
+    }
+    if segs.is_empty() {
+        return "(root)".into();
+    }
+    segs.reverse();
+    segs.join(" > ")
+}
+
+pub(crate) fn rematch_root(
+    tree: &mut ForestNodeMut<DomGeneralElement>,
+    dom_elem: web_sys::Element,
+    mode: PrerenderedMismatchMode,
+) -> Result<(), Error> {
+    let root: Node = dom_elem.into();
+    if let DomGeneralElement::Element(x) = &mut **tree {
+        x.rematch_dom(root.clone());
+    }
+    let mut state = RematchState {
+        prev_is_text_node: false,
+        mode,
+        root: root.clone(),
+    };
+    let extra = rematch_children(tree, &root, root.first_child(), &mut state)?;
+    check_extra(&root, extra, &mut state)
+}
+
+fn rematch_children(
+    n: &mut ForestNodeMut<DomGeneralElement>,
+    parent_dom: &Node,
+    mut next_dom: Option<Node>,
+    state: &mut RematchState,
+) -> Result<Option<Node>, Error> {
+    if let Some(mut child) = n.first_child_rc() {
+        loop {
+            let c = {
+                let child_mut = &mut n.borrow_mut(&child);
+                next_dom = rematch(child_mut, parent_dom, next_dom, state)?;
+                child_mut.next_sibling_rc()
+            };
+            match c {
+                None => break,
+                Some(c) => {
+                    child = c;
+                }
+            }
+        }
+    }
+    Ok(next_dom)
+}
+
+fn check_extra(parent_dom: &Node, extra: Option<Node>, state: &mut RematchState) -> Result<(), Error> {
+    // white spaces around the prerendered HTML segment are allowed
+    let mut extra = extra;
+    while let Some(x) = &extra {
+        let is_space = x.node_type() == Node::TEXT_NODE
+            && x.text_content().unwrap_or_default().trim().is_empty();
+        if !is_space {
+            break;
+        }
+        extra = x.next_sibling();
+    }
+    if let Some(extra) = extra {
+        state.mismatch(&extra, PrerenderedMismatchKind::ExtraNode)?;
+        let mut cur = Some(extra);
+        while let Some(x) = cur {
+            cur = x.next_sibling();
+            parent_dom.remove_child(&x).unwrap();
+        }
+    }
+    Ok(())
+}
+
+fn rematch(
+    n: &mut ForestNodeMut<DomGeneralElement>,
+    parent_dom: &Node,
+    next_dom: Option<Node>,
+    state: &mut RematchState,
+) -> Result<Option<Node>, Error> {
+    let ge: &mut DomGeneralElement = n;
+    match ge {
+        DomGeneralElement::Text(x) => {
+            let mut e = next_dom;
+            if state.prev_is_text_node {
+                // skip the separator between text nodes
+                if let Some(sep) = &e {
+                    if sep.node_type() == Node::COMMENT_NODE {
+                        e = sep.next_sibling();
+                    }
+                }
+            } else {
+                state.prev_is_text_node = true;
+            }
+            let content = x.text_content();
+            let matched = match &e {
+                None => false,
+                Some(e) if content.is_empty() => {
+                    e.node_type() == Node::COMMENT_NODE
+                        && e.text_content().unwrap_or_default().is_empty()
+                }
+                Some(e) => {
+                    e.node_type() == Node::TEXT_NODE
+                        && e.text_content().unwrap_or_default() == content
+                }
+            };
+            match e {
+                Some(e) if matched => {
+                    let ret = e.next_sibling();
+                    x.rematch_dom(e);
+                    Ok(ret)
+                }
+                Some(e) => {
+                    let found = if e.node_type() == Node::TEXT_NODE {
+                        e.text_content().unwrap_or_default()
+                    } else {
+                        node_name(&e)
+                    };
+                    state.mismatch(
+                        &e,
+                        PrerenderedMismatchKind::TextContent {
+                            expected: content.to_string(),
+                            found,
+                        },
+                    )?;
+                    let ret = e.next_sibling();
+                    parent_dom.replace_child(&x.recreate_dom(), &e).unwrap();
+                    Ok(ret)
+                }
+                None => {
+                    state.mismatch(parent_dom, PrerenderedMismatchKind::MissingNode)?;
+                    parent_dom.append_child(&x.recreate_dom()).unwrap();
+                    Ok(None)
+                }
+            }
+        }
+        DomGeneralElement::Virtual(x) => {
+            x.rematch_dom();
+            if x.is_portal() {
+                // the portal content is not prerendered, so create it in the target
+                let target: Option<Node> = x.portal_target().map(|x| x.clone().into());
+                recreate_children(n, target.as_ref());
+                return Ok(next_dom);
+            }
+            rematch_children(n, parent_dom, next_dom, state)
+        }
+        DomGeneralElement::Element(x) => {
+            state.prev_is_text_node = false;
+            let tag_name = x
+                .rematched_dom_elem()
+                .map(|x| x.tag_name())
+                .unwrap_or_default();
+            match next_dom {
+                Some(e)
+                    if e.node_type() == Node::ELEMENT_NODE
+                        && e.node_name().eq_ignore_ascii_case(tag_name) =>
+                {
+                    x.rematch_dom(e.clone());
+                    let extra = rematch_children(n, &e, e.first_child(), state)?;
+                    check_extra(&e, extra, state)?;
+                    state.prev_is_text_node = false;
+                    Ok(e.next_sibling())
+                }
+                Some(e) => {
+                    state.mismatch(
+                        &e,
+                        PrerenderedMismatchKind::TagName {
+                            expected: tag_name.to_string(),
+                            found: node_name(&e),
+                        },
+                    )?;
+                    let ret = e.next_sibling();
+                    parent_dom.replace_child(&recreate(n), &e).unwrap();
+                    Ok(ret)
+                }
+                None => {
+                    state.mismatch(parent_dom, PrerenderedMismatchKind::MissingNode)?;
+                    parent_dom.append_child(&recreate(n)).unwrap();
+                    Ok(None)
+                }
+            }
+        }
+    }
+}
+
+fn recreate(n: &mut ForestNodeMut<DomGeneralElement>) -> Node {
+    let ge: &mut DomGeneralElement = n;
+    let (ret, children_parent): (Node, Option<Node>) = match ge {
+        DomGeneralElement::Text(x) => return x.recreate_dom().into(),
+        DomGeneralElement::Virtual(x) => {
+            x.rematch_dom();
+            let frag: Node = crate::DOCUMENT
+                .with(|document| document.create_document_fragment())
+                .into();
+            if x.is_portal() {
+                (frag, x.portal_target().map(|x| x.clone().into()))
+            } else {
+                (frag.clone(), Some(frag))
+            }
+        }
+        DomGeneralElement::Element(x) => {
+            let e = x
+                .rematched_dom_elem()
+                .expect("The element is not in prerendering-apply stage")
+                .create_dom();
+            x.rematch_dom(e.clone().into());
+            let e: Node = e.into();
+            (e.clone(), Some(e))
+        }
+    };
+    recreate_children(n, children_parent.as_ref());
+    ret
+}
+
+fn recreate_children(n: &mut ForestNodeMut<DomGeneralElement>, parent: Option<&Node>) {
+    if let Some(mut child) = n.first_child_rc() {
+        loop {
+            let c = {
+                let child_mut = &mut n.borrow_mut(&child);
+                let child_dom = recreate(child_mut);
+                if let Some(parent) = parent {
+                    parent.append_child(&child_dom).unwrap();
+                }
+                child_mut.next_sibling_rc()
+            };
+            match c {
+                None => break,
+                Some(c) => {
+                    child = c;
+                }
+            }
+        }
+    }
+}
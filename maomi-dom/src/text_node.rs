@@ -29,11 +29,19 @@ impl DomTextNode {
         let mut e = e;
         if self.content.len() == 0 {
             let text_node = crate::DOCUMENT.with(|document| document.create_text_node(""));
-            e = e.parent_node().unwrap().replace_child(&text_node, &e).unwrap();
+            e.parent_node().unwrap().replace_child(&text_node, &e).unwrap();
+            e = text_node.into();
         }
         self.dom_elem = DomState::Normal(e.unchecked_into());
     }
 
+    #[cfg(feature = "prerendering-apply")]
+    pub(crate) fn recreate_dom(&mut self) -> web_sys::Text {
+        let e = crate::DOCUMENT.with(|document| document.create_text_node(&self.content));
+        self.dom_elem = DomState::Normal(e.clone());
+        e
+    }
+
     pub(crate) fn new(this: &mut tree::ForestNodeMut<DomGeneralElement>, content: &str) -> Self {
         let dom_elem = match this.is_prerendering() {
             DomState::Normal(_) => DomState::Normal(
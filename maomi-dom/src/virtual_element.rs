@@ -5,6 +5,7 @@ use crate::{DomGeneralElement, DomState};
 #[doc(hidden)]
 pub struct DomVirtualElement {
     dom_elem: dom_state_ty!((), (), ()),
+    portal: Option<Option<web_sys::Element>>,
 }
 
 impl DomVirtualElement {
@@ -17,7 +18,33 @@ impl DomVirtualElement {
             #[cfg(feature = "prerendering-apply")]
             DomState::PrerenderingApply(_) => DomState::PrerenderingApply(()),
         };
-        Self { dom_elem }
+        Self { dom_elem, portal: None }
+    }
+
+    // the child nodes of a portal are placed in the target element instead of the parent
+    #[inline]
+    pub(crate) fn new_portal(this: &mut tree::ForestNodeMut<DomGeneralElement>) -> Self {
+        let mut ret = Self::new(this);
+        ret.portal = Some(None);
+        ret
+    }
+
+    #[inline]
+    pub(crate) fn is_portal(&self) -> bool {
+        self.portal.is_some()
+    }
+
+    #[inline]
+    pub(crate) fn portal_target(&self) -> Option<&web_sys::Element> {
+        self.portal.as_ref().and_then(|x| x.as_ref())
+    }
+
+    #[inline]
+    pub(crate) fn set_portal_target(
+        &mut self,
+        target: Option<web_sys::Element>,
+    ) -> Option<web_sys::Element> {
+        std::mem::replace(self.portal.as_mut().expect("not a portal"), target)
     }
 
     #[cfg(feature = "prerendering-apply")]
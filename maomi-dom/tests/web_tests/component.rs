@@ -1,7 +1,7 @@
 use wasm_bindgen_test::*;
 
 use maomi::prelude::*;
-use maomi_dom::{async_task, element::*, prelude::*};
+use maomi_dom::{async_task, element::*, event::TapEvent, prelude::*};
 
 use super::*;
 
@@ -677,3 +677,194 @@ async fn self_update_slot_data() {
 
     test_component::<Parent>().await;
 }
+
+#[wasm_bindgen_test]
+async fn multiple_mount_points() {
+    #[component(Backend = DomBackend)]
+    struct Island {
+        template: template! {
+            <span> { &self.text } </span>
+        },
+        text: String,
+    }
+
+    impl Component for Island {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                text: String::new(),
+            }
+        }
+    }
+
+    let document = web_sys::window().unwrap().document().unwrap();
+    let island_a = document.create_element("div").unwrap();
+    let island_b = document.create_element("div").unwrap();
+    let dom_backend =
+        DomBackend::new_with_element(document.create_element("div").unwrap()).unwrap();
+    let backend_context = maomi::BackendContext::new(dom_backend);
+    let (mut mount_point_a, mut mount_point_b) = backend_context
+        .enter_sync(|ctx| {
+            let target = ctx.mount_target(island_a.clone()).unwrap();
+            let mount_point_a = ctx
+                .attach_at(target, |this: &mut Island| this.text = "a".into())
+                .unwrap();
+            let target = ctx.mount_target(island_b.clone()).unwrap();
+            let mount_point_b = ctx
+                .attach_at(target, |this: &mut Island| this.text = "b".into())
+                .unwrap()
+                .into_dyn();
+            (mount_point_a, mount_point_b)
+        })
+        .map_err(|_| "Cannot init mount point")
+        .unwrap();
+    assert_eq!(island_a.inner_html(), "<span>a</span>");
+    assert_eq!(island_b.inner_html(), "<span>b</span>");
+
+    mount_point_a
+        .root_component()
+        .rc()
+        .update(|this| this.text = "c".into())
+        .await
+        .unwrap();
+    assert_eq!(island_a.inner_html(), "<span>c</span>");
+    assert_eq!(island_b.inner_html(), "<span>b</span>");
+
+    backend_context
+        .enter_sync(|ctx| {
+            ctx.detach(&mut mount_point_a);
+            ctx.detach_dyn(&mut mount_point_b);
+        })
+        .map_err(|_| "Cannot detach mount point")
+        .unwrap();
+    assert_eq!(island_a.inner_html(), "");
+    assert_eq!(island_b.inner_html(), "");
+}
+
+#[wasm_bindgen_test]
+async fn nested_mount_targets() {
+    thread_local! {
+        static TAP_COUNT: std::cell::Cell<u32> = std::cell::Cell::new(0);
+    }
+
+    #[component(Backend = DomBackend)]
+    struct Island {
+        template: template! {
+            <span tap=@handler()></span>
+        },
+    }
+
+    impl Component for Island {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+            }
+        }
+    }
+
+    impl Island {
+        fn handler(_this: ComponentRc<Self>, _ev: &mut TapEvent) {
+            TAP_COUNT.with(|x| x.set(x.get() + 1));
+        }
+    }
+
+    fn tap_on(target: &web_sys::Element) {
+        let document: web_sys::EventTarget = web_sys::window().unwrap().document().unwrap().into();
+        for (target, ty) in [(target.as_ref(), "mousedown"), (&document, "mouseup")] {
+            let event_init = web_sys::EventInit::new();
+            event_init.set_bubbles(true);
+            let ev = web_sys::Event::new_with_event_init_dict(ty, &event_init).unwrap();
+            for (k, v) in [("button", 0.), ("clientX", 12.), ("clientY", 34.)] {
+                js_sys::Reflect::set(&ev, &k.into(), &v.into()).unwrap();
+            }
+            target.dispatch_event(&ev).unwrap();
+        }
+    }
+
+    let document = web_sys::window().unwrap().document().unwrap();
+    let outer = document.create_element("div").unwrap();
+    let inner = document.create_element("div").unwrap();
+    outer.append_child(&inner).unwrap();
+    let dom_backend =
+        DomBackend::new_with_element(document.create_element("div").unwrap()).unwrap();
+    let backend_context = maomi::BackendContext::new(dom_backend);
+
+    // the inner target is listened at first, and then the outer one takes its place
+    let (mut mount_point_inner, mut mount_point_outer) = backend_context
+        .enter_sync(|ctx| {
+            let target = ctx.mount_target(inner.clone()).unwrap();
+            let mount_point_inner = ctx.attach_at(target, |_: &mut Island| {}).unwrap();
+            let target = ctx.mount_target(outer.clone()).unwrap();
+            let mount_point_outer = ctx.attach_at(target, |_: &mut Island| {}).unwrap();
+            (mount_point_inner, mount_point_outer)
+        })
+        .map_err(|_| "Cannot init mount point")
+        .unwrap();
+    let span = inner.first_element_child().unwrap();
+    tap_on(&span);
+    assert_eq!(TAP_COUNT.with(|x| x.get()), 1);
+
+    // the inner target is listened again after the outer one released
+    backend_context
+        .enter_sync(|ctx| ctx.detach(&mut mount_point_outer))
+        .map_err(|_| "Cannot detach mount point")
+        .unwrap();
+    drop(mount_point_outer);
+    tap_on(&span);
+    assert_eq!(TAP_COUNT.with(|x| x.get()), 2);
+
+    backend_context
+        .enter_sync(|ctx| ctx.detach(&mut mount_point_inner))
+        .map_err(|_| "Cannot detach mount point")
+        .unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn after_template_apply() {
+    #[component(Backend = DomBackend)]
+    struct Parent {
+        callback: Option<ComponentTestCb>,
+        template: template! {
+            <div> { &self.text } </div>
+        },
+        text: String,
+        rendered: Vec<String>,
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                template: Default::default(),
+                text: "a".into(),
+                rendered: vec![],
+            }
+        }
+
+        fn after_template_apply(&mut self) {
+            let html = first_dom!(self, div).inner_html();
+            self.rendered.push(html);
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            async_task(async move {
+                this.update(|this| this.text = "b".into()).await.unwrap();
+                this.update(|this| {
+                    assert_eq!(this.rendered, ["a", "b"]);
+                    (this.callback.take().unwrap())();
+                })
+                .await
+                .unwrap();
+            });
+        }
+    }
+
+    impl ComponentTest for Parent {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    test_component::<Parent>().await;
+}
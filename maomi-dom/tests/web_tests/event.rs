@@ -462,6 +462,293 @@ test_mouse_events!(mouse_move, "mousemove");
 test_mouse_events!(mouse_enter, "mouseenter");
 test_mouse_events!(mouse_leave, "mouseleave");
 
+macro_rules! test_keyboard_events {
+    ($ev:ident, $ev_js_name:expr) => {
+        #[wasm_bindgen_test]
+        async fn $ev() {
+            #[component(Backend = DomBackend)]
+            struct MyComp {
+                callback: Option<ComponentTestCb>,
+                template: template! {
+                    <div $ev=@handler()>
+                        <input />
+                    </div>
+                },
+            }
+
+            impl Component for MyComp {
+                fn new() -> Self {
+                    Self {
+                        callback: None,
+                        template: Default::default(),
+                    }
+                }
+
+                fn created(&self) {
+                    let this = self.rc();
+                    async_task(async move {
+                        this.get(|this| {
+                            let dom_elem = first_dom!(this, div).first_element_child().unwrap();
+                            simulate_event(
+                                &dom_elem,
+                                $ev_js_name,
+                                true,
+                                [
+                                    ("key", JsValue::from_str("A")),
+                                    ("code", JsValue::from_str("KeyA")),
+                                    ("shiftKey", JsValue::TRUE),
+                                    ("ctrlKey", JsValue::FALSE),
+                                    ("repeat", JsValue::TRUE),
+                                ],
+                            );
+                        })
+                        .await;
+                    });
+                }
+            }
+
+            impl MyComp {
+                fn handler(this: ComponentRc<Self>, ev: &mut KeyboardEvent) {
+                    assert_eq!(ev.key(), "A");
+                    assert_eq!(ev.code(), "KeyA");
+                    assert!(ev.shift_key());
+                    assert!(!ev.ctrl_key());
+                    assert!(ev.repeat());
+                    ev.prevent_default();
+                    assert!(ev.default_prevented());
+                    async_task(async move {
+                        this.update_with(|this, _| {
+                            (this.callback.take().unwrap())();
+                        })
+                        .await
+                        .unwrap();
+                    });
+                }
+            }
+
+            impl ComponentTest for MyComp {
+                fn set_callback(&mut self, callback: ComponentTestCb) {
+                    self.callback = Some(callback);
+                }
+            }
+
+            test_component::<MyComp>().await;
+        }
+    };
+}
+
+test_keyboard_events!(key_down, "keydown");
+test_keyboard_events!(key_up, "keyup");
+
+macro_rules! test_pointer_events {
+    ($ev:ident, $ev_js_name:expr) => {
+        #[wasm_bindgen_test]
+        async fn $ev() {
+            #[component(Backend = DomBackend)]
+            struct MyComp {
+                callback: Option<ComponentTestCb>,
+                template: template! {
+                    <div $ev=@handler()>
+                        <span />
+                    </div>
+                },
+            }
+
+            impl Component for MyComp {
+                fn new() -> Self {
+                    Self {
+                        callback: None,
+                        template: Default::default(),
+                    }
+                }
+
+                fn created(&self) {
+                    let this = self.rc();
+                    async_task(async move {
+                        this.get(|this| {
+                            let dom_elem = first_dom!(this, div).first_element_child().unwrap();
+                            simulate_event(
+                                &dom_elem,
+                                $ev_js_name,
+                                true,
+                                [
+                                    ("pointerId", JsValue::from_f64(3.)),
+                                    ("pointerType", JsValue::from_str("pen")),
+                                    ("isPrimary", JsValue::TRUE),
+                                    ("button", JsValue::from_f64(0.)),
+                                    ("clientX", JsValue::from_f64(12.)),
+                                    ("clientY", JsValue::from_f64(34.)),
+                                ],
+                            );
+                        })
+                        .await;
+                    });
+                }
+            }
+
+            impl MyComp {
+                fn handler(this: ComponentRc<Self>, ev: &mut PointerEvent) {
+                    assert_eq!(ev.pointer_id(), 3);
+                    assert_eq!(ev.pointer_type(), PointerType::Pen);
+                    assert!(ev.is_primary());
+                    assert_eq!(ev.button(), MouseButton::Main);
+                    assert_eq!(ev.client_x(), 12);
+                    assert_eq!(ev.client_y(), 34);
+                    async_task(async move {
+                        this.update_with(|this, _| {
+                            (this.callback.take().unwrap())();
+                        })
+                        .await
+                        .unwrap();
+                    });
+                }
+            }
+
+            impl ComponentTest for MyComp {
+                fn set_callback(&mut self, callback: ComponentTestCb) {
+                    self.callback = Some(callback);
+                }
+            }
+
+            test_component::<MyComp>().await;
+        }
+    };
+}
+
+test_pointer_events!(pointer_down, "pointerdown");
+test_pointer_events!(pointer_move, "pointermove");
+test_pointer_events!(pointer_up, "pointerup");
+test_pointer_events!(pointer_cancel, "pointercancel");
+
+#[wasm_bindgen_test]
+async fn wheel_event() {
+    #[component(Backend = DomBackend)]
+    struct MyComp {
+        callback: Option<ComponentTestCb>,
+        template: template! {
+            <div wheel=@handler()>
+                <span />
+            </div>
+        },
+    }
+
+    impl Component for MyComp {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                template: Default::default(),
+            }
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            async_task(async move {
+                this.get(|this| {
+                    let dom_elem = first_dom!(this, div).first_element_child().unwrap();
+                    simulate_event(
+                        &dom_elem,
+                        "wheel",
+                        true,
+                        [
+                            ("deltaX", JsValue::from_f64(0.)),
+                            ("deltaY", JsValue::from_f64(-3.)),
+                            ("deltaZ", JsValue::from_f64(0.)),
+                            ("deltaMode", JsValue::from_f64(1.)),
+                        ],
+                    );
+                })
+                .await;
+            });
+        }
+    }
+
+    impl MyComp {
+        fn handler(this: ComponentRc<Self>, ev: &mut WheelEvent) {
+            assert_eq!(ev.delta_x(), 0.);
+            assert_eq!(ev.delta_y(), -3.);
+            assert_eq!(ev.delta_mode(), WheelDeltaMode::Line);
+            async_task(async move {
+                this.update_with(|this, _| {
+                    (this.callback.take().unwrap())();
+                })
+                .await
+                .unwrap();
+            });
+        }
+    }
+
+    impl ComponentTest for MyComp {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    test_component::<MyComp>().await;
+}
+
+macro_rules! test_observer_events {
+    ($ev:ident, $detail:ty, |$detail_name:ident| $check:expr) => {
+        #[wasm_bindgen_test]
+        async fn $ev() {
+            #[component(Backend = DomBackend)]
+            struct MyComp {
+                callback: Option<ComponentTestCb>,
+                template: template! {
+                    <div $ev=@handler()> "content" </div>
+                },
+            }
+
+            impl Component for MyComp {
+                fn new() -> Self {
+                    Self {
+                        callback: None,
+                        template: Default::default(),
+                    }
+                }
+
+                fn created(&self) {
+                    let this = self.rc();
+                    async_task(async move {
+                        this.get(|this| {
+                            // observers only work for rendered elements
+                            let container = first_dom!(this, div).parent_node().unwrap();
+                            let body = web_sys::window().unwrap().document().unwrap().body().unwrap();
+                            body.append_child(&container).unwrap();
+                        })
+                        .await;
+                    });
+                }
+            }
+
+            impl MyComp {
+                fn handler(this: ComponentRc<Self>, $detail_name: &mut $detail) {
+                    assert!($check);
+                    async_task(async move {
+                        this.update_with(|this, _| {
+                            if let Some(cb) = this.callback.take() {
+                                cb();
+                            }
+                        })
+                        .await
+                        .unwrap();
+                    });
+                }
+            }
+
+            impl ComponentTest for MyComp {
+                fn set_callback(&mut self, callback: ComponentTestCb) {
+                    self.callback = Some(callback);
+                }
+            }
+
+            test_component::<MyComp>().await;
+        }
+    };
+}
+
+test_observer_events!(resize, ResizeEvent, |ev| ev.width() > 0. && ev.height() > 0.);
+test_observer_events!(intersection, IntersectionEvent, |ev| ev.is_intersecting() && ev.intersection_ratio() > 0.);
+
 #[wasm_bindgen_test]
 async fn tap() {
     #[component(Backend = DomBackend)]
@@ -684,3 +971,327 @@ async fn long_tap() {
 
     test_component::<MyComp>().await;
 }
+
+#[wasm_bindgen_test]
+async fn drag() {
+    #[component(Backend = DomBackend)]
+    struct MyComp {
+        callback: Option<ComponentTestCb>,
+        events: Vec<(&'static str, i32, i32)>,
+        template: template! {
+            <div drag_start=@drag_start() drag_move=@drag_move() drag_end=@drag_end()>
+                <span tap=@tap() />
+            </div>
+        },
+    }
+
+    impl Component for MyComp {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                events: vec![],
+                template: Default::default(),
+            }
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            async_task(async move {
+                this.get(|this| {
+                    let dom_elem = first_dom!(this, div).first_element_child().unwrap();
+                    let document = web_sys::window().unwrap().document().unwrap();
+                    let mouse_ev = |target: &web_sys::EventTarget, name, x: f64, y: f64| {
+                        simulate_event(
+                            target,
+                            name,
+                            true,
+                            [
+                                ("button", JsValue::from_f64(0.)),
+                                ("clientX", JsValue::from_f64(x)),
+                                ("clientY", JsValue::from_f64(y)),
+                            ],
+                        );
+                    };
+                    mouse_ev(&dom_elem, "mousedown", 10., 10.);
+                    // too short to start a drag
+                    mouse_ev(&document, "mousemove", 12., 13.);
+                    mouse_ev(&document, "mousemove", 20., 13.);
+                    mouse_ev(&document, "mousemove", 30., 5.);
+                    mouse_ev(&document, "mouseup", 40., 0.);
+                })
+                .await;
+            });
+        }
+    }
+
+    impl MyComp {
+        fn record(this: ComponentRc<Self>, name: &'static str, ev: &DragEvent) {
+            assert_eq!(ev.start_client_x(), 10);
+            assert_eq!(ev.start_client_y(), 10);
+            assert!(!ev.canceled());
+            let item = (name, ev.delta_x(), ev.delta_y());
+            async_task(async move {
+                this.update_with(move |this, _| {
+                    this.events.push(item);
+                    if name == "end" {
+                        assert_eq!(
+                            this.events,
+                            [
+                                ("start", 10, 3),
+                                ("move", 20, -5),
+                                ("move", 30, -10),
+                                ("end", 30, -10),
+                            ],
+                        );
+                        (this.callback.take().unwrap())();
+                    }
+                })
+                .await
+                .unwrap();
+            });
+        }
+
+        fn drag_start(this: ComponentRc<Self>, ev: &mut DragEvent) {
+            Self::record(this, "start", ev);
+        }
+
+        fn drag_move(this: ComponentRc<Self>, ev: &mut DragEvent) {
+            Self::record(this, "move", ev);
+        }
+
+        fn drag_end(this: ComponentRc<Self>, ev: &mut DragEvent) {
+            Self::record(this, "end", ev);
+        }
+
+        fn tap(_this: ComponentRc<Self>, _ev: &mut TapEvent) {
+            panic!("should not tap after dragging");
+        }
+    }
+
+    impl ComponentTest for MyComp {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    test_component::<MyComp>().await;
+}
+
+#[wasm_bindgen_test]
+async fn focus_events() {
+    #[component(Backend = DomBackend)]
+    struct MyComp {
+        callback: Option<ComponentTestCb>,
+        events: Vec<&'static str>,
+        template: template! {
+            <div focus_in=@focus_in() focus_out=@focus_out()>
+                <input focus=@focus() blur=@blur() />
+            </div>
+        },
+    }
+
+    impl Component for MyComp {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                events: vec![],
+                template: Default::default(),
+            }
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            async_task(async move {
+                this.get(|this| {
+                    let dom_elem = first_dom!(this, div).first_element_child().unwrap();
+                    simulate_event(&dom_elem, "focus", false, []);
+                    simulate_event(&dom_elem, "focusin", true, []);
+                    simulate_event(&dom_elem, "blur", false, []);
+                    simulate_event(&dom_elem, "focusout", true, []);
+                })
+                .await;
+            });
+        }
+    }
+
+    impl MyComp {
+        fn record(this: ComponentRc<Self>, name: &'static str) {
+            async_task(async move {
+                this.update_with(move |this, _| {
+                    this.events.push(name);
+                    if this.events.len() == 4 {
+                        assert_eq!(this.events, ["focus", "focus_in", "blur", "focus_out"]);
+                        (this.callback.take().unwrap())();
+                    }
+                })
+                .await
+                .unwrap();
+            });
+        }
+
+        fn focus(this: ComponentRc<Self>, _ev: &mut FocusEvent) {
+            Self::record(this, "focus");
+        }
+
+        fn blur(this: ComponentRc<Self>, _ev: &mut FocusEvent) {
+            Self::record(this, "blur");
+        }
+
+        fn focus_in(this: ComponentRc<Self>, ev: &mut FocusEvent) {
+            assert_eq!(ev.target().unwrap().tag_name(), "INPUT");
+            Self::record(this, "focus_in");
+        }
+
+        fn focus_out(this: ComponentRc<Self>, ev: &mut FocusEvent) {
+            assert_eq!(ev.target().unwrap().tag_name(), "INPUT");
+            Self::record(this, "focus_out");
+        }
+    }
+
+    impl ComponentTest for MyComp {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    test_component::<MyComp>().await;
+}
+
+#[wasm_bindgen_test]
+async fn focus_management() {
+    #[component(Backend = DomBackend)]
+    struct MyComp {
+        callback: Option<ComponentTestCb>,
+        template: template! {
+            <button />
+            <div>
+                <input />
+                <input />
+            </div>
+        },
+    }
+
+    impl Component for MyComp {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                template: Default::default(),
+            }
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            async_task(async move {
+                this.update_with(|this, _| {
+                    {
+                        let structure = this.template_structure().unwrap();
+                        let button = &structure[0].as_ref::<maomi::node::Node<button>>().tag;
+                        let div = &structure[1].as_ref::<maomi::node::Node<div>>().tag;
+                        // only connected elements can be focused
+                        let container = button.dom_element().parent_node().unwrap();
+                        let body = web_sys::window().unwrap().document().unwrap().body().unwrap();
+                        body.append_child(&container).unwrap();
+                        button.focus();
+                        assert!(button.has_focus());
+                        assert!(!div.contains_focus());
+                        {
+                            let trap = div.trap_focus();
+                            assert!(div.contains_focus());
+                            let document = web_sys::window().unwrap().document().unwrap();
+                            let first = div.dom_element().first_element_child().unwrap();
+                            assert!(trap.focus_last());
+                            assert!(!first.is_same_node(document.active_element().as_deref()));
+                            assert!(trap.focus_first());
+                            assert!(first.is_same_node(document.active_element().as_deref()));
+                        }
+                        assert!(button.has_focus());
+                        button.blur();
+                        assert!(!button.has_focus());
+                        body.remove_child(&container).unwrap();
+                    }
+                    (this.callback.take().unwrap())();
+                })
+                .await
+                .unwrap();
+            });
+        }
+    }
+
+    impl ComponentTest for MyComp {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    test_component::<MyComp>().await;
+}
+
+#[wasm_bindgen_test]
+async fn media_events() {
+    #[component(Backend = DomBackend)]
+    struct MyComp {
+        callback: Option<ComponentTestCb>,
+        template: template! {
+            <audio
+                play=@handler(&"play")
+                pause=@handler(&"pause")
+                ended=@handler(&"ended")
+                loaded_metadata=@handler(&"loadedmetadata")
+                error=@handler(&"error")
+            />
+        },
+        triggered: Vec<&'static str>,
+    }
+
+    impl Component for MyComp {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                template: Default::default(),
+                triggered: vec![],
+            }
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            async_task(async move {
+                this.get(|this| {
+                    let dom_elem = first_dom!(this, audio).clone();
+                    for name in ["loadedmetadata", "play", "pause", "ended", "error"] {
+                        simulate_event(&dom_elem, name, false, []);
+                    }
+                })
+                .await;
+            });
+        }
+    }
+
+    impl MyComp {
+        fn handler(this: ComponentRc<Self>, ev: &mut MediaEvent, name: &&'static str) {
+            assert_eq!(ev.target().unwrap().tag_name(), "AUDIO");
+            let name = *name;
+            async_task(async move {
+                this.update_with(|this, _| {
+                    this.triggered.push(name);
+                    if this.triggered.len() == 5 {
+                        assert_eq!(
+                            this.triggered,
+                            ["loadedmetadata", "play", "pause", "ended", "error"],
+                        );
+                        (this.callback.take().unwrap())();
+                    }
+                })
+                .await
+                .unwrap();
+            });
+        }
+    }
+
+    impl ComponentTest for MyComp {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    test_component::<MyComp>().await;
+}
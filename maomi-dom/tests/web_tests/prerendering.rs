@@ -3,7 +3,7 @@
 use wasm_bindgen_test::*;
 
 use maomi::prelude::*;
-use maomi_dom::{async_task, element::*, event::*, prelude::*};
+use maomi_dom::{async_task, element::*, event::*, portal::portal, prelude::*};
 
 use super::*;
 
@@ -267,3 +267,673 @@ async fn hot_event_in_prerendered() {
     let (html, prerendering_data) = test_component_prerendering::<MyComp>(&()).await;
     test_component_prerendering_apply::<MyComp>(&html, prerendering_data).await;
 }
+
+#[wasm_bindgen_test]
+async fn streaming_prerendering_html() {
+    use futures::StreamExt;
+    use maomi::{component::PrerenderingTask, AsyncCallback};
+
+    #[component(Backend = DomBackend)]
+    struct Child {
+        template: template! {
+            <span>{ &self.text }</span>
+        },
+        text: String,
+    }
+
+    impl Component for Child {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                text: "loading".into(),
+            }
+        }
+
+        fn prerendering_created(&self) -> Option<PrerenderingTask> {
+            let this = self.rc();
+            Some(Box::pin(async move {
+                let (fut, cb) = AsyncCallback::new();
+                async_task(async move {
+                    cb("loaded");
+                });
+                let text = fut.await;
+                this.update(move |this| this.text = text.into())
+                    .await
+                    .unwrap();
+            }))
+        }
+    }
+
+    #[component(Backend = DomBackend)]
+    struct Parent {
+        template: template! {
+            <div> "a" <Child /> </div>
+            <p> "b" </p>
+        },
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+            }
+        }
+    }
+
+    let backend_context = maomi::BackendContext::new(DomBackend::prerendering());
+    let _mount_point = backend_context
+        .enter_sync(|ctx| ctx.attach(|_: &mut Parent| {}).unwrap())
+        .map_err(|_| "Cannot init mount point")
+        .unwrap();
+    let chunks: Vec<String> = DomBackend::prerendering_html_stream(&backend_context)
+        .collect()
+        .await;
+    assert_eq!(chunks, ["<div>a", "<span>loaded</span></div><p>b</p>"]);
+
+    let mut html = vec![];
+    DomBackend::write_prerendering_html_streaming(&backend_context, &mut html)
+        .await
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(html).unwrap(),
+        "<div>a<span>loaded</span></div><p>b</p>",
+    );
+}
+
+#[wasm_bindgen_test]
+async fn prerendering_resource() {
+    use maomi::{
+        component::PrerenderingTask,
+        error::Error,
+        resource::{Resource, ResourceState},
+        AsyncCallback,
+    };
+
+    #[component(Backend = DomBackend)]
+    struct Parent {
+        template: template! {
+            match self.text.state() {
+                ResourceState::Pending => { <span> "loading" </span> }
+                ResourceState::Ready(x) => { <span> { x } </span> }
+                ResourceState::Failed(_) => { <span> "failed" </span> }
+            }
+        },
+        text: Resource<String>,
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                text: Resource::new(),
+            }
+        }
+
+        fn prerendering_created(&self) -> Option<PrerenderingTask> {
+            let (fut, cb) = AsyncCallback::<Result<String, Error>>::new();
+            async_task(async move {
+                cb(Ok("loaded".into()));
+            });
+            self.rc().load(|this| &mut this.text, fut);
+            None
+        }
+    }
+
+    let backend_context = maomi::BackendContext::new(DomBackend::prerendering());
+    let _mount_point = backend_context
+        .enter_sync(|ctx| ctx.attach(|_: &mut Parent| {}).unwrap())
+        .map_err(|_| "Cannot init mount point")
+        .unwrap();
+    let mut html = vec![];
+    DomBackend::write_prerendering_html_streaming(&backend_context, &mut html)
+        .await
+        .unwrap();
+    assert_eq!(String::from_utf8(html).unwrap(), "<span>loaded</span>");
+}
+
+#[cfg(feature = "serde")]
+#[wasm_bindgen_test]
+async fn serialized_prerendering_data() {
+    #[component(Backend = DomBackend)]
+    struct MyComp {
+        template: template! {
+            <div title={ &self.data.0 }>{ self.data.1.to_string() }</div>
+        },
+        data: (String, u32),
+    }
+
+    impl Component for MyComp {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                data: Default::default(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl PrerenderableComponent for MyComp {
+        type QueryData = &'static str;
+        type PrerenderingData = (String, u32);
+
+        async fn prerendering_data(query_data: &Self::QueryData) -> Self::PrerenderingData {
+            (query_data.to_string(), 123)
+        }
+
+        fn apply_prerendering_data(&mut self, data: Self::PrerenderingData) {
+            self.data = data;
+        }
+    }
+
+    // server side
+    let backend_context = maomi::BackendContext::new(DomBackend::prerendering());
+    let prerendering_data =
+        maomi::BackendContext::<DomBackend>::prerendering_data::<MyComp>(&"</script>&\"").await;
+    let mut script = vec![];
+    DomBackend::write_prerendering_data(&prerendering_data, "my-comp-data", &mut script).unwrap();
+    let script = String::from_utf8(script).unwrap();
+    assert_eq!(
+        script,
+        r#"<script type="application/json" id="my-comp-data">["\u003c/script\u003e\u0026\"",123]</script>"#,
+    );
+    let (_mount_point, html) = backend_context
+        .enter_sync(move |ctx| {
+            let mount_point = ctx.prerendering_attach(prerendering_data).unwrap();
+            let mut html = vec![];
+            ctx.write_prerendering_html(&mut html).unwrap();
+            (mount_point, String::from_utf8(html).unwrap())
+        })
+        .map_err(|_| "Cannot init mount point")
+        .unwrap();
+
+    // client side
+    let document = web_sys::window().unwrap().document().unwrap();
+    let holder = document.create_element("div").unwrap();
+    holder.set_inner_html(&script);
+    document.body().unwrap().append_child(&holder).unwrap();
+    let elem = document.create_element("div").unwrap();
+    elem.set_inner_html(&html);
+    let (dom_backend, prerendering_data) =
+        DomBackend::new_prerendered_with_data::<MyComp>("my-comp-data").unwrap();
+    holder.remove();
+    assert!(document.get_element_by_id("my-comp-data").is_none());
+    assert_eq!(prerendering_data.get(), &("</script>&\"".to_string(), 123));
+    let backend_context = maomi::BackendContext::new(dom_backend);
+    let _mount_point = backend_context
+        .enter_sync(move |ctx| {
+            let mount_point = ctx.prerendering_attach(prerendering_data).unwrap();
+            ctx.apply_prerendered_element(elem.clone()).unwrap();
+            let div = elem.first_element_child().unwrap();
+            assert_eq!(div.get_attribute("title").unwrap(), "</script>&\"");
+            assert_eq!(div.text_content().unwrap(), "123");
+            mount_point
+        })
+        .map_err(|_| "Cannot init mount point")
+        .unwrap();
+}
+
+#[wasm_bindgen_test]
+async fn prerendered_mismatch() {
+    use maomi_dom::{PrerenderedMismatch, PrerenderedMismatchKind, PrerenderedMismatchMode};
+
+    #[component(Backend = DomBackend)]
+    struct MyComp {
+        template: template! {
+            <div title="t">
+                <span class:abc>{ &self.text }</span>
+                "b"
+            </div>
+        },
+        text: String,
+    }
+
+    stylesheet! {
+        #[css_name("abc")]
+        class abc {}
+    }
+
+    impl Component for MyComp {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                text: "a".into(),
+            }
+        }
+    }
+
+    let html = {
+        let backend_context = maomi::BackendContext::new(DomBackend::prerendering());
+        backend_context
+            .enter_sync(|ctx| {
+                let _mount_point = ctx.attach(|_: &mut MyComp| {}).unwrap();
+                let mut html = vec![];
+                ctx.write_prerendering_html(&mut html).unwrap();
+                String::from_utf8(html).unwrap()
+            })
+            .map_err(|_| "Cannot init mount point")
+            .unwrap()
+    };
+    assert_eq!(html, r#"<div title="t"><span class="abc">a</span>b</div>"#);
+
+    let apply = |html: &str, mode: PrerenderedMismatchMode| {
+        let elem = web_sys::window()
+            .unwrap()
+            .document()
+            .unwrap()
+            .create_element("div")
+            .unwrap();
+        elem.set_inner_html(html);
+        let mut dom_backend = DomBackend::new_prerendered();
+        dom_backend.set_prerendered_mismatch_mode(mode);
+        let backend_context = maomi::BackendContext::new(dom_backend);
+        let ret = backend_context
+            .enter_sync(|ctx| {
+                let mount_point = ctx.attach(|_: &mut MyComp| {}).unwrap();
+                let ret = ctx.apply_prerendered_element(elem.clone());
+                std::mem::forget(mount_point);
+                ret
+            })
+            .map_err(|_| "Cannot init mount point")
+            .unwrap();
+        (ret, elem.inner_html())
+    };
+
+    let mismatch_of = |ret: Result<(), maomi::error::Error>| match ret {
+        Err(maomi::error::Error::BackendError { err: Some(err), .. }) => {
+            err.downcast::<PrerenderedMismatch>().unwrap()
+        }
+        _ => panic!("expected a mismatch error"),
+    };
+
+    let (ret, _) = apply(&html, PrerenderedMismatchMode::Error);
+    assert!(ret.is_ok());
+
+    let (ret, _) = apply(
+        r#"<div title="t"><p class="abc">a</p>b</div>"#,
+        PrerenderedMismatchMode::Error,
+    );
+    let m = mismatch_of(ret);
+    assert_eq!(m.path, "div[0] > p[0]");
+    assert_eq!(
+        m.kind,
+        PrerenderedMismatchKind::TagName {
+            expected: "span".into(),
+            found: "p".into(),
+        },
+    );
+
+    let (ret, _) = apply(
+        r#"<div title="t"><span class="abc">x</span>b</div>"#,
+        PrerenderedMismatchMode::Error,
+    );
+    let m = mismatch_of(ret);
+    assert_eq!(m.path, "div[0] > span[0] > #text[0]");
+    assert_eq!(
+        m.kind,
+        PrerenderedMismatchKind::TextContent {
+            expected: "a".into(),
+            found: "x".into(),
+        },
+    );
+
+    let (ret, _) = apply(
+        r#"<div title="t"><span class="abc">a</span>b<i></i></div>"#,
+        PrerenderedMismatchMode::Error,
+    );
+    let m = mismatch_of(ret);
+    assert_eq!(m.path, "div[0] > i[2]");
+    assert_eq!(m.kind, PrerenderedMismatchKind::ExtraNode);
+
+    let (ret, _) = apply(r#"<div title="t"><span class="abc">a</span></div>"#, PrerenderedMismatchMode::Error);
+    let m = mismatch_of(ret);
+    assert_eq!(m.path, "div[0]");
+    assert_eq!(m.kind, PrerenderedMismatchKind::MissingNode);
+
+    let (ret, inner_html) = apply(
+        r#"<div title="t"><p>x</p>c<i></i></div>"#,
+        PrerenderedMismatchMode::Recreate,
+    );
+    assert!(ret.is_ok());
+    assert_eq!(inner_html, html);
+}
+
+#[wasm_bindgen_test]
+async fn prerendering_svg_elements() {
+    #[component(Backend = DomBackend)]
+    struct MyComp {
+        callback: Option<ComponentTestCb>,
+        template: template! {
+            <div>
+                <svg::svg view_box="0 0 10 10">
+                    <svg::circle r="5" stroke_width="2" />
+                    <svg::foreignObject>
+                        <span>"text"</span>
+                    </foreignObject>
+                </svg>
+            </div>
+        },
+    }
+
+    impl Component for MyComp {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                template: Default::default(),
+            }
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            this.task_with(|this, _| {
+                let svg = first_dom!(this, div).first_element_child().unwrap();
+                let circle = svg.first_element_child().unwrap();
+                let span = circle.next_element_sibling().unwrap().first_element_child().unwrap();
+                assert_eq!(svg.namespace_uri().as_deref(), Some(svg::NAMESPACE));
+                assert_eq!(circle.namespace_uri().as_deref(), Some(svg::NAMESPACE));
+                assert_eq!(span.namespace_uri().as_deref(), Some("http://www.w3.org/1999/xhtml"));
+                (this.callback.take().unwrap())();
+            });
+        }
+    }
+
+    #[async_trait]
+    impl PrerenderableComponent for MyComp {
+        type QueryData = ();
+        type PrerenderingData = ();
+
+        async fn prerendering_data(_query_data: &Self::QueryData) -> Self::PrerenderingData {}
+
+        fn apply_prerendering_data(&mut self, _data: Self::PrerenderingData) {}
+    }
+
+    impl ComponentTest for MyComp {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    let (html, prerendering_data) = test_component_prerendering::<MyComp>(&()).await;
+    assert_eq!(
+        &html,
+        concat!(
+            r#"<div><svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 10 10">"#,
+            r#"<circle r="5" stroke-width="2"></circle>"#,
+            r#"<foreignObject><span xmlns="http://www.w3.org/1999/xhtml">text</span></foreignObject>"#,
+            r#"</svg></div>"#,
+        ),
+    );
+    test_component_prerendering_apply::<MyComp>(&html, prerendering_data).await;
+}
+
+#[wasm_bindgen_test]
+async fn prerendering_mathml_elements() {
+    #[component(Backend = DomBackend)]
+    struct MyComp {
+        callback: Option<ComponentTestCb>,
+        template: template! {
+            <div>
+                <mathml::math>
+                    <mathml::msup>
+                        <mathml::mi> "x" </mi>
+                        <mathml::mn> "2" </mn>
+                    </msup>
+                </math>
+            </div>
+        },
+    }
+
+    impl Component for MyComp {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                template: Default::default(),
+            }
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            this.task_with(|this, _| {
+                let math = first_dom!(this, div).first_element_child().unwrap();
+                let msup = math.first_element_child().unwrap();
+                assert_eq!(math.namespace_uri().as_deref(), Some(mathml::NAMESPACE));
+                assert_eq!(msup.namespace_uri().as_deref(), Some(mathml::NAMESPACE));
+                (this.callback.take().unwrap())();
+            });
+        }
+    }
+
+    #[async_trait]
+    impl PrerenderableComponent for MyComp {
+        type QueryData = ();
+        type PrerenderingData = ();
+
+        async fn prerendering_data(_query_data: &Self::QueryData) -> Self::PrerenderingData {}
+
+        fn apply_prerendering_data(&mut self, _data: Self::PrerenderingData) {}
+    }
+
+    impl ComponentTest for MyComp {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    let (html, prerendering_data) = test_component_prerendering::<MyComp>(&()).await;
+    assert_eq!(
+        &html,
+        r#"<div><math xmlns="http://www.w3.org/1998/Math/MathML"><msup><mi>x</mi><mn>2</mn></msup></math></div>"#,
+    );
+    test_component_prerendering_apply::<MyComp>(&html, prerendering_data).await;
+}
+
+#[wasm_bindgen_test]
+async fn prerendering_embedded_content_attributes() {
+    #[component(Backend = DomBackend)]
+    struct MyComp {
+        callback: Option<ComponentTestCb>,
+        template: template! {
+            <iframe sandbox="allow-scripts" referrer_policy="no-referrer" allow_fullscreen=true />
+        },
+    }
+
+    impl Component for MyComp {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                template: Default::default(),
+            }
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            this.task_with(|this, _| {
+                (this.callback.take().unwrap())();
+            });
+        }
+    }
+
+    #[async_trait]
+    impl PrerenderableComponent for MyComp {
+        type QueryData = ();
+        type PrerenderingData = ();
+
+        async fn prerendering_data(_query_data: &Self::QueryData) -> Self::PrerenderingData {}
+
+        fn apply_prerendering_data(&mut self, _data: Self::PrerenderingData) {}
+    }
+
+    impl ComponentTest for MyComp {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    let (html, prerendering_data) = test_component_prerendering::<MyComp>(&()).await;
+    assert_eq!(
+        &html,
+        r#"<iframe sandbox="allow-scripts" referrerpolicy="no-referrer" allowfullscreen=""></iframe>"#,
+    );
+    test_component_prerendering_apply::<MyComp>(&html, prerendering_data).await;
+}
+
+#[wasm_bindgen_test]
+async fn prerendering_aria_attributes() {
+    use maomi_dom::aria::*;
+
+    #[component(Backend = DomBackend)]
+    struct MyComp {
+        callback: Option<ComponentTestCb>,
+        template: template! {
+            <button aria_expanded=false aria_pressed=&{None::<AriaTristate>} aria_haspopup=&{AriaHasPopup::Menu} aria_controls="menu" />
+            <ul id="menu" role=&{Role::Menu} hidden />
+        },
+    }
+
+    impl Component for MyComp {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                template: Default::default(),
+            }
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            this.task_with(|this, _| {
+                (this.callback.take().unwrap())();
+            });
+        }
+    }
+
+    #[async_trait]
+    impl PrerenderableComponent for MyComp {
+        type QueryData = ();
+        type PrerenderingData = ();
+
+        async fn prerendering_data(_query_data: &Self::QueryData) -> Self::PrerenderingData {}
+
+        fn apply_prerendering_data(&mut self, _data: Self::PrerenderingData) {}
+    }
+
+    impl ComponentTest for MyComp {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    let (html, prerendering_data) = test_component_prerendering::<MyComp>(&()).await;
+    assert_eq!(
+        &html,
+        r#"<button aria-expanded="false" aria-haspopup="menu" aria-controls="menu"></button><ul id="menu" role="menu" hidden=""></ul>"#,
+    );
+    test_component_prerendering_apply::<MyComp>(&html, prerendering_data).await;
+}
+
+#[wasm_bindgen_test]
+async fn prerendering_data_attributes() {
+    #[component(Backend = DomBackend)]
+    struct MyComp {
+        callback: Option<ComponentTestCb>,
+        template: template! {
+            <div data:user_id="1" data:title={ &self.title } dataset:"count"=&{ 2 } />
+        },
+        title: String,
+    }
+
+    impl Component for MyComp {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                template: Default::default(),
+                title: r#"<"a" & 'b'>"#.to_string(),
+            }
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            this.task_with(|this, _| {
+                (this.callback.take().unwrap())();
+            });
+        }
+    }
+
+    #[async_trait]
+    impl PrerenderableComponent for MyComp {
+        type QueryData = ();
+        type PrerenderingData = ();
+
+        async fn prerendering_data(_query_data: &Self::QueryData) -> Self::PrerenderingData {}
+
+        fn apply_prerendering_data(&mut self, _data: Self::PrerenderingData) {}
+    }
+
+    impl ComponentTest for MyComp {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    let (html, prerendering_data) = test_component_prerendering::<MyComp>(&()).await;
+    assert_eq!(
+        &html,
+        r#"<div data-user-id="1" data-title="&lt;&quot;a&quot; &amp; 'b'&gt;" data-count="2"></div>"#,
+    );
+    test_component_prerendering_apply::<MyComp>(&html, prerendering_data).await;
+}
+
+#[wasm_bindgen_test]
+async fn prerendering_portal() {
+    #[component(Backend = DomBackend)]
+    struct MyComp {
+        callback: Option<ComponentTestCb>,
+        template: template! {
+            <div>
+                <portal target={ &self.container }>
+                    <span> "a" </span>
+                </portal>
+                <span> "b" </span>
+            </div>
+        },
+        container: web_sys::Element,
+    }
+
+    impl Component for MyComp {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                template: Default::default(),
+                container: web_sys::window()
+                    .unwrap()
+                    .document()
+                    .unwrap()
+                    .create_element("div")
+                    .unwrap(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl PrerenderableComponent for MyComp {
+        type QueryData = ();
+        type PrerenderingData = ();
+
+        async fn prerendering_data(_query_data: &Self::QueryData) -> Self::PrerenderingData {}
+
+        fn apply_prerendering_data(&mut self, _data: Self::PrerenderingData) {}
+    }
+
+    impl ComponentTest for MyComp {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            assert_eq!(self.container.inner_html(), "<span>a</span>");
+            assert_eq!(
+                first_dom!(self, div).outer_html(),
+                "<div><span>b</span></div>",
+            );
+            callback();
+        }
+    }
+
+    let (html, prerendering_data) = test_component_prerendering::<MyComp>(&()).await;
+    assert_eq!(&html, "<div><span>b</span></div>");
+    test_component_prerendering_apply::<MyComp>(&html, prerendering_data).await;
+}
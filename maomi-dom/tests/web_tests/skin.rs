@@ -109,3 +109,53 @@ async fn skin_style() {
 
     test_component::<MyComp>().await;
 }
+
+#[wasm_bindgen_test]
+async fn shadow_root_style_sheet() {
+    stylesheet! {
+        #![css_text(SHADOW_CSS)]
+        #[css_name("shadow-class")]
+        class shadow_class {
+            color = rgb(1, 2, 3);
+        }
+    }
+
+    #[component(Backend = DomBackend)]
+    struct MyComp {
+        template: template! {
+            <div class:shadow_class></div>
+        },
+    }
+
+    impl Component for MyComp {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+            }
+        }
+    }
+
+    assert_eq!(SHADOW_CSS, ".shadow-class{color:rgb(1,2,3)}");
+    let window = web_sys::window().unwrap();
+    let document = window.document().unwrap();
+    let host = document.create_element("div").unwrap();
+    document.body().unwrap().append_child(&host).unwrap();
+    let dom_backend = DomBackend::new_with_shadow_root(&host, web_sys::ShadowRootMode::Closed).unwrap();
+    assert!(host.shadow_root().is_none());
+    let shadow_root = dom_backend.shadow_root().unwrap().clone();
+    dom_backend.adopt_style_sheet(SHADOW_CSS).unwrap();
+    assert_eq!(shadow_root.adopted_style_sheets().length(), 1);
+    let backend_context = maomi::BackendContext::new(dom_backend);
+    let _mount_point = backend_context
+        .enter_sync(|ctx| ctx.attach(|_: &mut MyComp| {}).unwrap())
+        .map_err(|_| "Cannot init mount point")
+        .unwrap();
+    assert_eq!(
+        shadow_root.inner_html(),
+        r#"<div style="display: contents"><div class="shadow-class"></div></div>"#,
+    );
+    let div = shadow_root.first_element_child().unwrap().first_element_child().unwrap();
+    let style = window.get_computed_style(&div).unwrap().unwrap();
+    assert_eq!(style.get_property_value("color").unwrap(), "rgb(1, 2, 3)");
+    host.remove();
+}
@@ -1,7 +1,15 @@
 use wasm_bindgen_test::*;
 
 use maomi::prelude::*;
-use maomi_dom::{async_task, element::*, prelude::*};
+use maomi_dom::{
+    async_task,
+    custom_element::*,
+    element::*,
+    event::{CustomDomEvent, CustomEvent, CustomEventType, LoadEvent},
+    portal::portal,
+    prelude::*,
+};
+use wasm_bindgen::{JsCast, JsValue};
 
 use super::*;
 
@@ -866,3 +874,724 @@ async fn list_prop() {
 
     test_component::<Parent>().await;
 }
+
+#[wasm_bindgen_test]
+async fn svg_elements() {
+    #[component(Backend = DomBackend)]
+    struct Parent {
+        callback: Option<ComponentTestCb>,
+        template: template! {
+            <div>
+                <svg::svg view_box="0 0 10 10">
+                    <svg::circle r={ &self.r } stroke_width="2" />
+                </svg>
+            </div>
+        },
+        r: String,
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                template: Default::default(),
+                r: "5".into(),
+            }
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            async_task(async move {
+                this.update(|this| {
+                    let svg = first_dom!(this, div).first_element_child().unwrap();
+                    let circle = svg.first_element_child().unwrap();
+                    assert_eq!(svg.namespace_uri().as_deref(), Some(svg::NAMESPACE));
+                    assert_eq!(circle.namespace_uri().as_deref(), Some(svg::NAMESPACE));
+                    assert_eq!(
+                        svg.outer_html(),
+                        r#"<svg viewBox="0 0 10 10"><circle r="5" stroke-width="2"></circle></svg>"#,
+                    );
+                    this.r = "3".into();
+                })
+                .await
+                .unwrap();
+                this.update_with(|this, _| {
+                    let svg = first_dom!(this, div).first_element_child().unwrap();
+                    let circle = svg.first_element_child().unwrap();
+                    assert_eq!(circle.get_attribute("r").as_deref(), Some("3"));
+                    (this.callback.take().unwrap())();
+                })
+                .await
+                .unwrap();
+            });
+        }
+    }
+
+    impl ComponentTest for Parent {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    test_component::<Parent>().await;
+}
+
+#[wasm_bindgen_test]
+async fn mathml_elements() {
+    #[component(Backend = DomBackend)]
+    struct Parent {
+        callback: Option<ComponentTestCb>,
+        template: template! {
+            <div>
+                <mathml::math display="block">
+                    <mathml::mfrac>
+                        <mathml::mi> "x" </mi>
+                        <mathml::mn> { &self.n } </mn>
+                    </mfrac>
+                </math>
+            </div>
+        },
+        n: String,
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                template: Default::default(),
+                n: "2".into(),
+            }
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            async_task(async move {
+                this.update_with(|this, _| {
+                    let math = first_dom!(this, div).first_element_child().unwrap();
+                    let mfrac = math.first_element_child().unwrap();
+                    assert_eq!(math.namespace_uri().as_deref(), Some(mathml::NAMESPACE));
+                    assert_eq!(mfrac.namespace_uri().as_deref(), Some(mathml::NAMESPACE));
+                    assert_eq!(
+                        math.outer_html(),
+                        r#"<math display="block"><mfrac><mi>x</mi><mn>2</mn></mfrac></math>"#,
+                    );
+                    (this.callback.take().unwrap())();
+                })
+                .await
+                .unwrap();
+            });
+        }
+    }
+
+    impl ComponentTest for Parent {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    test_component::<Parent>().await;
+}
+
+#[wasm_bindgen_test]
+async fn embedded_content_elements() {
+    #[component(Backend = DomBackend)]
+    struct Parent {
+        callback: Option<ComponentTestCb>,
+        template: template! {
+            <div>
+                <picture>
+                    <source srcset="a.webp" media="(min-width: 800px)" />
+                    <img sizes="100vw" loading="lazy" referrer_policy="no-referrer" />
+                </picture>
+                <iframe sandbox="allow-scripts" allow="fullscreen" load=@iframe_loaded() />
+            </div>
+        },
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                template: Default::default(),
+            }
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            async_task(async move {
+                this.get(|this| {
+                    let div = first_dom!(this, div).clone();
+                    assert_eq!(
+                        div.first_element_child().unwrap().outer_html(),
+                        r#"<picture><source srcset="a.webp" media="(min-width: 800px)"><img sizes="100vw" loading="lazy" referrerpolicy="no-referrer"></picture>"#,
+                    );
+                    let iframe = div.last_element_child().unwrap();
+                    assert_eq!(iframe.get_attribute("sandbox").as_deref(), Some("allow-scripts"));
+                    assert_eq!(iframe.get_attribute("allow").as_deref(), Some("fullscreen"));
+                    simulate_event(&iframe, "load", false, []);
+                })
+                .await;
+            });
+        }
+    }
+
+    impl Parent {
+        fn iframe_loaded(this: ComponentRc<Self>, ev: &mut LoadEvent) {
+            assert_eq!(ev.target().unwrap().tag_name(), "IFRAME");
+            async_task(async move {
+                this.update_with(|this, _| {
+                    (this.callback.take().unwrap())();
+                })
+                .await
+                .unwrap();
+            });
+        }
+    }
+
+    impl ComponentTest for Parent {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    test_component::<Parent>().await;
+}
+
+#[wasm_bindgen_test]
+async fn media_binding() {
+    use maomi::prop::BindingValue;
+    use maomi_dom::event::MediaEvent;
+
+    #[component(Backend = DomBackend)]
+    struct Parent {
+        callback: Option<ComponentTestCb>,
+        template: template! {
+            <video
+                current_time={ &self.current_time }
+                paused={ &self.paused }
+                volume={ &self.volume }
+                muted={ &self.muted }
+                time_update=@time_update()
+            />
+            <audio muted={ &self.audio_muted } />
+        },
+        current_time: BindingValue<f64>,
+        paused: BindingValue<bool>,
+        volume: BindingValue<f64>,
+        muted: BindingValue<bool>,
+        audio_muted: BindingValue<bool>,
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                template: Default::default(),
+                current_time: BindingValue::new(0.),
+                paused: BindingValue::new(true),
+                volume: BindingValue::new(1.),
+                muted: BindingValue::new(false),
+                audio_muted: BindingValue::new(true),
+            }
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            this.task_with(|this, _| {
+                let audio = this.template_structure().unwrap()[1]
+                    .as_ref::<maomi::node::Node<audio>>()
+                    .tag
+                    .dom_element()
+                    .clone();
+                assert!(audio.dyn_ref::<web_sys::HtmlMediaElement>().unwrap().muted());
+                let dom_elem = first_dom!(this, video).clone();
+                let video = dom_elem.dyn_ref::<web_sys::HtmlMediaElement>().unwrap();
+                video.set_volume(0.5);
+                video.set_muted(true);
+                simulate_event(&dom_elem, "volumechange", false, []);
+                simulate_event(&dom_elem, "pause", false, []);
+                simulate_event(&dom_elem, "timeupdate", false, []);
+            });
+        }
+    }
+
+    impl Parent {
+        fn time_update(this: ComponentRc<Self>, ev: &mut MediaEvent) {
+            assert_eq!(ev.current_time(), 0.);
+            assert!(ev.paused());
+            this.task_with(|this, _| {
+                assert_eq!(this.volume.get(), 0.5);
+                assert!(this.muted.get());
+                assert_eq!(this.current_time.get(), 0.);
+                assert!(this.paused.get());
+                (this.callback.take().unwrap())();
+            });
+        }
+    }
+
+    impl ComponentTest for Parent {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    test_component::<Parent>().await;
+}
+
+#[wasm_bindgen_test]
+async fn aria_attributes() {
+    use maomi_dom::aria::*;
+
+    #[component(Backend = DomBackend)]
+    struct Parent {
+        callback: Option<ComponentTestCb>,
+        template: template! {
+            <div
+                role=&{Role::Tab}
+                tabindex=0
+                lang="en"
+                aria_selected={&self.selected}
+                aria_expanded={&self.expanded}
+                aria_level=2
+                aria_live=&{AriaLive::Polite}
+                aria_valuenow=0.5
+                aria_label="Tab"
+            />
+        },
+        selected: bool,
+        expanded: Option<bool>,
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                template: Default::default(),
+                selected: false,
+                expanded: Some(false),
+            }
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            async_task(async move {
+                this.get(|this| {
+                    assert_eq!(
+                        first_dom!(this, div).outer_html(),
+                        r#"<div role="tab" tabindex="0" lang="en" aria-selected="false" aria-expanded="false" aria-level="2" aria-live="polite" aria-valuenow="0.5" aria-label="Tab"></div>"#,
+                    );
+                })
+                .await;
+                this.update(|this| {
+                    this.selected = true;
+                    this.expanded = None;
+                })
+                .await
+                .unwrap();
+                this.update_with(|this, _| {
+                    assert_eq!(
+                        first_dom!(this, div).get_attribute("aria-selected").as_deref(),
+                        Some("true"),
+                    );
+                    assert!(!first_dom!(this, div).has_attribute("aria-expanded"));
+                    (this.callback.take().unwrap())();
+                })
+                .await
+                .unwrap();
+            });
+        }
+    }
+
+    impl ComponentTest for Parent {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    test_component::<Parent>().await;
+}
+
+#[wasm_bindgen_test]
+async fn data_attributes() {
+    #[component(Backend = DomBackend)]
+    struct Parent {
+        callback: Option<ComponentTestCb>,
+        template: template! {
+            <div data:test_id="item" data:index={ &self.index } dataset:"label"={ &self.label } attrs:"x-vendor"="v" />
+            <object data="a.svg" data:index={ &self.index } />
+        },
+        index: i32,
+        label: Option<String>,
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                template: Default::default(),
+                index: 1,
+                label: Some("a\"b".to_string()),
+            }
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            async_task(async move {
+                this.get(|this| {
+                    let div = first_dom!(this, div).clone();
+                    assert_eq!(
+                        div.outer_html(),
+                        r#"<div data-test-id="item" data-index="1" data-label="a&quot;b" x-vendor="v"></div>"#,
+                    );
+                    let object = this.template_structure().unwrap()[1]
+                        .as_ref::<maomi::node::Node<object>>()
+                        .tag
+                        .dom_element()
+                        .clone();
+                    assert_eq!(object.get_attribute("data").as_deref(), Some("a.svg"));
+                    assert_eq!(object.get_attribute("data-index").as_deref(), Some("1"));
+                })
+                .await;
+                this.update(|this| {
+                    this.index = 2;
+                    this.label = None;
+                })
+                .await
+                .unwrap();
+                this.update_with(|this, _| {
+                    let div = first_dom!(this, div).clone();
+                    assert_eq!(div.get_attribute("data-index").as_deref(), Some("2"));
+                    assert_eq!(div.get_attribute("data-label"), None);
+                    (this.callback.take().unwrap())();
+                })
+                .await
+                .unwrap();
+            });
+        }
+    }
+
+    impl ComponentTest for Parent {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    test_component::<Parent>().await;
+}
+
+#[wasm_bindgen_test]
+async fn custom_element_usage() {
+    pub struct ValueChanged;
+
+    impl CustomEventType for ValueChanged {
+        const NAME: &'static str = "value-changed";
+    }
+
+    #[custom_element_definition(tag = "x-test-slider")]
+    pub struct x_test_slider {
+        pub label: attribute!(&str),
+        pub max_value: property!(f64),
+        pub value_changed: event!(CustomDomEvent<ValueChanged>),
+    }
+
+    #[component(Backend = DomBackend)]
+    struct Parent {
+        callback: Option<ComponentTestCb>,
+        template: template! {
+            <x_test_slider label={ &self.label } max_value={ &self.max_value } value_changed=@changed() />
+        },
+        label: String,
+        max_value: f64,
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                template: Default::default(),
+                label: "a".to_string(),
+                max_value: 10.,
+            }
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            async_task(async move {
+                this.get(|this| {
+                    let elem = first_dom!(this, x_test_slider).clone();
+                    assert_eq!(elem.outer_html(), r#"<x-test-slider label="a"></x-test-slider>"#);
+                    let max_value = js_sys::Reflect::get(&elem, &JsValue::from_str("maxValue")).unwrap();
+                    assert_eq!(max_value.as_f64(), Some(10.));
+                })
+                .await;
+                this.update(|this| {
+                    this.label = "b".to_string();
+                    this.max_value = 20.;
+                })
+                .await
+                .unwrap();
+                let elem = this
+                    .get(|this| {
+                        let elem = first_dom!(this, x_test_slider).clone();
+                        assert_eq!(elem.get_attribute("label").as_deref(), Some("b"));
+                        let max_value = js_sys::Reflect::get(&elem, &JsValue::from_str("maxValue")).unwrap();
+                        assert_eq!(max_value.as_f64(), Some(20.));
+                        elem
+                    })
+                    .await;
+                let init = web_sys::CustomEventInit::new();
+                init.set_detail(&JsValue::from_f64(5.));
+                let ev = web_sys::CustomEvent::new_with_event_init_dict("value-changed", &init).unwrap();
+                elem.dispatch_event(&ev).unwrap();
+            });
+        }
+    }
+
+    impl ComponentTest for Parent {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    impl Parent {
+        fn changed(this: ComponentRc<Self>, detail: &mut CustomEvent) {
+            let value = detail.detail().as_f64();
+            this.task(move |this| {
+                assert_eq!(value, Some(5.));
+                (this.callback.take().unwrap())();
+            });
+        }
+    }
+
+    test_component::<Parent>().await;
+}
+
+#[wasm_bindgen_test]
+async fn custom_element_export() {
+    #[component(Backend = DomBackend)]
+    struct Counter {
+        template: template! {
+            <span>{ &self.count.to_string() }</span>
+        },
+        count: Prop<i32>,
+        changed: Event<i32>,
+    }
+
+    impl Component for Counter {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                count: Prop::new(0),
+                changed: Event::new(),
+            }
+        }
+    }
+
+    impl CustomElementComponent for Counter {
+        fn attributes(attrs: &mut CustomElementAttributes<Self>) {
+            attrs.prop("count", |this| &mut this.count);
+        }
+
+        fn observed_attributes() -> &'static [&'static str] {
+            &["ping"]
+        }
+
+        fn attribute_changed(&mut self, name: &str, value: Option<&str>) {
+            assert_eq!(name, "ping");
+            if value.is_some() {
+                let mut count = *self.count;
+                self.changed.trigger(&mut count);
+            }
+        }
+
+        fn dispatch_events(&mut self, dispatcher: &CustomEventDispatcher) {
+            dispatcher.bind(&mut self.changed, "count-changed");
+        }
+    }
+
+    define_custom_element::<Counter>("maomi-test-counter").unwrap();
+    assert!(define_custom_element::<Counter>("maomi-test-counter").is_err());
+    assert!(define_custom_element::<Counter>("counter").is_err());
+    let document = web_sys::window().unwrap().document().unwrap();
+    let host = document.create_element("maomi-test-counter").unwrap();
+    host.set_attribute("count", "1").unwrap();
+    document.body().unwrap().append_child(&host).unwrap();
+    assert_eq!(host.inner_html(), "<span>1</span>");
+    let changed = js_sys::Promise::new(&mut |resolve, _| {
+        host.add_event_listener_with_callback("count-changed", &resolve).unwrap();
+    });
+    host.set_attribute("count", "2").unwrap();
+    host.set_attribute("ping", "").unwrap();
+    let ev: web_sys::CustomEvent = wasm_bindgen_futures::JsFuture::from(changed)
+        .await
+        .unwrap()
+        .unchecked_into();
+    assert_eq!(ev.detail().as_f64(), Some(2.));
+    assert_eq!(host.inner_html(), "<span>2</span>");
+    host.remove();
+}
+
+#[wasm_bindgen_test]
+async fn portal_content() {
+    #[component(Backend = DomBackend)]
+    struct Parent {
+        callback: Option<ComponentTestCb>,
+        template: template! {
+            <div>
+                "a"
+                if self.show {
+                    <portal target={ &self.target }>
+                        <span> { &self.text } </span>
+                        if self.show_extra {
+                            <span> "extra" </span>
+                        }
+                    </portal>
+                }
+                "b"
+            </div>
+        },
+        show: bool,
+        show_extra: bool,
+        text: String,
+        target: Option<web_sys::Element>,
+        containers: [web_sys::Element; 2],
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            let document = web_sys::window().unwrap().document().unwrap();
+            let containers = [
+                document.create_element("div").unwrap(),
+                document.create_element("div").unwrap(),
+            ];
+            Self {
+                callback: None,
+                template: Default::default(),
+                show: true,
+                show_extra: false,
+                text: "c".to_string(),
+                target: Some(containers[0].clone()),
+                containers,
+            }
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            async_task(async move {
+                this.get(|this| {
+                    assert_eq!(first_dom!(this, div).outer_html(), "<div>ab</div>");
+                    assert_eq!(this.containers[0].inner_html(), "<span>c</span>");
+                })
+                .await;
+                this.update(|this| {
+                    this.text = "d".to_string();
+                    this.show_extra = true;
+                })
+                .await
+                .unwrap();
+                this.get(|this| {
+                    assert_eq!(
+                        this.containers[0].inner_html(),
+                        "<span>d</span><span>extra</span>",
+                    );
+                })
+                .await;
+                this.update(|this| {
+                    this.target = Some(this.containers[1].clone());
+                })
+                .await
+                .unwrap();
+                this.get(|this| {
+                    assert_eq!(this.containers[0].inner_html(), "");
+                    assert_eq!(
+                        this.containers[1].inner_html(),
+                        "<span>d</span><span>extra</span>",
+                    );
+                })
+                .await;
+                this.update(|this| {
+                    this.target = None;
+                })
+                .await
+                .unwrap();
+                this.get(|this| {
+                    assert_eq!(this.containers[1].inner_html(), "");
+                })
+                .await;
+                this.update(|this| {
+                    this.target = Some(this.containers[0].clone());
+                })
+                .await
+                .unwrap();
+                this.update(|this| {
+                    this.show = false;
+                })
+                .await
+                .unwrap();
+                this.update_with(|this, _| {
+                    assert_eq!(first_dom!(this, div).outer_html(), "<div>ab</div>");
+                    assert_eq!(this.containers[0].inner_html(), "");
+                    (this.callback.take().unwrap())();
+                })
+                .await
+                .unwrap();
+            });
+        }
+    }
+
+    impl ComponentTest for Parent {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    test_component::<Parent>().await;
+}
+
+#[wasm_bindgen_test]
+async fn locale_str_attributes() {
+    use maomi::locale_string::LocaleString;
+
+    #[component(Backend = DomBackend)]
+    struct Parent {
+        callback: Option<ComponentTestCb>,
+        template: template! {
+            <div title="Title">
+                <img alt={ &self.alt } />
+                <input placeholder={ self.placeholder.as_str() } />
+            </div>
+        },
+        alt: LocaleString,
+        placeholder: String,
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                callback: None,
+                template: Default::default(),
+                alt: LocaleString::translated("Image"),
+                placeholder: "Name".to_string(),
+            }
+        }
+
+        fn created(&self) {
+            let this = self.rc();
+            this.task_with(|this, _| {
+                assert_eq!(
+                    first_dom!(this, div).outer_html(),
+                    r#"<div title="Title"><img alt="Image"><input placeholder="Name"></div>"#,
+                );
+                (this.callback.take().unwrap())();
+            });
+        }
+    }
+
+    impl ComponentTest for Parent {
+        fn set_callback(&mut self, callback: ComponentTestCb) {
+            self.callback = Some(callback);
+        }
+    }
+
+    test_component::<Parent>().await;
+}
@@ -333,6 +333,11 @@ impl ToTokens for ComponentBody {
                             &self.#template_field
                         }
 
+                        #[inline]
+                        fn template_mut(&mut self) -> &mut Self::TemplateField {
+                            &mut self.#template_field
+                        }
+
                         #[inline]
                         fn template_init(&mut self, __m_init: maomi::template::TemplateInit<#component_name>) {
                             self.#template_field.init(__m_init);
@@ -114,6 +114,19 @@ impl LocaleGroup {
         }
     }
 
+    // find the translation without writing format metadata,
+    // used for the strings that may not need translation, i.e. static attribute values
+    pub(crate) fn try_trans<'a>(&'a self, s: &'a str) -> TransRes<'a> {
+        match &self.inner {
+            LocaleGroupStatus::Normal(x) => match x.get(s) {
+                None => TransRes::LackTrans,
+                Some(s) => TransRes::Done(s),
+            },
+            LocaleGroupStatus::Missing(x) => TransRes::LackTransGroup(x.as_str()),
+            LocaleGroupStatus::NotNeeded => TransRes::NotNeeded,
+        }
+    }
+
     pub(crate) fn trans<'a>(&'a self, s: &'a str) -> TransRes<'a> {
         match &self.inner {
             LocaleGroupStatus::Normal(x) => {
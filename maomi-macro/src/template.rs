@@ -3,6 +3,7 @@ use proc_macro2::TokenStream;
 use quote::*;
 use syn::parse::*;
 use syn::punctuated::Punctuated;
+use syn::ext::IdentExt;
 use syn::spanned::Spanned;
 use syn::*;
 
@@ -469,14 +470,14 @@ impl Parse for TemplateNode {
 pub(super) enum TemplateAttribute {
     StaticProperty {
         name: Ident,
-        list_updater: Option<(token::Colon, Path)>,
+        list_updater: Option<(token::Colon, ListItemUpdater)>,
         #[allow(dead_code)]
         eq_token: token::Eq,
         value: Lit,
     },
     DynamicProperty {
         name: Ident,
-        list_updater: Option<(token::Colon, Path)>,
+        list_updater: Option<(token::Colon, ListItemUpdater)>,
         #[allow(dead_code)]
         eq_token: token::Eq,
         ref_token: Option<token::And>,
@@ -517,6 +518,82 @@ impl TemplateAttribute {
     }
 }
 
+pub(super) enum ListItemUpdater {
+    // the `xxx:Updater=` form, updated with the `ListPropertyItem`
+    Path(Path),
+    // the `xxx:"name"=` form, updated with the item name
+    Named(LitStr),
+}
+
+impl Parse for ListItemUpdater {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(LitStr) {
+            let item_name: LitStr = input.parse()?;
+            if !is_valid_item_name(&item_name.value()) {
+                return Err(Error::new(item_name.span(), "Illegal item name"));
+            }
+            Ok(Self::Named(item_name))
+        } else {
+            Ok(Self::Path(input.parse()?))
+        }
+    }
+}
+
+// the item names are usually used as attribute names
+fn is_valid_item_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name
+            .chars()
+            .any(|c| c.is_whitespace() || c.is_control() || matches!(c, '"' | '\'' | '>' | '/' | '=' | '<'))
+}
+
+// `data:xxx=` is a shorthand of `dataset:"xxx"=` , with the `_` in `xxx` replaced by `-`
+fn expand_data_list(
+    name: Ident,
+    list_updater: Option<(token::Colon, ListItemUpdater)>,
+) -> Result<(Ident, Option<(token::Colon, ListItemUpdater)>)> {
+    let (colon_token, updater) = match list_updater {
+        Some(x) if name == "data" => x,
+        list_updater => return Ok((name, list_updater)),
+    };
+    let item_name = match updater {
+        ListItemUpdater::Named(x) => x,
+        ListItemUpdater::Path(path) => match path.get_ident() {
+            Some(x) => LitStr::new(&x.unraw().to_string().replace('_', "-"), x.span()),
+            None => {
+                return Err(Error::new(path.span(), "The `data` item name should be an identifier"))
+            }
+        },
+    };
+    let name = Ident::new("dataset", name.span());
+    Ok((name, Some((colon_token, ListItemUpdater::Named(item_name)))))
+}
+
+fn list_item_update(name: &Ident, updater: &ListItemUpdater, list_index: usize, value: TokenStream, span: proc_macro2::Span) -> TokenStream {
+    let index = Index::from(list_index);
+    match updater {
+        ListItemUpdater::Named(item_name) => {
+            quote_spanned! {span=>
+                maomi::prop::NamedListPropertyUpdate::compare_and_set_named_item_ref(
+                    &mut __m_child.#name,
+                    #index,
+                    #item_name,
+                    #value,
+                    __m_update_ctx,
+                );
+            }
+        }
+        ListItemUpdater::Path(updater) => quote_spanned! {span=>
+            maomi::prop::ListPropertyUpdate::compare_and_set_item_ref::<#updater>(
+                &mut __m_child.#name,
+                #index,
+                #value,
+                __m_update_ctx,
+            );
+        },
+    }
+}
+
 impl Parse for TemplateAttribute {
     fn parse(input: ParseStream) -> Result<Self> {
         let la = input.lookahead1();
@@ -534,6 +611,7 @@ impl Parse for TemplateAttribute {
                 } else {
                     None
                 };
+                let (name, list_updater) = expand_data_list(name, list_updater)?;
                 if input.peek(token::Eq) {
                     let eq_token = input.parse()?;
                     let la = input.lookahead1();
@@ -866,7 +944,7 @@ impl<'a> ToTokens for TemplateNodeUpdate<'a> {
                     } else {
                         0
                     };
-                    (TemplateAttributeCreate { attr, list_index }, TemplateAttributeUpdate { attr, list_index })
+                    (TemplateAttributeCreate { attr, list_index, locale_group }, TemplateAttributeUpdate { attr, list_index, locale_group })
                 }).unzip();
                 let (list_prop_name, list_prop_count): (Vec<&Ident>, Vec<usize>) = list_prop_count.iter().unzip();
                 let slot_var_name_def = match slot_var_name {
@@ -1247,11 +1325,12 @@ impl<'a> ToTokens for TemplateNodeUpdate<'a> {
 pub(super) struct TemplateAttributeCreate<'a> {
     attr: &'a TemplateAttribute,
     list_index: usize,
+    locale_group: &'a LocaleGroup,
 }
 
 impl<'a> ToTokens for TemplateAttributeCreate<'a> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let Self { attr, list_index } = self;
+        let Self { attr, list_index, locale_group } = self;
         match attr {
             TemplateAttribute::StaticProperty { name, list_updater, value, eq_token, .. } => {
                 let span = eq_token.span();
@@ -1260,15 +1339,32 @@ impl<'a> ToTokens for TemplateAttributeCreate<'a> {
                     _ => quote_spanned!{span=> & },
                 };
                 if let Some((_, updater)) = list_updater {
-                    let index = Index::from(*list_index);
+                    list_item_update(name, updater, *list_index, quote_spanned!(span=> #ref_sign #value), span).to_tokens(tokens);
+                } else if !locale_group.need_trans() {
                     quote_spanned! {span=>
-                        maomi::prop::ListPropertyUpdate::compare_and_set_item_ref::<#updater>(
+                        maomi::prop::UntranslatedPropertyUpdate::compare_and_set_untranslated(
                             &mut __m_child.#name,
-                            #index,
                             #ref_sign #value,
                             __m_update_ctx,
                         );
                     }.to_tokens(tokens);
+                } else if let Lit::Str(content) = value {
+                    // the literal is also provided in the translated form, in case the property requires it
+                    let translated = match locale_group.try_trans(&content.value()) {
+                        TransRes::Done(x) => {
+                            let s = LitStr::new(x, span);
+                            quote_spanned! {span=> maomi::locale_string::LocaleStaticStr::translated(#s) }
+                        }
+                        _ => quote_spanned! {span=> maomi::locale_string::LackTranslation },
+                    };
+                    quote_spanned! {span=>
+                        maomi::prop::StaticStrPropertyUpdate::compare_and_set_static_str(
+                            &mut __m_child.#name,
+                            #value,
+                            #translated,
+                            __m_update_ctx,
+                        );
+                    }.to_tokens(tokens);
                 } else {
                     quote_spanned! {span=>
                         maomi::prop::PropertyUpdate::compare_and_set_ref(
@@ -1286,11 +1382,11 @@ impl<'a> ToTokens for TemplateAttributeCreate<'a> {
                     None => quote_spanned!(span=> #expr),
                 };
                 if let Some((_, updater)) = list_updater {
-                    let index = Index::from(*list_index);
+                    list_item_update(name, updater, *list_index, expr, span).to_tokens(tokens);
+                } else if !locale_group.need_trans() {
                     quote_spanned! {span=>
-                        maomi::prop::ListPropertyUpdate::compare_and_set_item_ref::<#updater>(
+                        maomi::prop::UntranslatedPropertyUpdate::compare_and_set_untranslated(
                             &mut __m_child.#name,
-                            #index,
                             #expr,
                             __m_update_ctx,
                         );
@@ -1336,11 +1432,12 @@ impl<'a> ToTokens for TemplateAttributeCreate<'a> {
 pub(super) struct TemplateAttributeUpdate<'a> {
     attr: &'a TemplateAttribute,
     list_index: usize,
+    locale_group: &'a LocaleGroup,
 }
 
 impl<'a> ToTokens for TemplateAttributeUpdate<'a> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let Self { attr, list_index } = self;
+        let Self { attr, list_index, locale_group } = self;
         match attr {
             TemplateAttribute::StaticProperty { .. } => {
                 // empty
@@ -1359,11 +1456,11 @@ impl<'a> ToTokens for TemplateAttributeUpdate<'a> {
                     None => quote_spanned!(span=> #expr),
                 };
                 if let Some((_, updater)) = list_updater {
-                    let index = Index::from(*list_index);
+                    list_item_update(name, updater, *list_index, expr, span).to_tokens(tokens);
+                } else if !locale_group.need_trans() {
                     quote_spanned! {span=>
-                        maomi::prop::ListPropertyUpdate::compare_and_set_item_ref::<#updater>(
+                        maomi::prop::UntranslatedPropertyUpdate::compare_and_set_untranslated(
                             &mut __m_child.#name,
-                            #index,
                             #expr,
                             __m_update_ctx,
                         );
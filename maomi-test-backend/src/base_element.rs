@@ -0,0 +1,200 @@
+//! Basic types for test elements.
+
+use maomi::prop::PropertyUpdate;
+use std::{any::Any, borrow::Borrow, ops::Deref, rc::Rc};
+
+pub(crate) type TestEventHandlerFn = Rc<dyn Fn(&mut dyn Any)>;
+
+/// A tagged element in the test backend.
+///
+/// It records the attributes and the event handlers set through templates.
+pub struct TestElement {
+    tag_name: &'static str,
+    attrs: Vec<(&'static str, String)>,
+    events: Vec<(&'static str, TestEventHandlerFn)>,
+}
+
+impl std::fmt::Debug for TestElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ret = Vec::new();
+        self.write_start_tag(&mut ret).unwrap();
+        write!(f, "{}", String::from_utf8_lossy(&ret))
+    }
+}
+
+impl TestElement {
+    pub(crate) fn new(tag_name: &'static str) -> Self {
+        Self {
+            tag_name,
+            attrs: vec![],
+            events: vec![],
+        }
+    }
+
+    /// Get the tag name.
+    #[inline]
+    pub fn tag_name(&self) -> &'static str {
+        self.tag_name
+    }
+
+    /// Get the value of an attribute.
+    ///
+    /// Boolean attributes have an empty value when they are `true` .
+    #[inline]
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Iterate over the attributes in the order they are set.
+    #[inline]
+    pub fn attributes(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        self.attrs.iter().map(|(n, v)| (*n, v.as_str()))
+    }
+
+    /// Get the class names in the `class` attribute.
+    #[inline]
+    pub fn class_names(&self) -> impl Iterator<Item = &str> {
+        self.attribute("class").unwrap_or_default().split_ascii_whitespace()
+    }
+
+    /// Get the handler of an event.
+    ///
+    /// The returned function can be called after the backend is released,
+    /// so that the handler can visit the backend context freely.
+    /// It panics if the event detail type does not match.
+    #[inline]
+    pub fn event_handler<D: 'static>(&self, name: &str) -> Option<impl Fn(&mut D)> {
+        let f = self
+            .events
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, f)| f.clone())?;
+        Some(move |detail: &mut D| f(detail))
+    }
+
+    pub(crate) fn set_attribute(&mut self, name: &'static str, value: String) {
+        if let Some((_, v)) = self.attrs.iter_mut().find(|(n, _)| *n == name) {
+            *v = value;
+        } else {
+            self.attrs.push((name, value));
+        }
+    }
+
+    pub(crate) fn remove_attribute(&mut self, name: &'static str) {
+        if let Some(index) = self.attrs.iter().position(|(n, _)| *n == name) {
+            self.attrs.remove(index);
+        }
+    }
+
+    pub(crate) fn set_event_handler(&mut self, name: &'static str, f: TestEventHandlerFn) {
+        if let Some((_, v)) = self.events.iter_mut().find(|(n, _)| *n == name) {
+            *v = f;
+        } else {
+            self.events.push((name, f));
+        }
+    }
+
+    pub(crate) fn write_start_tag(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        write!(w, "<{}", self.tag_name)?;
+        for (name, value) in &self.attrs {
+            write!(w, r#" {}=""#, name)?;
+            html_escape::encode_double_quoted_attribute_to_writer(value, w)?;
+            write!(w, r#"""#)?;
+        }
+        write!(w, ">")?;
+        Ok(())
+    }
+
+    pub(crate) fn write_end_tag(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        write!(w, "</{}>", self.tag_name)
+    }
+}
+
+/// The attributes that accepts a string value.
+pub struct TestStrAttr {
+    inner: String,
+    attr_name: &'static str,
+}
+
+impl TestStrAttr {
+    #[doc(hidden)]
+    pub fn new(attr_name: &'static str) -> Self {
+        Self {
+            inner: String::new(),
+            attr_name,
+        }
+    }
+}
+
+impl Deref for TestStrAttr {
+    type Target = String;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<S: ?Sized + PartialEq + ToOwned<Owned = String>> PropertyUpdate<S> for TestStrAttr
+where
+    String: Borrow<S>,
+{
+    type UpdateContext = TestElement;
+
+    #[inline]
+    fn compare_and_set_ref(dest: &mut Self, src: &S, ctx: &mut TestElement) {
+        if dest.inner.borrow() == src {
+            return;
+        }
+        dest.inner = src.to_owned();
+        ctx.set_attribute(dest.attr_name, dest.inner.clone());
+    }
+}
+
+/// The attributes that accepts a boolean value.
+///
+/// The boolean attributes are always default to `false` .
+/// In templates, they can be used without `=` like `<div hidden />` .
+pub struct TestBoolAttr {
+    inner: bool,
+    attr_name: &'static str,
+}
+
+impl TestBoolAttr {
+    #[doc(hidden)]
+    pub fn new(attr_name: &'static str) -> Self {
+        Self {
+            inner: false,
+            attr_name,
+        }
+    }
+}
+
+impl Deref for TestBoolAttr {
+    type Target = bool;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl PropertyUpdate<bool> for TestBoolAttr {
+    type UpdateContext = TestElement;
+
+    #[inline]
+    fn compare_and_set_ref(dest: &mut Self, src: &bool, ctx: &mut TestElement) {
+        if dest.inner == *src {
+            return;
+        }
+        dest.inner = *src;
+        if dest.inner {
+            ctx.set_attribute(dest.attr_name, String::new());
+        } else {
+            ctx.remove_attribute(dest.attr_name);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! The element definition.
+//!
+//! The test backend provides a small set of common elements.
+//! All elements share the global attributes `id` , `class` , `title` , `hidden`
+//! and the global events `click` and `tap` .
+
+use maomi::{
+    backend::{tree::*, BackendComponent, SupportBackend},
+    error::Error,
+    node::{OwnerWeak, SlotChange, StaticSingleSlot},
+    BackendContext,
+};
+
+use crate::{
+    base_element::*,
+    event::{ChangeEvent, TestEvent},
+    TestBackend,
+    TestGeneralElement,
+};
+
+macro_rules! define_element {
+    ($tag_name:ident { $($attr:ident: $ty:ty,)* }) => {
+        #[doc = concat!("The `<", stringify!($tag_name), ">` element.")]
+        #[allow(non_camel_case_types)]
+        pub struct $tag_name {
+            backend_element_token: ForestToken,
+            /// The `id` attribute.
+            pub id: TestStrAttr,
+            /// The `class` attribute.
+            pub class: TestStrAttr,
+            /// The `title` attribute.
+            pub title: TestStrAttr,
+            /// The `hidden` attribute.
+            pub hidden: TestBoolAttr,
+            /// The `click` event.
+            pub click: TestEvent<()>,
+            /// The `tap` event.
+            pub tap: TestEvent<()>,
+            $(
+                #[doc = concat!("The `", stringify!($attr), "` attribute or event.")]
+                pub $attr: $ty,
+            )*
+        }
+
+        impl BackendComponent<TestBackend> for $tag_name {
+            type SlotData = ();
+            type UpdateTarget = Self;
+            type UpdateContext = TestElement;
+
+            #[inline]
+            fn init<'b>(
+                _backend_context: &'b BackendContext<TestBackend>,
+                owner: &'b mut ForestNodeMut<TestGeneralElement>,
+                _owner_weak: &'b Box<dyn OwnerWeak>,
+            ) -> Result<(Self, ForestNodeRc<TestGeneralElement>), Error>
+            where
+                Self: Sized,
+            {
+                let backend_element = owner.new_tree(TestGeneralElement::Element(
+                    TestElement::new(stringify!($tag_name)),
+                ));
+                let this = Self {
+                    backend_element_token: backend_element.token(),
+                    id: TestStrAttr::new("id"),
+                    class: TestStrAttr::new("class"),
+                    title: TestStrAttr::new("title"),
+                    hidden: TestBoolAttr::new("hidden"),
+                    click: TestEvent::new("click"),
+                    tap: TestEvent::new("tap"),
+                    $($attr: <$ty>::new(stringify!($attr)),)*
+                };
+                Ok((this, backend_element))
+            }
+
+            #[inline]
+            fn create<'b>(
+                &'b mut self,
+                _backend_context: &'b BackendContext<TestBackend>,
+                owner: &'b mut ForestNodeMut<TestGeneralElement>,
+                update_fn: Box<dyn 'b + FnOnce(&mut Self, &mut Self::UpdateContext)>,
+                slot_fn: &mut dyn FnMut(
+                    &mut ForestNodeMut<TestGeneralElement>,
+                    &ForestToken,
+                    &Self::SlotData,
+                ) -> Result<(), Error>,
+            ) -> Result<(), Error> {
+                let mut node = owner
+                    .borrow_mut_token(&self.backend_element_token)
+                    .ok_or(Error::TreeNodeReleased)?;
+                update_fn(self, &mut TestGeneralElement::as_test_element_mut(&mut node).unwrap());
+                slot_fn(&mut node, &self.backend_element_token, &())?;
+                Ok(())
+            }
+
+            #[inline]
+            fn apply_updates<'b>(
+                &'b mut self,
+                _backend_context: &'b BackendContext<TestBackend>,
+                owner: &'b mut ForestNodeMut<TestGeneralElement>,
+                update_fn: Box<dyn 'b + FnOnce(&mut Self, &mut Self::UpdateContext)>,
+                slot_fn: &mut dyn FnMut(
+                    SlotChange<&mut ForestNodeMut<TestGeneralElement>, &ForestToken, &Self::SlotData>,
+                ) -> Result<(), Error>,
+            ) -> Result<(), Error> {
+                let mut node = owner
+                    .borrow_mut_token(&self.backend_element_token)
+                    .ok_or(Error::TreeNodeReleased)?;
+                update_fn(self, &mut TestGeneralElement::as_test_element_mut(&mut node).unwrap());
+                slot_fn(SlotChange::Unchanged(&mut node, &self.backend_element_token, &()))?;
+                Ok(())
+            }
+        }
+
+        impl SupportBackend for $tag_name {
+            type Target = Self;
+            type SlotChildren = StaticSingleSlot<ForestTokenAddr, maomi::node::DynNodeList>;
+        }
+    };
+}
+
+define_element!(div {});
+define_element!(span {});
+define_element!(p {});
+define_element!(ul {});
+define_element!(ol {});
+define_element!(li {});
+define_element!(h1 {});
+define_element!(h2 {});
+define_element!(h3 {});
+define_element!(a {
+    href: TestStrAttr,
+});
+define_element!(img {
+    src: TestStrAttr,
+    alt: TestStrAttr,
+});
+define_element!(button {
+    disabled: TestBoolAttr,
+});
+define_element!(label {});
+define_element!(input {
+    value: TestStrAttr,
+    placeholder: TestStrAttr,
+    disabled: TestBoolAttr,
+    change: TestEvent<ChangeEvent>,
+});
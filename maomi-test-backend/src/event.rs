@@ -0,0 +1,61 @@
+//! The event types of the test elements.
+
+use maomi::event::EventHandler;
+use std::{any::Any, marker::PhantomData, rc::Rc};
+
+use crate::base_element::TestElement;
+
+/// An element event in the test backend.
+///
+/// The handler is stored in the `TestElement` ,
+/// and can be fetched with `TestElement::event_handler` .
+pub struct TestEvent<D> {
+    event_name: &'static str,
+    _phantom: PhantomData<D>,
+}
+
+impl<D> TestEvent<D> {
+    #[doc(hidden)]
+    pub fn new(event_name: &'static str) -> Self {
+        Self {
+            event_name,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<D: 'static> EventHandler<D> for TestEvent<D> {
+    type UpdateContext = TestElement;
+
+    #[inline]
+    fn set_handler_fn(
+        dest: &mut Self,
+        handler_fn: Box<dyn 'static + Fn(&mut D)>,
+        ctx: &mut Self::UpdateContext,
+    ) {
+        let f = move |detail: &mut dyn Any| {
+            let detail = detail
+                .downcast_mut::<D>()
+                .expect("The event detail type does not match");
+            handler_fn(detail)
+        };
+        ctx.set_event_handler(dest.event_name, Rc::new(f));
+    }
+}
+
+/// The detail of the `change` event.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChangeEvent {
+    /// The new value.
+    pub value: String,
+}
+
+impl ChangeEvent {
+    /// Create with the new value.
+    #[inline]
+    pub fn new(value: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+        }
+    }
+}
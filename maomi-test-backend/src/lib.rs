@@ -0,0 +1,417 @@
+//! maomi: a rust framework for building pages with components
+//!
+//! This is the *in-memory test backend* of the framework.
+//!
+//! The backend records elements, attributes and text nodes in memory,
+//! so that components can be tested with plain `cargo test` without any browser environment.
+//! The rendered tree can be serialized into an HTML-like snapshot for assertions.
+//! See the [`testing`] module for a higher level test harness.
+//!
+//! ### Quick Start
+//!
+//! ```rust
+//! use maomi::prelude::*;
+//! use maomi_test_backend::{prelude::*, element::*};
+//!
+//! #[component(Backend = TestBackend)]
+//! struct HelloWorld {
+//!     template: template! {
+//!         <div id="hello">
+//!             { &self.text }
+//!         </div>
+//!     },
+//!     text: String,
+//! }
+//!
+//! impl Component for HelloWorld {
+//!     fn new() -> Self {
+//!         Self {
+//!             template: Default::default(),
+//!             text: "Hello world!".into(),
+//!         }
+//!     }
+//! }
+//!
+//! let backend_context = maomi::BackendContext::new(TestBackend::new());
+//! let mount_point = backend_context
+//!     .enter_sync(move |ctx| {
+//!         ctx.attach(|_: &mut HelloWorld| {}).unwrap()
+//!     })
+//!     .map_err(|_| "Cannot init mount point")
+//!     .unwrap();
+//! let html = backend_context
+//!     .enter_sync(|ctx| ctx.inner_html())
+//!     .map_err(|_| "Cannot visit backend")
+//!     .unwrap();
+//! assert_eq!(html, r#"<div id="hello">Hello world!</div>"#);
+//!
+//! // updates are applied in async tasks, which should be driven explicitly
+//! let comp = mount_point.root_component().rc();
+//! maomi_test_backend::async_task(async move {
+//!     comp.update(|this| this.text = "Hello again!".into()).await.unwrap();
+//! });
+//! maomi_test_backend::run_until_stalled();
+//! let html = backend_context
+//!     .enter_sync(|ctx| ctx.inner_html())
+//!     .map_err(|_| "Cannot visit backend")
+//!     .unwrap();
+//! assert_eq!(html, r#"<div id="hello">Hello again!</div>"#);
+//! ```
+//!
+
+#![warn(missing_docs)]
+
+use futures::{
+    executor::{LocalPool, LocalSpawner},
+    task::LocalSpawnExt,
+};
+use maomi::{
+    backend::{tree::*, *},
+    error::Error,
+};
+use std::cell::RefCell;
+
+pub mod base_element;
+use base_element::TestElement;
+pub mod element;
+pub mod event;
+mod virtual_element;
+use virtual_element::TestVirtualElement;
+mod text_node;
+use text_node::TestTextNode;
+pub mod testing;
+
+/// The types that should usually be imported.
+///
+/// Usually, `use maomi_test_backend::prelude::*;` should be added in test files for convinience.
+pub mod prelude {
+    pub use crate::TestBackend;
+    pub use crate::testing::TestHarness;
+}
+
+thread_local! {
+    static LOCAL_POOL: RefCell<LocalPool> = RefCell::new(LocalPool::new());
+    static LOCAL_SPAWNER: LocalSpawner = LOCAL_POOL.with(|pool| pool.borrow().spawner());
+}
+
+/// A common async runner for the test environment
+///
+/// The task is not executed until `run_until_stalled` is called.
+#[inline]
+pub fn async_task(fut: impl 'static + std::future::Future<Output = ()>) {
+    LOCAL_SPAWNER.with(|spawner| {
+        spawner
+            .spawn_local(fut)
+            .expect("Cannot spawn async task in the test backend")
+    });
+}
+
+/// Run all async tasks until none of them can make progress
+///
+/// The backend context updates are applied in async tasks,
+/// so this should be called before checking the results of `ComponentRc::update` and similar methods.
+/// It should not be called inside an async task.
+#[inline]
+pub fn run_until_stalled() {
+    LOCAL_POOL.with(|pool| pool.borrow_mut().run_until_stalled());
+}
+
+/// An in-memory backend for tests
+pub struct TestBackend {
+    tree: tree::ForestNodeRc<TestGeneralElement>,
+}
+
+impl Default for TestBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TestBackend {
+    /// Create an empty backend
+    ///
+    /// The root element is a `<maomi>` element,
+    /// which is not included in the `inner_html` result.
+    #[inline]
+    pub fn new() -> Self {
+        let tree = tree::ForestNodeRc::new_forest(TestGeneralElement::Element(TestElement::new(
+            "maomi",
+        )));
+        Self { tree }
+    }
+
+    /// Serialize the whole rendered tree as an HTML segment
+    #[inline]
+    pub fn inner_html(&self) -> String {
+        TestGeneralElement::inner_html(&self.root())
+    }
+
+    /// Write the serialized tree to a `Write`
+    #[inline]
+    pub fn write_inner_html(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        TestGeneralElement::write_inner_html(&self.root(), w)
+    }
+}
+
+impl Backend for TestBackend {
+    type GeneralElement = TestGeneralElement;
+    type VirtualElement = TestVirtualElement;
+    type TextNode = TestTextNode;
+
+    #[inline]
+    fn async_task(fut: impl 'static + std::future::Future<Output = ()>) {
+        async_task(fut)
+    }
+
+    #[inline]
+    fn backend_stage(&self) -> BackendStage {
+        BackendStage::Normal
+    }
+
+    #[inline]
+    fn root(&self) -> ForestNode<'_, Self::GeneralElement> {
+        self.tree.borrow()
+    }
+
+    #[inline]
+    fn root_mut(&mut self) -> ForestNodeMut<'_, Self::GeneralElement> {
+        self.tree.borrow_mut()
+    }
+}
+
+/// The general element type of the test backend
+pub enum TestGeneralElement {
+    /// A virtual element
+    Virtual(TestVirtualElement),
+    /// A text node
+    Text(TestTextNode),
+    /// A tagged element
+    Element(TestElement),
+}
+
+impl std::fmt::Debug for TestGeneralElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Virtual(_) => write!(f, "[Virtual {:p}]", self),
+            Self::Text(x) => write!(f, "{:?}", x.text_content()),
+            Self::Element(x) => write!(f, "{:?}", x),
+        }
+    }
+}
+
+impl TestGeneralElement {
+    /// Get the tagged element
+    #[inline]
+    pub fn as_element(&self) -> Option<&TestElement> {
+        if let Self::Element(x) = self {
+            Some(x)
+        } else {
+            None
+        }
+    }
+
+    /// Get the text content if it is a text node
+    #[inline]
+    pub fn as_text(&self) -> Option<&str> {
+        if let Self::Text(x) = self {
+            Some(x.text_content())
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn as_test_element_mut<'b>(
+        this: &'b mut ForestNodeMut<Self>,
+    ) -> Option<ForestValueMut<'b, TestElement>> {
+        if let Self::Element(_) = &mut **this {
+            Some(this.map(|g| {
+                if let Self::Element(e) = g {
+                    e
+                } else {
+                    unreachable!()
+                }
+            }))
+        } else {
+            None
+        }
+    }
+
+    fn write_children_html(
+        this: &ForestNode<Self>,
+        w: &mut impl std::io::Write,
+    ) -> std::io::Result<()> {
+        let mut cur = this.first_child();
+        while let Some(c) = &cur {
+            Self::write_outer_html(c, w)?;
+            cur = c.next_sibling();
+        }
+        Ok(())
+    }
+
+    /// Write the inner HTML of the specified node to a `Write`
+    pub fn write_inner_html(
+        this: &ForestNode<Self>,
+        w: &mut impl std::io::Write,
+    ) -> std::io::Result<()> {
+        match &**this {
+            Self::Text(x) => x.write_html(w),
+            Self::Element(_) | Self::Virtual(_) => Self::write_children_html(this, w),
+        }
+    }
+
+    /// Write the outer HTML of the specified node to a `Write`
+    pub fn write_outer_html(
+        this: &ForestNode<Self>,
+        w: &mut impl std::io::Write,
+    ) -> std::io::Result<()> {
+        match &**this {
+            Self::Text(x) => x.write_html(w),
+            Self::Element(x) => {
+                x.write_start_tag(w)?;
+                Self::write_children_html(this, w)?;
+                x.write_end_tag(w)
+            }
+            Self::Virtual(_) => Self::write_children_html(this, w),
+        }
+    }
+
+    /// Get the inner HTML of the specified node
+    ///
+    /// Virtual elements are transparent in the result.
+    #[inline]
+    pub fn inner_html(this: &ForestNode<Self>) -> String {
+        let mut ret = Vec::new();
+        Self::write_inner_html(this, &mut ret).unwrap();
+        // since all str sources are valid UTF-8, this operation is safe
+        unsafe { String::from_utf8_unchecked(ret) }
+    }
+
+    /// Get the outer HTML of the specified node
+    ///
+    /// Virtual elements are transparent in the result.
+    #[inline]
+    pub fn outer_html(this: &ForestNode<Self>) -> String {
+        let mut ret = Vec::new();
+        Self::write_outer_html(this, &mut ret).unwrap();
+        // since all str sources are valid UTF-8, this operation is safe
+        unsafe { String::from_utf8_unchecked(ret) }
+    }
+}
+
+impl BackendGeneralElement for TestGeneralElement {
+    type BaseBackend = TestBackend;
+
+    #[inline]
+    fn as_virtual_element_mut<'b>(
+        this: &'b mut ForestNodeMut<Self>,
+    ) -> Option<
+        ForestValueMut<
+            'b,
+            <<Self as BackendGeneralElement>::BaseBackend as Backend>::VirtualElement,
+        >,
+    >
+    where
+        Self: Sized,
+    {
+        if let Self::Virtual(_) = &mut **this {
+            Some(this.map(|g| {
+                if let Self::Virtual(e) = g {
+                    e
+                } else {
+                    unreachable!()
+                }
+            }))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn as_text_node_mut<'b>(
+        this: &'b mut ForestNodeMut<Self>,
+    ) -> Option<
+        ForestValueMut<'b, <<Self as BackendGeneralElement>::BaseBackend as Backend>::TextNode>,
+    >
+    where
+        Self: Sized,
+    {
+        if let Self::Text(_) = &mut **this {
+            Some(this.map(|g| {
+                if let Self::Text(e) = g {
+                    e
+                } else {
+                    unreachable!()
+                }
+            }))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn create_virtual_element(
+        this: &mut ForestNodeMut<Self>,
+    ) -> Result<ForestNodeRc<<Self::BaseBackend as Backend>::GeneralElement>, Error>
+    where
+        Self: Sized,
+    {
+        let child = this.new_tree(Self::Virtual(TestVirtualElement::new()));
+        Ok(child)
+    }
+
+    #[inline]
+    fn create_text_node(
+        this: &mut ForestNodeMut<Self>,
+        content: &str,
+    ) -> Result<ForestNodeRc<<Self::BaseBackend as Backend>::GeneralElement>, Error>
+    where
+        Self: Sized,
+    {
+        let child = this.new_tree(Self::Text(TestTextNode::new(content)));
+        Ok(child)
+    }
+
+    #[inline]
+    fn append<'b>(
+        this: &'b mut ForestNodeMut<Self>,
+        child: &'b ForestNodeRc<
+            <<Self as BackendGeneralElement>::BaseBackend as Backend>::GeneralElement,
+        >,
+    ) where
+        Self: Sized,
+    {
+        this.append(child);
+    }
+
+    #[inline]
+    fn insert<'b>(
+        this: &'b mut ForestNodeMut<Self>,
+        target: &'b ForestNodeRc<
+            <<Self as BackendGeneralElement>::BaseBackend as Backend>::GeneralElement,
+        >,
+    ) where
+        Self: Sized,
+    {
+        this.insert(target);
+    }
+
+    #[inline]
+    fn temp_detach(
+        this: ForestNodeMut<Self>,
+    ) -> ForestNodeRc<<<Self as BackendGeneralElement>::BaseBackend as Backend>::GeneralElement>
+    where
+        Self: Sized,
+    {
+        this.detach()
+    }
+
+    #[inline]
+    fn detach(
+        this: ForestNodeMut<Self>,
+    ) -> ForestNodeRc<<<Self as BackendGeneralElement>::BaseBackend as Backend>::GeneralElement>
+    where
+        Self: Sized,
+    {
+        this.detach()
+    }
+}
@@ -0,0 +1,404 @@
+//! The component test harness.
+//!
+//! A `TestHarness` mounts a component in a `TestBackend` ,
+//! drives the async tasks, and provides queries and snapshots on the rendered tree.
+//!
+//! ```rust
+//! use maomi::prelude::*;
+//! use maomi_test_backend::{prelude::*, element::*};
+//!
+//! #[component(Backend = TestBackend)]
+//! struct Counter {
+//!     template: template! {
+//!         <div class="counter">
+//!             <span> { self.count.to_string() } </span>
+//!             <button click=@add()> "+" </button>
+//!         </div>
+//!     },
+//!     count: usize,
+//! }
+//!
+//! impl Component for Counter {
+//!     fn new() -> Self {
+//!         Self {
+//!             template: Default::default(),
+//!             count: 0,
+//!         }
+//!     }
+//! }
+//!
+//! impl Counter {
+//!     fn add(this: ComponentRc<Self>, _: &mut ()) {
+//!         this.task(|this| this.count += 1);
+//!     }
+//! }
+//!
+//! let harness = TestHarness::<Counter>::mount();
+//! let button = harness.find_by_tag("button").unwrap();
+//! assert!(harness.trigger(&button, "click", &mut ()));
+//! assert_eq!(harness.find_by_tag("span").unwrap().text_content(), "1");
+//! harness.assert_snapshot(r#"
+//!     <div class="counter">
+//!       <span>
+//!         "1"
+//!       </span>
+//!       <button>
+//!         "+"
+//!       </button>
+//!     </div>
+//! "#);
+//! ```
+
+use maomi::{
+    backend::tree::*,
+    component::{Component, ComponentRc},
+    error::Error,
+    mount_point::MountPoint,
+    template::ComponentTemplate,
+    BackendContext,
+};
+use std::{cell::Cell, future::Future, rc::Rc};
+
+use crate::{run_until_stalled, TestBackend, TestGeneralElement};
+
+/// Run a future in the test executor until it is resolved.
+///
+/// All other pending async tasks are also driven.
+/// Panics if the future cannot be resolved after all tasks stalled.
+pub fn block_on<T: 'static>(fut: impl 'static + Future<Output = T>) -> T {
+    let ret = Rc::new(Cell::new(None));
+    let ret2 = ret.clone();
+    crate::async_task(async move {
+        ret2.set(Some(fut.await));
+    });
+    run_until_stalled();
+    ret.take()
+        .expect("The future is still pending after all async tasks stalled")
+}
+
+/// A reference to an element in the rendered tree.
+///
+/// The element is kept alive even if it has been removed from the tree.
+/// The methods should not be called while the backend context is entered.
+#[derive(Clone)]
+pub struct ElementRef {
+    node: ForestNodeRc<TestGeneralElement>,
+}
+
+impl std::fmt::Debug for ElementRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", &*self.node.borrow())
+    }
+}
+
+impl ElementRef {
+    fn with<R>(&self, f: impl FnOnce(&crate::base_element::TestElement) -> R) -> R {
+        let n = self.node.borrow();
+        f(n.as_element().unwrap())
+    }
+
+    /// Get the tag name.
+    pub fn tag_name(&self) -> &'static str {
+        self.with(|x| x.tag_name())
+    }
+
+    /// Get the value of an attribute.
+    pub fn attribute(&self, name: &str) -> Option<String> {
+        self.with(|x| x.attribute(name).map(|x| x.to_string()))
+    }
+
+    /// Check whether the element contains the class name.
+    pub fn has_class(&self, class_name: &str) -> bool {
+        self.with(|x| x.class_names().any(|x| x == class_name))
+    }
+
+    /// Get all the text in the element and its descendants.
+    pub fn text_content(&self) -> String {
+        let mut ret = String::new();
+        collect_text(&self.node.borrow(), &mut ret, true);
+        ret
+    }
+
+    /// Get the inner HTML of the element.
+    pub fn inner_html(&self) -> String {
+        TestGeneralElement::inner_html(&self.node.borrow())
+    }
+
+    /// Get the outer HTML of the element.
+    pub fn outer_html(&self) -> String {
+        TestGeneralElement::outer_html(&self.node.borrow())
+    }
+
+    /// Get the text snapshot of the element.
+    pub fn snapshot(&self) -> String {
+        let mut ret = String::new();
+        write_snapshot(&self.node.borrow(), &mut ret, 0);
+        ret
+    }
+}
+
+fn collect_text(n: &ForestNode<TestGeneralElement>, ret: &mut String, deep: bool) {
+    let mut cur = n.first_child();
+    while let Some(c) = &cur {
+        match &**c {
+            TestGeneralElement::Text(_) => ret.push_str(c.as_text().unwrap()),
+            TestGeneralElement::Virtual(_) => collect_text(c, ret, deep),
+            TestGeneralElement::Element(_) => {
+                if deep {
+                    collect_text(c, ret, deep);
+                }
+            }
+        }
+        cur = c.next_sibling();
+    }
+}
+
+fn collect_elements(
+    n: &ForestNode<TestGeneralElement>,
+    ret: &mut Vec<ElementRef>,
+    f: &impl Fn(&ForestNode<TestGeneralElement>) -> bool,
+) {
+    let mut cur = n.first_child();
+    while let Some(c) = &cur {
+        if c.as_element().is_some() && f(c) {
+            ret.push(ElementRef { node: c.rc() });
+        }
+        collect_elements(c, ret, f);
+        cur = c.next_sibling();
+    }
+}
+
+fn write_snapshot(n: &ForestNode<TestGeneralElement>, ret: &mut String, indent: usize) {
+    match &**n {
+        TestGeneralElement::Text(_) => {
+            ret.push_str(&"  ".repeat(indent));
+            ret.push_str(&format!("{:?}\n", n.as_text().unwrap()));
+        }
+        TestGeneralElement::Virtual(_) => {
+            let mut cur = n.first_child();
+            while let Some(c) = &cur {
+                write_snapshot(c, ret, indent);
+                cur = c.next_sibling();
+            }
+        }
+        TestGeneralElement::Element(x) => {
+            ret.push_str(&"  ".repeat(indent));
+            let start_tag = format!("{:?}", x);
+            if n.first_child().is_none() {
+                ret.push_str(&start_tag[..start_tag.len() - 1]);
+                ret.push_str(" />\n");
+            } else {
+                ret.push_str(&start_tag);
+                ret.push('\n');
+                let mut cur = n.first_child();
+                while let Some(c) = &cur {
+                    write_snapshot(c, ret, indent + 1);
+                    cur = c.next_sibling();
+                }
+                ret.push_str(&"  ".repeat(indent));
+                ret.push_str(&format!("</{}>\n", x.tag_name()));
+            }
+        }
+    }
+}
+
+fn normalize_snapshot(s: &str) -> String {
+    let lines: Vec<&str> = s
+        .lines()
+        .skip_while(|x| x.trim().is_empty())
+        .collect();
+    let end = lines
+        .iter()
+        .rposition(|x| !x.trim().is_empty())
+        .map(|x| x + 1)
+        .unwrap_or(0);
+    let lines = &lines[..end];
+    let common_indent = lines
+        .iter()
+        .filter(|x| !x.trim().is_empty())
+        .map(|x| x.len() - x.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let mut ret = String::new();
+    for line in lines {
+        ret.push_str(line.get(common_indent..).unwrap_or("").trim_end());
+        ret.push('\n');
+    }
+    ret
+}
+
+/// A harness that mounts a component in a new `TestBackend` .
+///
+/// The mount point is detached when the harness is dropped.
+pub struct TestHarness<C: Component + ComponentTemplate<TestBackend> + 'static> {
+    backend_context: BackendContext<TestBackend>,
+    mount_point: MountPoint<TestBackend, C>,
+}
+
+impl<C: Component + ComponentTemplate<TestBackend> + 'static> Drop for TestHarness<C> {
+    fn drop(&mut self) {
+        let mount_point = &mut self.mount_point;
+        let _ = self
+            .backend_context
+            .enter_sync(move |ctx| ctx.detach(mount_point));
+    }
+}
+
+impl<C: Component + ComponentTemplate<TestBackend> + 'static> TestHarness<C> {
+    /// Mount the component.
+    pub fn mount() -> Self {
+        Self::mount_with(|_| {})
+    }
+
+    /// Mount the component with some updates before the component `created` lifetime.
+    pub fn mount_with(init: impl FnOnce(&mut C)) -> Self {
+        let backend_context = BackendContext::new(TestBackend::new());
+        let mount_point = backend_context
+            .enter_sync(move |ctx| ctx.attach(init))
+            .map_err(|_| "Cannot init mount point")
+            .unwrap()
+            .unwrap();
+        let this = Self {
+            backend_context,
+            mount_point,
+        };
+        this.run_until_stalled();
+        this
+    }
+
+    /// Get the backend context.
+    pub fn backend_context(&self) -> &BackendContext<TestBackend> {
+        &self.backend_context
+    }
+
+    /// Get the `ComponentRc` of the mounted component.
+    pub fn component(&self) -> ComponentRc<C> {
+        self.mount_point.root_component().rc()
+    }
+
+    /// Drive all the pending async tasks until the backend context event queue is drained.
+    pub fn run_until_stalled(&self) {
+        run_until_stalled();
+    }
+
+    /// Update the mounted component and wait until the update is applied.
+    pub fn update<R: 'static>(&self, f: impl 'static + FnOnce(&mut C) -> R) -> Result<R, Error> {
+        let rc = self.component();
+        block_on(async move { rc.update(f).await })
+    }
+
+    /// Read the mounted component.
+    pub fn get<R: 'static>(&self, f: impl 'static + FnOnce(&C) -> R) -> R {
+        let rc = self.component();
+        block_on(async move { rc.get(f).await })
+    }
+
+    fn root_element(&self) -> ElementRef {
+        let node = self
+            .backend_context
+            .enter_sync(|ctx| ctx.root().rc())
+            .map_err(|_| "Cannot visit backend")
+            .unwrap();
+        ElementRef { node }
+    }
+
+    /// Find all elements matching the `f` in tree order.
+    pub fn find_all(&self, f: impl Fn(&ElementRef) -> bool) -> Vec<ElementRef> {
+        let root = self.root_element();
+        let mut ret = vec![];
+        collect_elements(&root.node.borrow(), &mut ret, &|_| true);
+        ret.retain(|x| f(x));
+        ret
+    }
+
+    /// Find all elements with the tag name.
+    pub fn find_all_by_tag(&self, tag_name: &str) -> Vec<ElementRef> {
+        self.find_all(|x| x.tag_name() == tag_name)
+    }
+
+    /// Find the first element with the tag name.
+    pub fn find_by_tag(&self, tag_name: &str) -> Option<ElementRef> {
+        self.find_all_by_tag(tag_name).into_iter().next()
+    }
+
+    /// Find all elements containing the class name.
+    pub fn find_all_by_class(&self, class_name: &str) -> Vec<ElementRef> {
+        self.find_all(|x| x.has_class(class_name))
+    }
+
+    /// Find the first element containing the class name.
+    pub fn find_by_class(&self, class_name: &str) -> Option<ElementRef> {
+        self.find_all_by_class(class_name).into_iter().next()
+    }
+
+    /// Find all elements whose own text equals to `text` .
+    ///
+    /// Only the text nodes directly in the element (not in child elements) are considered,
+    /// and the leading and trailing whitespaces are trimmed.
+    pub fn find_all_by_text(&self, text: &str) -> Vec<ElementRef> {
+        self.find_all(|x| {
+            let mut s = String::new();
+            collect_text(&x.node.borrow(), &mut s, false);
+            s.trim() == text
+        })
+    }
+
+    /// Find the first element whose own text equals to `text` .
+    pub fn find_by_text(&self, text: &str) -> Option<ElementRef> {
+        self.find_all_by_text(text).into_iter().next()
+    }
+
+    /// Trigger an event of an element and drive the async tasks.
+    ///
+    /// Returns `false` if there is no handler binded to the event.
+    /// Panics if the event detail type does not match.
+    pub fn trigger<D: 'static>(&self, elem: &ElementRef, event_name: &str, detail: &mut D) -> bool {
+        let handler = self
+            .backend_context
+            .enter_sync(|_| elem.with(|x| x.event_handler::<D>(event_name)))
+            .map_err(|_| "Cannot visit backend")
+            .unwrap();
+        let ret = match handler {
+            Some(f) => {
+                f(detail);
+                true
+            }
+            None => false,
+        };
+        self.run_until_stalled();
+        ret
+    }
+
+    /// Get the inner HTML of the backend.
+    pub fn html(&self) -> String {
+        self.backend_context
+            .enter_sync(|ctx| ctx.inner_html())
+            .map_err(|_| "Cannot visit backend")
+            .unwrap()
+    }
+
+    /// Get a stable text snapshot of the rendered tree.
+    ///
+    /// Each element or text node is placed in a separated line, indented by its depth.
+    /// Virtual elements are transparent and text nodes are quoted.
+    pub fn snapshot(&self) -> String {
+        let root = self.root_element();
+        let mut ret = String::new();
+        let n = root.node.borrow();
+        let mut cur = n.first_child();
+        while let Some(c) = &cur {
+            write_snapshot(c, &mut ret, 0);
+            cur = c.next_sibling();
+        }
+        ret
+    }
+
+    /// Assert the text snapshot is the same as `expected` .
+    ///
+    /// The common indentation and the surrounding empty lines of `expected` are ignored,
+    /// so it can be written as an indented raw string literal.
+    #[track_caller]
+    pub fn assert_snapshot(&self, expected: &str) {
+        assert_eq!(normalize_snapshot(&self.snapshot()), normalize_snapshot(expected));
+    }
+}
@@ -0,0 +1,34 @@
+use maomi::backend::*;
+
+#[doc(hidden)]
+pub struct TestTextNode {
+    content: String,
+}
+
+impl TestTextNode {
+    pub(crate) fn new(content: &str) -> Self {
+        Self {
+            content: content.to_string(),
+        }
+    }
+
+    pub(crate) fn text_content(&self) -> &str {
+        &self.content
+    }
+
+    pub(crate) fn write_html(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        html_escape::encode_text_minimal_to_writer(&self.content, w)?;
+        Ok(())
+    }
+}
+
+impl BackendTextNode for TestTextNode {
+    type BaseBackend = crate::TestBackend;
+
+    #[inline]
+    fn set_text(&mut self, content: &str) {
+        if self.content.as_str() != content {
+            self.content = content.to_string();
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use maomi::backend::*;
+
+#[doc(hidden)]
+pub struct TestVirtualElement {}
+
+impl TestVirtualElement {
+    pub(crate) fn new() -> Self {
+        Self {}
+    }
+}
+
+impl BackendVirtualElement for TestVirtualElement {
+    type BaseBackend = crate::TestBackend;
+}
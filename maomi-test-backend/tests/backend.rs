@@ -0,0 +1,959 @@
+use maomi::{backend::BackendGeneralElement, prelude::*, template::ComponentTemplate, BackendContext};
+use maomi_test_backend::{element::*, prelude::*, run_until_stalled, TestGeneralElement};
+
+fn inner_html(backend_context: &BackendContext<TestBackend>) -> String {
+    backend_context
+        .enter_sync(|ctx| ctx.inner_html())
+        .map_err(|_| "Cannot visit backend")
+        .unwrap()
+}
+
+fn mount<C: Component + ComponentTemplate<TestBackend>>(
+) -> (BackendContext<TestBackend>, ComponentRc<C>) {
+    let backend_context = BackendContext::new(TestBackend::new());
+    let mount_point = backend_context
+        .enter_sync(|ctx| ctx.attach(|_: &mut C| {}).unwrap())
+        .map_err(|_| "Cannot init mount point")
+        .unwrap();
+    let rc = mount_point.root_component().rc();
+    std::mem::forget(mount_point);
+    (backend_context, rc)
+}
+
+#[test]
+fn static_and_dynamic_attributes() {
+    #[component(Backend = TestBackend)]
+    struct Parent {
+        template: template! {
+            <div id="a" class={ &self.class_name } hidden={ &self.hidden }>
+                <a href="/x?a=1&b=2"> "<link>" </a>
+                <input value={ &self.class_name } disabled />
+            </div>
+        },
+        class_name: String,
+        hidden: bool,
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                class_name: "c1".into(),
+                hidden: false,
+            }
+        }
+    }
+
+    let (backend_context, comp) = mount::<Parent>();
+    assert_eq!(
+        inner_html(&backend_context),
+        r#"<div id="a" class="c1"><a href="/x?a=1&amp;b=2">&lt;link></a><input value="c1" disabled=""></input></div>"#,
+    );
+    maomi_test_backend::async_task(async move {
+        comp.update(|this| {
+            this.class_name = "c2 \"q\"".into();
+            this.hidden = true;
+        })
+        .await
+        .unwrap();
+    });
+    run_until_stalled();
+    assert_eq!(
+        inner_html(&backend_context),
+        r#"<div id="a" class="c2 &quot;q&quot;" hidden=""><a href="/x?a=1&amp;b=2">&lt;link></a><input value="c2 &quot;q&quot;" disabled=""></input></div>"#,
+    );
+}
+
+#[test]
+fn control_flow_and_child_components() {
+    #[component(Backend = TestBackend)]
+    struct Child {
+        template: template! {
+            <li> { &self.label } <slot /> </li>
+        },
+        label: Prop<String>,
+    }
+
+    impl Component for Child {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                label: Prop::new(String::new()),
+            }
+        }
+    }
+
+    #[component(Backend = TestBackend)]
+    struct Parent {
+        template: template! {
+            if self.list.is_empty() {
+                <p> "(empty)" </p>
+            } else {
+                <ul>
+                    for item in self.list.iter() {
+                        <Child label={ item }> <span> "!" </span> </Child>
+                    }
+                </ul>
+            }
+        },
+        list: Vec<String>,
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                list: vec![],
+            }
+        }
+    }
+
+    let (backend_context, comp) = mount::<Parent>();
+    assert_eq!(inner_html(&backend_context), r#"<p>(empty)</p>"#);
+    let comp2 = comp.clone();
+    maomi_test_backend::async_task(async move {
+        comp2
+            .update(|this| this.list = vec!["a".into(), "b".into()])
+            .await
+            .unwrap();
+    });
+    run_until_stalled();
+    assert_eq!(
+        inner_html(&backend_context),
+        r#"<ul><li>a<span>!</span></li><li>b<span>!</span></li></ul>"#,
+    );
+    maomi_test_backend::async_task(async move {
+        comp.update(|this| this.list = vec!["b".into()]).await.unwrap();
+    });
+    run_until_stalled();
+    assert_eq!(
+        inner_html(&backend_context),
+        r#"<ul><li>b<span>!</span></li></ul>"#,
+    );
+}
+
+#[test]
+fn event_handlers() {
+    #[component(Backend = TestBackend)]
+    struct Parent {
+        template: template! {
+            <button click=@clicked()> { self.count.to_string() } </button>
+        },
+        count: usize,
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                count: 0,
+            }
+        }
+    }
+
+    impl Parent {
+        fn clicked(this: ComponentRc<Self>, _: &mut ()) {
+            maomi_test_backend::async_task(async move {
+                this.update(|this| this.count += 1).await.unwrap();
+            });
+        }
+    }
+
+    let (backend_context, _comp) = mount::<Parent>();
+    let handler = backend_context
+        .enter_sync(|ctx| {
+            let root = ctx.root();
+            let button = root.first_child().unwrap();
+            let button = button.first_child().unwrap();
+            match &*button {
+                TestGeneralElement::Element(x) => {
+                    assert_eq!(x.tag_name(), "button");
+                    x.event_handler::<()>("click").unwrap()
+                }
+                _ => unreachable!(),
+            }
+        })
+        .map_err(|_| "Cannot visit backend")
+        .unwrap();
+    handler(&mut ());
+    handler(&mut ());
+    run_until_stalled();
+    assert_eq!(inner_html(&backend_context), r#"<button>2</button>"#);
+}
+
+#[test]
+fn attach_at_other_elements() {
+    #[component(Backend = TestBackend)]
+    struct Island {
+        template: template! {
+            <span> { &self.text } </span>
+        },
+        text: String,
+    }
+
+    impl Component for Island {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                text: String::new(),
+            }
+        }
+    }
+
+    let backend_context = BackendContext::new(TestBackend::new());
+    let (container_a, container_b, mut mount_point_a, mut mount_point_b) = backend_context
+        .enter_sync(|ctx| {
+            let (container_a, container_b) = {
+                let mut root = ctx.root_mut();
+                (
+                    TestGeneralElement::create_virtual_element(&mut root).unwrap(),
+                    TestGeneralElement::create_virtual_element(&mut root).unwrap(),
+                )
+            };
+            let mount_point_a = ctx
+                .attach_at(container_a.clone(), |this: &mut Island| this.text = "a".into())
+                .unwrap();
+            let mount_point_b = ctx
+                .attach_at(container_b.clone(), |this: &mut Island| this.text = "b".into())
+                .unwrap()
+                .into_dyn();
+            (container_a, container_b, mount_point_a, mount_point_b)
+        })
+        .map_err(|_| "Cannot init mount point")
+        .unwrap();
+    let island_html = |container: &maomi::backend::tree::ForestNodeRc<TestGeneralElement>| {
+        backend_context
+            .enter_sync(|ctx| TestGeneralElement::inner_html(&ctx.root().borrow(container)))
+            .map_err(|_| "Cannot visit backend")
+            .unwrap()
+    };
+    assert_eq!(inner_html(&backend_context), "");
+    assert_eq!(island_html(&container_a), "<span>a</span>");
+    assert_eq!(island_html(&container_b), "<span>b</span>");
+
+    let comp = mount_point_a.root_component().rc();
+    maomi_test_backend::async_task(async move {
+        comp.update(|this| this.text = "c".into()).await.unwrap();
+    });
+    run_until_stalled();
+    assert_eq!(island_html(&container_a), "<span>c</span>");
+    assert_eq!(island_html(&container_b), "<span>b</span>");
+
+    backend_context
+        .enter_sync(|ctx| {
+            ctx.detach(&mut mount_point_a);
+            ctx.detach_dyn(&mut mount_point_b);
+        })
+        .map_err(|_| "Cannot detach mount point")
+        .unwrap();
+    assert_eq!(island_html(&container_a), "");
+    assert_eq!(island_html(&container_b), "");
+
+    // the elements of other backends cannot be used
+    let other_backend_context = BackendContext::new(TestBackend::new());
+    let other_container = other_backend_context
+        .enter_sync(|ctx| TestGeneralElement::create_virtual_element(&mut ctx.root_mut()).unwrap())
+        .map_err(|_| "Cannot visit backend")
+        .unwrap();
+    backend_context
+        .enter_sync(|ctx| {
+            let ret = ctx.attach_at(other_container, |_: &mut Island| {});
+            assert!(matches!(ret, Err(maomi::error::Error::TreeNodeTypeWrong)));
+        })
+        .map_err(|_| "Cannot visit backend")
+        .unwrap();
+}
+
+#[test]
+fn before_destroy() {
+    thread_local! {
+        static DESTROYED: std::cell::RefCell<Vec<String>> = Default::default();
+    }
+
+    #[component(Backend = TestBackend)]
+    struct Child {
+        template: template! {
+            <span> { &self.label } </span>
+            if *self.label == "a" {
+                <GrandChild />
+            }
+        },
+        label: Prop<String>,
+    }
+
+    impl Component for Child {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                label: Prop::new(String::new()),
+            }
+        }
+
+        fn before_destroy(&mut self) {
+            DESTROYED.with(|x| x.borrow_mut().push((*self.label).clone()));
+        }
+    }
+
+    #[component(Backend = TestBackend)]
+    struct GrandChild {
+        template: template! {},
+    }
+
+    impl Component for GrandChild {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+            }
+        }
+
+        fn before_destroy(&mut self) {
+            DESTROYED.with(|x| x.borrow_mut().push("grand child".into()));
+        }
+    }
+
+    #[component(Backend = TestBackend)]
+    struct Parent {
+        template: template! {
+            for item in self.list.iter() {
+                <Child label={ item } />
+            }
+            if self.show {
+                <Child label="if" />
+            }
+        },
+        list: Vec<String>,
+        show: bool,
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                list: vec!["a".into(), "b".into()],
+                show: true,
+            }
+        }
+
+        fn before_destroy(&mut self) {
+            DESTROYED.with(|x| x.borrow_mut().push("parent".into()));
+        }
+    }
+
+    let take_destroyed = || DESTROYED.with(|x| std::mem::take(&mut *x.borrow_mut()));
+    let backend_context = BackendContext::new(TestBackend::new());
+    let mut mount_point = backend_context
+        .enter_sync(|ctx| ctx.attach(|_: &mut Parent| {}).unwrap())
+        .map_err(|_| "Cannot init mount point")
+        .unwrap();
+    let comp = mount_point.root_component().rc();
+    // a clone of the node does not keep the component from destroying on detach
+    let node = mount_point.root_component().clone();
+    assert_eq!(
+        inner_html(&backend_context),
+        r#"<span>a</span><span>b</span><span>if</span>"#,
+    );
+    assert_eq!(take_destroyed(), Vec::<String>::new());
+
+    maomi_test_backend::async_task({
+        let comp = comp.clone();
+        async move {
+            comp.update(|this| this.show = false).await.unwrap();
+        }
+    });
+    run_until_stalled();
+    assert_eq!(inner_html(&backend_context), r#"<span>a</span><span>b</span>"#);
+    assert_eq!(take_destroyed(), ["if"]);
+
+    maomi_test_backend::async_task({
+        let comp = comp.clone();
+        async move {
+            comp.update(|this| this.list.remove(0)).await.unwrap();
+        }
+    });
+    run_until_stalled();
+    assert_eq!(inner_html(&backend_context), r#"<span>b</span>"#);
+    // the list is not keyed, so the first item is updated and the last one is removed
+    assert_eq!(take_destroyed(), ["grand child", "b"]);
+
+    backend_context
+        .enter_sync(|ctx| ctx.detach(&mut mount_point))
+        .map_err(|_| "Cannot detach mount point")
+        .unwrap();
+    assert_eq!(inner_html(&backend_context), "");
+    assert_eq!(take_destroyed(), ["parent", "b"]);
+
+    // updates after destroyed are ignored
+    maomi_test_backend::async_task(async move {
+        comp.update(|this| this.show = true).await.unwrap();
+    });
+    run_until_stalled();
+    assert_eq!(inner_html(&backend_context), "");
+    drop(mount_point);
+    drop(node);
+    assert_eq!(take_destroyed(), Vec::<String>::new());
+}
+
+#[test]
+fn after_template_apply() {
+    thread_local! {
+        static LOG: std::cell::RefCell<Vec<String>> = Default::default();
+        static CHILD: std::cell::RefCell<Option<ComponentRc<Child>>> = Default::default();
+    }
+
+    #[component(Backend = TestBackend)]
+    struct Child {
+        template: template! {
+            <span> { &self.label } { &self.suffix } </span>
+        },
+        label: Prop<String>,
+        suffix: String,
+    }
+
+    impl Component for Child {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                label: Prop::new(String::new()),
+                suffix: String::new(),
+            }
+        }
+
+        fn created(&self) {
+            CHILD.with(|x| *x.borrow_mut() = Some(self.rc()));
+            LOG.with(|x| x.borrow_mut().push("created".into()));
+        }
+
+        fn before_template_apply(&mut self) {
+            LOG.with(|x| x.borrow_mut().push(format!("before {}{}", *self.label, self.suffix)));
+        }
+
+        fn after_template_apply(&mut self) {
+            assert!(self.template_structure().is_some());
+            LOG.with(|x| x.borrow_mut().push(format!("after {}{}", *self.label, self.suffix)));
+        }
+    }
+
+    #[component(Backend = TestBackend)]
+    struct Parent {
+        template: template! {
+            <Child label={ &self.label } />
+        },
+        label: String,
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                label: "a".into(),
+            }
+        }
+    }
+
+    let take_log = || LOG.with(|x| std::mem::take(&mut *x.borrow_mut()));
+    let (backend_context, comp) = mount::<Parent>();
+    assert_eq!(take_log(), ["before a", "after a", "created"]);
+
+    // updated by the parent
+    maomi_test_backend::async_task(async move {
+        comp.update(|this| this.label = "b".into()).await.unwrap();
+    });
+    run_until_stalled();
+    assert_eq!(inner_html(&backend_context), r#"<span>b</span>"#);
+    assert_eq!(take_log(), ["before b", "after b"]);
+
+    // updated by itself
+    let child = CHILD.with(|x| x.borrow_mut().take()).unwrap();
+    maomi_test_backend::async_task(async move {
+        child.update(|this| this.suffix = "!".into()).await.unwrap();
+    });
+    run_until_stalled();
+    assert_eq!(inner_html(&backend_context), r#"<span>b!</span>"#);
+    assert_eq!(take_log(), ["before b!", "after b!"]);
+}
+
+mod failing {
+    use maomi::{
+        backend::{tree::*, BackendComponent, BackendGeneralElement, SupportBackend},
+        error::Error,
+        node::{NoneSlot, OwnerWeak, SlotChange},
+        prop::PropertyUpdate,
+        BackendContext,
+    };
+    use maomi_test_backend::{TestBackend, TestGeneralElement};
+
+    pub struct FailFlag(bool);
+
+    impl PropertyUpdate<bool> for FailFlag {
+        type UpdateContext = ();
+
+        fn compare_and_set_ref(dest: &mut Self, src: &bool, _ctx: &mut ()) {
+            dest.0 = *src;
+        }
+    }
+
+    /// A node that fails to create or update when `fail` is set.
+    #[allow(non_camel_case_types)]
+    pub struct failing {
+        pub fail: FailFlag,
+    }
+
+    impl failing {
+        fn check(&self) -> Result<(), Error> {
+            if self.fail.0 {
+                Err(Error::Custom("failed".into()))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    impl BackendComponent<TestBackend> for failing {
+        type SlotData = ();
+        type UpdateTarget = Self;
+        type UpdateContext = ();
+
+        fn init<'b>(
+            _backend_context: &'b BackendContext<TestBackend>,
+            owner: &'b mut ForestNodeMut<TestGeneralElement>,
+            _owner_weak: &'b Box<dyn OwnerWeak>,
+        ) -> Result<(Self, ForestNodeRc<TestGeneralElement>), Error> {
+            let backend_element = TestGeneralElement::create_virtual_element(owner)?;
+            Ok((Self { fail: FailFlag(false) }, backend_element))
+        }
+
+        fn create<'b>(
+            &'b mut self,
+            _backend_context: &'b BackendContext<TestBackend>,
+            _owner: &'b mut ForestNodeMut<TestGeneralElement>,
+            update_fn: Box<dyn 'b + FnOnce(&mut Self, &mut Self::UpdateContext)>,
+            _slot_fn: &mut dyn FnMut(
+                &mut ForestNodeMut<TestGeneralElement>,
+                &ForestToken,
+                &Self::SlotData,
+            ) -> Result<(), Error>,
+        ) -> Result<(), Error> {
+            update_fn(self, &mut ());
+            self.check()
+        }
+
+        fn apply_updates<'b>(
+            &'b mut self,
+            _backend_context: &'b BackendContext<TestBackend>,
+            _owner: &'b mut ForestNodeMut<TestGeneralElement>,
+            update_fn: Box<dyn 'b + FnOnce(&mut Self, &mut Self::UpdateContext)>,
+            _slot_fn: &mut dyn FnMut(
+                SlotChange<&mut ForestNodeMut<TestGeneralElement>, &ForestToken, &Self::SlotData>,
+            ) -> Result<(), Error>,
+        ) -> Result<(), Error> {
+            update_fn(self, &mut ());
+            self.check()
+        }
+    }
+
+    impl SupportBackend for failing {
+        type Target = Self;
+        type SlotChildren = NoneSlot<ForestTokenAddr, maomi::node::DynNodeList>;
+    }
+}
+
+#[test]
+fn error_captured() {
+    use failing::failing;
+
+    thread_local! {
+        static CHILD: std::cell::RefCell<Option<ComponentRc<Child>>> = Default::default();
+    }
+
+    #[component(Backend = TestBackend)]
+    struct Child {
+        template: template! {
+            <span> "child" </span>
+            <failing fail={ &self.fail } />
+        },
+        fail: bool,
+    }
+
+    impl Component for Child {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                fail: false,
+            }
+        }
+
+        fn created(&self) {
+            CHILD.with(|x| *x.borrow_mut() = Some(self.rc()));
+        }
+    }
+
+    #[component(Backend = TestBackend)]
+    struct Parent {
+        template: template! {
+            if !self.error.is_empty() {
+                <div> { &self.error } </div>
+            } else {
+                <failing fail={ &self.fail } />
+                <Child />
+            }
+        },
+        fail: bool,
+        error: String,
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                fail: false,
+                error: String::new(),
+            }
+        }
+
+        fn error_captured(&mut self, err: maomi::error::Error) -> Result<(), maomi::error::Error> {
+            self.error = err.to_string();
+            Ok(())
+        }
+    }
+
+    let mount_with = |fail: bool| {
+        let backend_context = BackendContext::new(TestBackend::new());
+        let mount_point = backend_context
+            .enter_sync(|ctx| ctx.attach(|this: &mut Parent| this.fail = fail).unwrap())
+            .map_err(|_| "Cannot init mount point")
+            .unwrap();
+        let rc = mount_point.root_component().rc();
+        std::mem::forget(mount_point);
+        (backend_context, rc)
+    };
+
+    // failed when creating
+    let (backend_context, _) = mount_with(true);
+    assert_eq!(inner_html(&backend_context), r#"<div>failed</div>"#);
+
+    // failed when updating
+    let (backend_context, comp) = mount_with(false);
+    assert_eq!(inner_html(&backend_context), r#"<span>child</span>"#);
+    maomi_test_backend::async_task(async move {
+        comp.update(|this| this.fail = true).await.unwrap();
+    });
+    run_until_stalled();
+    assert_eq!(inner_html(&backend_context), r#"<div>failed</div>"#);
+
+    // failed when a descendant updating itself
+    let (backend_context, _) = mount_with(false);
+    let child = CHILD.with(|x| x.borrow_mut().take()).unwrap();
+    maomi_test_backend::async_task(async move {
+        child.update(|this| this.fail = true).await.unwrap();
+    });
+    run_until_stalled();
+    assert_eq!(inner_html(&backend_context), r#"<div>failed</div>"#);
+
+    // recreated after recovered
+    #[component(Backend = TestBackend)]
+    struct Recovering {
+        template: template! {
+            <Child />
+            <failing fail={ &self.fail } />
+        },
+        fail: bool,
+    }
+
+    impl Component for Recovering {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                fail: false,
+            }
+        }
+
+        fn error_captured(&mut self, _err: maomi::error::Error) -> Result<(), maomi::error::Error> {
+            self.fail = false;
+            Ok(())
+        }
+    }
+
+    let (backend_context, comp) = mount::<Recovering>();
+    assert!(CHILD.with(|x| x.borrow_mut().take()).is_some());
+    maomi_test_backend::async_task(async move {
+        comp.update(|this| this.fail = true).await.unwrap();
+    });
+    run_until_stalled();
+    assert_eq!(inner_html(&backend_context), r#"<span>child</span>"#);
+    assert!(CHILD.with(|x| x.borrow_mut().take()).is_some());
+
+    // not captured
+    let (backend_context, _) = mount::<Child>();
+    let child = CHILD.with(|x| x.borrow_mut().take()).unwrap();
+    maomi_test_backend::async_task(async move {
+        assert!(child.update(|this| this.fail = true).await.is_err());
+    });
+    run_until_stalled();
+    assert_eq!(inner_html(&backend_context), r#"<span>child</span>"#);
+}
+
+#[test]
+fn error_captured_with_slot() {
+    use failing::failing;
+
+    #[component(Backend = TestBackend)]
+    struct Panel {
+        template: template! {
+            <div> <slot /> </div>
+            if self.error.is_empty() {
+                <failing fail={ &*self.fail } />
+            } else {
+                <p> { &self.error } </p>
+            }
+        },
+        fail: Prop<bool>,
+        error: String,
+    }
+
+    impl Component for Panel {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                fail: Prop::new(false),
+                error: String::new(),
+            }
+        }
+
+        fn error_captured(&mut self, err: maomi::error::Error) -> Result<(), maomi::error::Error> {
+            self.error = err.to_string();
+            Ok(())
+        }
+    }
+
+    #[component(Backend = TestBackend)]
+    struct Parent {
+        template: template! {
+            if self.error.is_empty() {
+                <Panel fail={ &self.fail }> <span> { &self.text } </span> </Panel>
+            } else {
+                <p> { &self.error } </p>
+            }
+        },
+        fail: bool,
+        text: String,
+        error: String,
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                fail: false,
+                text: "a".into(),
+                error: String::new(),
+            }
+        }
+
+        fn error_captured(&mut self, err: maomi::error::Error) -> Result<(), maomi::error::Error> {
+            self.error = format!("parent {}", err);
+            Ok(())
+        }
+    }
+
+    let mount_with = |fail: bool| {
+        let backend_context = BackendContext::new(TestBackend::new());
+        let mount_point = backend_context
+            .enter_sync(|ctx| ctx.attach(|this: &mut Parent| this.fail = fail).unwrap())
+            .map_err(|_| "Cannot init mount point")
+            .unwrap();
+        let rc = mount_point.root_component().rc();
+        std::mem::forget(mount_point);
+        (backend_context, rc)
+    };
+
+    // failed when creating after the slot content passed to the owner
+    let (backend_context, _) = mount_with(true);
+    assert_eq!(inner_html(&backend_context), r#"<p>parent failed</p>"#);
+
+    // failed when updating, and the error is passed to the slot content owner
+    let (backend_context, comp) = mount_with(false);
+    assert_eq!(inner_html(&backend_context), r#"<div><span>a</span></div>"#);
+    maomi_test_backend::async_task(async move {
+        comp.update(|this| this.fail = true).await.unwrap();
+    });
+    run_until_stalled();
+    assert_eq!(inner_html(&backend_context), r#"<p>parent failed</p>"#);
+}
+
+#[test]
+fn resource() {
+    use maomi::{
+        resource::{Resource, ResourceState},
+        AsyncCallback,
+    };
+
+    type LoadResult = Result<String, String>;
+
+    #[component(Backend = TestBackend)]
+    struct Parent {
+        template: template! {
+            match self.text.state() {
+                ResourceState::Pending => { <span> "loading" </span> }
+                ResourceState::Ready(x) => { <span> { x } </span> }
+                ResourceState::Failed(x) => { <div> { x } </div> }
+            }
+        },
+        text: Resource<String, String>,
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                text: Resource::new(),
+            }
+        }
+    }
+
+    let load = |comp: &ComponentRc<Parent>| {
+        let (fut, cb) = AsyncCallback::<LoadResult>::new();
+        comp.load(|this| &mut this.text, fut);
+        cb
+    };
+
+    let (backend_context, comp) = mount::<Parent>();
+    let cb = load(&comp);
+    run_until_stalled();
+    assert_eq!(inner_html(&backend_context), r#"<span>loading</span>"#);
+    cb(Ok("a".into()));
+    run_until_stalled();
+    assert_eq!(inner_html(&backend_context), r#"<span>a</span>"#);
+
+    // the result of the previous loading is ignored
+    let cb1 = load(&comp);
+    let cb2 = load(&comp);
+    run_until_stalled();
+    assert_eq!(inner_html(&backend_context), r#"<span>loading</span>"#);
+    cb1(Ok("b".into()));
+    run_until_stalled();
+    assert_eq!(inner_html(&backend_context), r#"<span>loading</span>"#);
+    cb2(Err("failed".into()));
+    run_until_stalled();
+    assert_eq!(inner_html(&backend_context), r#"<div>failed</div>"#);
+}
+
+#[test]
+fn context() {
+    use maomi::context::Context;
+
+    #[component(Backend = TestBackend)]
+    struct Consumer {
+        template: template! {
+            <span> { self.text.with(|x| x.clone()) } </span>
+        },
+        text: Context<String>,
+        missing: Option<Context<u32>>,
+    }
+
+    impl Component for Consumer {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                text: Context::consume().unwrap(),
+                missing: Context::consume(),
+            }
+        }
+
+        fn created(&self) {
+            assert!(self.missing.is_none());
+        }
+    }
+
+    #[component(Backend = TestBackend)]
+    struct LateConsumer {
+        template: template! {
+            <a> { self.text.as_ref().map(|x| x.with(|x| x.clone())).unwrap_or_default() } </a>
+        },
+        text: Option<Context<String>>,
+    }
+
+    impl Component for LateConsumer {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                text: None,
+            }
+        }
+
+        fn created(&self) {
+            let rc = self.rc();
+            let text = rc.context::<String>();
+            rc.task(move |this| this.text = text);
+        }
+    }
+
+    #[component(Backend = TestBackend)]
+    struct Override {
+        template: template! {
+            <Consumer />
+        },
+        _text: Context<String>,
+    }
+
+    impl Component for Override {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                _text: Context::provide("inner".into()),
+            }
+        }
+    }
+
+    #[component(Backend = TestBackend)]
+    struct Parent {
+        template: template! {
+            <div> <Consumer /> <LateConsumer /> <Override /> </div>
+            if self.show {
+                <p> <Consumer /> </p>
+            }
+        },
+        text: Context<String>,
+        show: bool,
+    }
+
+    impl Component for Parent {
+        fn new() -> Self {
+            Self {
+                template: Default::default(),
+                text: Context::provide("a".into()),
+                show: false,
+            }
+        }
+    }
+
+    let (backend_context, comp) = mount::<Parent>();
+    run_until_stalled();
+    assert_eq!(
+        inner_html(&backend_context),
+        r#"<div><span>a</span><a>a</a><span>inner</span></div>"#,
+    );
+
+    // the consumers are updated when the context changed
+    let comp2 = comp.clone();
+    maomi_test_backend::async_task(async move {
+        comp2.get(|this| this.text.set("b".into())).await;
+    });
+    run_until_stalled();
+    assert_eq!(
+        inner_html(&backend_context),
+        r#"<div><span>b</span><a>b</a><span>inner</span></div>"#,
+    );
+
+    // the consumers that are created again are also updated
+    maomi_test_backend::async_task(async move {
+        comp.update(|this| this.show = true).await.unwrap();
+        comp.update(|this| this.show = false).await.unwrap();
+        comp.update(|this| this.show = true).await.unwrap();
+        comp.get(|this| this.text.set("c".into())).await;
+    });
+    run_until_stalled();
+    assert_eq!(
+        inner_html(&backend_context),
+        r#"<div><span>c</span><a>c</a><span>inner</span></div><p><span>c</span></p>"#,
+    );
+}
@@ -0,0 +1,114 @@
+use maomi::prelude::*;
+use maomi_test_backend::{element::*, event::ChangeEvent, prelude::*};
+
+#[component(Backend = TestBackend)]
+struct Item {
+    template: template! {
+        <li class={ &*self.class_name }> { &self.label } </li>
+    },
+    label: Prop<String>,
+    class_name: Prop<String>,
+}
+
+impl Component for Item {
+    fn new() -> Self {
+        Self {
+            template: Default::default(),
+            label: Prop::new(String::new()),
+            class_name: Prop::new(String::new()),
+        }
+    }
+}
+
+#[component(Backend = TestBackend)]
+struct Form {
+    template: template! {
+        <input value={ &self.text } change=@changed() />
+        <ul>
+            for (index, item) in self.items.iter().enumerate() {
+                <Item
+                    label={ item }
+                    class_name={ if index == 0 { "item first" } else { "item" } }
+                />
+            }
+        </ul>
+    },
+    text: String,
+    items: Vec<String>,
+}
+
+impl Component for Form {
+    fn new() -> Self {
+        Self {
+            template: Default::default(),
+            text: String::new(),
+            items: vec![],
+        }
+    }
+}
+
+impl Form {
+    fn changed(this: ComponentRc<Self>, detail: &mut ChangeEvent) {
+        let value = detail.value.clone();
+        this.task(move |this| {
+            this.items.push(value.clone());
+            this.text = value;
+        });
+    }
+}
+
+#[test]
+fn mount_and_update() {
+    let harness = TestHarness::<Form>::mount_with(|this| {
+        this.items = vec!["a".into()];
+    });
+    assert_eq!(harness.find_all_by_tag("li").len(), 1);
+    harness
+        .update(|this| this.items.push("b".into()))
+        .unwrap();
+    assert_eq!(harness.get(|this| this.items.len()), 2);
+    assert_eq!(
+        harness.html(),
+        r#"<input></input><ul><li class="item first">a</li><li class="item">b</li></ul>"#,
+    );
+}
+
+#[test]
+fn queries() {
+    let harness = TestHarness::<Form>::mount_with(|this| {
+        this.items = vec!["a".into(), "b".into(), "c".into()];
+    });
+    let items = harness.find_all_by_class("item");
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[2].text_content(), "c");
+    let first = harness.find_by_class("first").unwrap();
+    assert_eq!(first.tag_name(), "li");
+    assert_eq!(first.attribute("class").as_deref(), Some("item first"));
+    assert_eq!(harness.find_by_text("b").unwrap().outer_html(), r#"<li class="item">b</li>"#);
+    assert!(harness.find_by_text("d").is_none());
+    assert_eq!(harness.find_by_tag("ul").unwrap().text_content(), "abc");
+    assert!(harness.find_by_tag("p").is_none());
+}
+
+#[test]
+fn trigger_events() {
+    let harness = TestHarness::<Form>::mount();
+    let input = harness.find_by_tag("input").unwrap();
+    assert!(harness.trigger(&input, "change", &mut ChangeEvent::new("x")));
+    assert!(harness.trigger(&input, "change", &mut ChangeEvent::new("y")));
+    assert!(!harness.trigger(&input, "click", &mut ()));
+    assert_eq!(input.attribute("value").as_deref(), Some("y"));
+    harness.assert_snapshot(
+        r#"
+            <input value="y" />
+            <ul>
+              <li class="item first">
+                "x"
+              </li>
+              <li class="item">
+                "y"
+              </li>
+            </ul>
+        "#,
+    );
+}
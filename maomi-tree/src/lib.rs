@@ -409,14 +409,24 @@ impl<'a, T> ForestNodeMut<'a, T> {
     /// Borrow another node in the same forest.
     #[inline]
     pub fn borrow_mut<'b>(&'b mut self, target: &'b ForestNodeRc<T>) -> ForestNodeMut<'b, T> {
+        self.try_borrow_mut(target)
+            .expect("The target node is not in the same forest")
+    }
+
+    /// Borrow another node, returning `None` if it is not in the same forest.
+    #[inline]
+    pub fn try_borrow_mut<'b>(
+        &'b mut self,
+        target: &'b ForestNodeRc<T>,
+    ) -> Option<ForestNodeMut<'b, T>> {
         unsafe {
             if !Rc::ptr_eq(
                 &{ self.inner.data_ref() }.ctx,
                 &{ &*target.inner.data_ref() }.ctx,
             ) {
-                panic!("The target node is not in the same forest")
+                return None;
             }
-            self.borrow_mut_unchecked(&target.inner)
+            Some(self.borrow_mut_unchecked(&target.inner))
         }
     }
 
@@ -78,6 +78,15 @@ struct BackendContextInner<B: Backend> {
     initial_backend_stage: Cell<BackendStage>,
     entered: RefCell<EnteredBackendContext<B>>,
     event_queue: RefCell<VecDeque<BackendContextEvent<B>>>,
+    #[cfg(feature = "prerendering")]
+    prerendering_tasks: RefCell<PrerenderingTasks>,
+}
+
+#[cfg(feature = "prerendering")]
+#[derive(Default)]
+struct PrerenderingTasks {
+    pending: Vec<tree::ForestTokenAddr>,
+    wakers: Vec<Waker>,
 }
 
 impl<B: Backend> Clone for BackendContext<B> {
@@ -97,6 +106,8 @@ impl<B: Backend> BackendContext<B> {
             initial_backend_stage,
             entered,
             event_queue: Default::default(),
+            #[cfg(feature = "prerendering")]
+            prerendering_tasks: Default::default(),
         });
         let w = Rc::downgrade(&inner);
         inner.entered.borrow_mut().ctx = Some(w);
@@ -175,6 +186,67 @@ impl<B: Backend> BackendContext<B> {
         }
     }
 
+    #[cfg(feature = "prerendering")]
+    pub(crate) fn add_prerendering_task(
+        &self,
+        backend_element: &tree::ForestToken,
+        task: crate::component::PrerenderingTask,
+    ) {
+        let addr = backend_element.stable_addr();
+        self.inner.prerendering_tasks.borrow_mut().pending.push(addr);
+        let inner = self.inner.clone();
+        B::async_task(async move {
+            task.await;
+            let wakers = {
+                let mut tasks = inner.prerendering_tasks.borrow_mut();
+                if let Some(index) = tasks.pending.iter().position(|x| *x == addr) {
+                    tasks.pending.swap_remove(index);
+                }
+                std::mem::take(&mut tasks.wakers)
+            };
+            for waker in wakers {
+                waker.wake();
+            }
+        });
+    }
+
+    /// Check whether the component of the backend element has an unfinished prerendering task.
+    ///
+    /// The prerendering tasks are returned by `Component::prerendering_created` .
+    #[cfg(feature = "prerendering")]
+    pub fn has_pending_prerendering_task(&self, backend_element: &tree::ForestToken) -> bool {
+        let addr = backend_element.stable_addr();
+        self.inner
+            .prerendering_tasks
+            .borrow()
+            .pending
+            .contains(&addr)
+    }
+
+    /// Wait until the prerendering tasks of the component of the backend element finished.
+    #[cfg(feature = "prerendering")]
+    pub fn prerendering_task_settled(
+        &self,
+        backend_element: &tree::ForestToken,
+    ) -> PrerenderingTaskSettled<B> {
+        PrerenderingTaskSettled {
+            inner: self.inner.clone(),
+            addr: Some(backend_element.stable_addr()),
+        }
+    }
+
+    /// Wait until all prerendering tasks finished.
+    ///
+    /// The tasks generated while waiting (i.e. by newly created components) are also waited.
+    /// After it resolved, the whole prerendering result is ready to be written.
+    #[cfg(feature = "prerendering")]
+    pub fn all_prerendering_tasks_settled(&self) -> PrerenderingTaskSettled<B> {
+        PrerenderingTaskSettled {
+            inner: self.inner.clone(),
+            addr: None,
+        }
+    }
+
     /// Get the prerendering data of a prerenderable component.
     ///
     /// The `QueryData` should be provided to the `PrerenderableComponent` .
@@ -185,6 +257,35 @@ impl<B: Backend> BackendContext<B> {
     }
 }
 
+/// A future that waits some prerendering tasks.
+///
+/// Returned by `BackendContext::prerendering_task_settled` and `BackendContext::all_prerendering_tasks_settled` .
+#[cfg(feature = "prerendering")]
+#[must_use]
+pub struct PrerenderingTaskSettled<B: Backend> {
+    inner: Rc<BackendContextInner<B>>,
+    addr: Option<tree::ForestTokenAddr>,
+}
+
+#[cfg(feature = "prerendering")]
+impl<B: Backend> Future for PrerenderingTaskSettled<B> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let tasks = &mut self.inner.prerendering_tasks.borrow_mut();
+        let settled = match &self.addr {
+            Some(addr) => !tasks.pending.contains(addr),
+            None => tasks.pending.is_empty(),
+        };
+        if settled {
+            Poll::Ready(())
+        } else {
+            tasks.wakers.push(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
 /// A mutable reference to a backend context.
 pub struct EnteredBackendContext<B: Backend> {
     backend: B,
@@ -210,6 +311,34 @@ impl<B: Backend> EnteredBackendContext<B> {
         )
     }
 
+    /// Create a mount point under the specified backend element.
+    ///
+    /// The `parent` can be any element in the backend tree,
+    /// including a standalone tree created by the backend (i.e. an existing DOM element),
+    /// so that multiple mount points can share one backend context.
+    /// The `parent` is kept alive by the mount point.
+    /// The mount point can be detached with `detach` or `detach_dyn` as usual.
+    /// An error is returned if the `parent` is not created by this backend.
+    pub fn attach_at<C: Component + ComponentTemplate<B>>(
+        &mut self,
+        parent: tree::ForestNodeRc<B::GeneralElement>,
+        init: impl FnOnce(&mut C),
+    ) -> Result<MountPoint<B, C>, Error> {
+        let mut root = self.backend.root_mut();
+        let mut parent_node = root.try_borrow_mut(&parent).ok_or(Error::TreeNodeTypeWrong)?;
+        let mut mount_point = MountPoint::attach(
+            &BackendContext {
+                inner: self.ctx.as_ref().unwrap().upgrade().unwrap(),
+            },
+            &mut parent_node,
+            init,
+        )?;
+        drop(parent_node);
+        drop(root);
+        mount_point.set_container(parent);
+        Ok(mount_point)
+    }
+
     /// Create a mount point and apply the prerendering data.
     pub fn prerendering_attach<C: PrerenderableComponent + ComponentTemplate<B> + 'static>(
         &mut self,
@@ -18,8 +18,11 @@
 
 use async_trait::async_trait;
 use std::{
+    any::{Any, TypeId},
     cell::{Cell, RefCell, Ref},
+    future::Future,
     marker::PhantomData,
+    pin::Pin,
     rc::{Rc, Weak},
 };
 
@@ -28,8 +31,10 @@ use crate::{
         context::AsyncCallback, tree::*, Backend, BackendComponent, BackendGeneralElement,
         SupportBackend,
     },
+    context::{Context, ContextDependent, ContextDependents},
     error::Error,
     node::{OwnerWeak, SlotChange, SlotKindTrait, DynNodeList},
+    resource::Resource,
     template::*,
     BackendContext,
 };
@@ -150,6 +155,58 @@ impl<C: 'static> ComponentRc<C> {
             .unwrap())
     }
 
+    /// Load a `Resource` in the component with a future.
+    ///
+    /// The resource returned by `field` is set to pending,
+    /// and then set to ready or failed when the `fut` resolved.
+    /// The template is updated when the resource state changed.
+    /// If the resource is loaded again before the `fut` resolved, the result of the `fut` is ignored.
+    /// In prerendering stage, the prerendering result is written after the `fut` resolved.
+    /// Panics if any error occurred during update.
+    pub fn load<T: 'static, E: 'static>(
+        &self,
+        field: fn(&mut C) -> &mut Resource<T, E>,
+        fut: impl 'static + Future<Output = Result<T, E>>,
+    ) {
+        let ticket = Rc::new(());
+        self.task_with({
+            let ticket = ticket.clone();
+            move |c, ctx| {
+                if field(c).start(ticket) {
+                    ctx.need_update();
+                }
+            }
+        });
+        let this = self.clone();
+        self.inner.spawn_task(Box::pin(async move {
+            let ret = fut.await;
+            let r = this
+                .update_with(move |c, ctx| {
+                    if field(c).finish(&ticket, ret) {
+                        ctx.need_update();
+                    }
+                })
+                .await;
+            if let Err(err) = r {
+                panic!("{}", err);
+            }
+        }));
+    }
+
+    /// Look up a `Context` provided by the ancestor components.
+    ///
+    /// The ancestors are the owner of the component (the component whose template contains it) and the owner's ancestors.
+    /// The template is updated when the context changed.
+    pub fn context<T: 'static>(&self) -> Option<Context<T>> {
+        let ret = Context::from_any(self.inner.owner_context(TypeId::of::<T>())?)?;
+        self.add_context_dependent(&ret.dependents());
+        Some(ret)
+    }
+
+    fn add_context_dependent(&self, dependents: &Rc<dyn ContextDependents>) {
+        dependents.add_dependent(Box::new(self.downgrade()));
+    }
+
     /// Schedule a visiting task, getting the component reference.
     ///
     /// The `f` will be called asynchronously.
@@ -204,6 +261,26 @@ impl<C: 'static> ComponentWeak<C> {
     }
 }
 
+impl<C: 'static> ContextDependent for ComponentWeak<C> {
+    fn key(&self) -> *const () {
+        self.inner.as_ptr() as *const ()
+    }
+
+    fn is_dropped(&self) -> bool {
+        self.inner.strong_count() == 0
+    }
+
+    fn notify(&self) -> bool {
+        match self.upgrade() {
+            Some(this) => {
+                this.task(|_| {});
+                true
+            }
+            None => false,
+        }
+    }
+}
+
 impl<C: 'static> OwnerWeak for ComponentWeak<C> {
     fn apply_updates(&self) -> Result<(), Error> {
         if let Some(x) = self.inner.upgrade() {
@@ -212,6 +289,17 @@ impl<C: 'static> OwnerWeak for ComponentWeak<C> {
         Ok(())
     }
 
+    fn capture_error(&self, err: Error) -> Result<(), Error> {
+        match self.inner.upgrade() {
+            Some(x) => x.capture_error(err),
+            None => Err(err),
+        }
+    }
+
+    fn find_context(&self, type_id: TypeId) -> Option<Rc<dyn Any>> {
+        self.inner.upgrade()?.find_context(type_id)
+    }
+
     fn clone_owner_weak(&self) -> Box<dyn OwnerWeak> {
         Box::new(Self {
             inner: self.inner.clone(),
@@ -248,10 +336,51 @@ pub trait Component: 'static {
     /// such as network requests.
     fn created(&self) {}
 
+    /// Called instead of `created` when the component is created in prerendering stage.
+    ///
+    /// This function can return an async task to prepare some data (network requests, etc.),
+    /// and the task usually ends with a `ComponentRc::update` call to apply the data.
+    /// The streaming prerendering output of the component waits until the task finished.
+    /// The prerendering-apply process (client-side) does not call this function,
+    /// so the same data should be applied in `created` or through the mount point `PrerenderableComponent` .
+    fn prerendering_created(&self) -> Option<PrerenderingTask> {
+        None
+    }
+
     /// Called before every template updates.
     /// 
     /// This function can be used to update some cache that used in the template.
     fn before_template_apply(&mut self) {}
+
+    /// Called after every template updates.
+    ///
+    /// The backend elements in the template can be visited through `template_structure` ,
+    /// so this function can be used to read the layout or focus a newly inserted element.
+    /// Note that the component has not been attached to its parent yet in the first call,
+    /// which happens before `created` .
+    fn after_template_apply(&mut self) {}
+
+    /// Called when an error occurred while creating or updating the template.
+    ///
+    /// The error may come from the template of this component or any descendant component.
+    /// It is passed to the failing component first, and then to its owners until captured.
+    /// Returns `Ok` to capture the error, usually after storing it in a field to show some fallback content,
+    /// and the template will be updated again.
+    /// Returns the error (the default behavior) to pass it to the owner.
+    /// Note that panics are not captured.
+    fn error_captured(&mut self, err: Error) -> Result<(), Error> {
+        Err(err)
+    }
+
+    /// Called before the component is removed.
+    ///
+    /// It is called when the component is removed from the template
+    /// (i.e. an `if` branch switches or a `for` item is removed),
+    /// or the mount point is detached.
+    /// This function can be used to cancel timers, abort network requests, etc.
+    /// It is called before the child components being destroyed.
+    /// After this call, the template of the component will not be updated any more.
+    fn before_destroy(&mut self) {}
 }
 
 /// Some component utility functions.
@@ -361,6 +490,9 @@ impl<B: Backend, T: ComponentTemplate<B>> ComponentExt<B, Self> for T {
     }
 }
 
+/// An async task returned by `Component::prerendering_created` .
+pub type PrerenderingTask = Pin<Box<dyn Future<Output = ()>>>;
+
 /// A component that can be used as mount point when prerendering
 ///
 /// In prerendering stage,
@@ -403,6 +535,11 @@ pub(crate) trait UpdateScheduler: 'static {
         f: Box<dyn FnOnce(&mut Self::EnterType) -> bool>,
     );
     fn sync_update(&self) -> Result<(), Error>;
+    fn capture_error(&self, err: Error) -> Result<(), Error>;
+    fn spawn_task(&self, task: PrerenderingTask);
+    fn find_context(&self, type_id: TypeId) -> Option<Rc<dyn Any>>;
+    fn owner_context(&self, type_id: TypeId) -> Option<Rc<dyn Any>>;
+    fn destroy(self: Rc<Self>);
 }
 
 pub(crate) trait UpdateSchedulerWeak: 'static {
@@ -412,11 +549,35 @@ pub(crate) trait UpdateSchedulerWeak: 'static {
 }
 
 /// A node that wraps a component instance.
-#[derive(Clone)]
+///
+/// The component is destroyed when the node and all its clones are dropped.
 pub struct ComponentNode<C: Component> {
     inner: Rc<RefCell<C>>,
     backend_element_token: ForestToken,
     rc: ComponentRc<C>,
+    _owner: Rc<ComponentNodeOwner<C>>,
+}
+
+impl<C: Component> Clone for ComponentNode<C> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            backend_element_token: self.backend_element_token.clone(),
+            rc: self.rc.clone(),
+            _owner: self._owner.clone(),
+        }
+    }
+}
+
+// it destroys the component when dropped
+struct ComponentNodeOwner<C: 'static> {
+    rc: ComponentRc<C>,
+}
+
+impl<C: 'static> Drop for ComponentNodeOwner<C> {
+    fn drop(&mut self) {
+        self.rc.inner.clone().destroy();
+    }
 }
 
 impl<C: Component> ComponentNode<C> {
@@ -425,6 +586,7 @@ impl<C: Component> ComponentNode<C> {
         backend_context: BackendContext<B>,
         forest_node_rc: ForestNodeRc<<B as Backend>::GeneralElement>,
         owner_weak: Box<dyn OwnerWeak>,
+        contexts: Vec<(TypeId, Rc<dyn Any>)>,
     ) -> Self
     where
         C: ComponentTemplate<B>,
@@ -436,11 +598,14 @@ impl<C: Component> ComponentNode<C> {
             backend_context,
             forest_node_rc,
             owner_weak,
+            contexts,
+            destroyed: Cell::new(false),
         });
         let rc = ComponentRc::new(rc);
         Self {
             inner,
             backend_element_token,
+            _owner: Rc::new(ComponentNodeOwner { rc: rc.clone() }),
             rc,
         }
     }
@@ -449,6 +614,34 @@ impl<C: Component> ComponentNode<C> {
         &self.inner
     }
 
+    pub(crate) fn destroy(&self) {
+        self.rc.inner.clone().destroy();
+    }
+
+    fn create_template<B: Backend>(
+        comp: &mut C,
+        backend_context: &BackendContext<B>,
+        backend_element: &mut ForestNodeMut<B::GeneralElement>,
+        slots: &mut Vec<ForestToken>,
+    ) -> Result<(), Error>
+    where
+        C: ComponentTemplate<B>,
+    {
+        <C as Component>::before_template_apply(comp);
+        <C as ComponentTemplate<B>>::template_create_or_update(
+            comp,
+            backend_context,
+            backend_element,
+            &mut |slot_change| match slot_change {
+                SlotChange::Added(_, t, _) => {
+                    slots.push(t.clone());
+                    Ok(())
+                }
+                _ => Err(Error::TreeNotCreated),
+            },
+        )
+    }
+
     /// Get a ref-counted token `ComponentRc` for the component.
     ///
     /// The `ComponentRc` can move across async steps.
@@ -473,9 +666,72 @@ struct ComponentNodeInBackend<B: Backend, C: ComponentTemplate<B> + Component> {
     backend_context: BackendContext<B>,
     forest_node_rc: ForestNodeRc<B::GeneralElement>,
     owner_weak: Box<dyn OwnerWeak>,
+    contexts: Vec<(TypeId, Rc<dyn Any>)>,
+    destroyed: Cell<bool>,
 }
 
 impl<B: Backend, C: ComponentTemplate<B> + Component> ComponentNodeInBackend<B, C> {
+    // returns false if the component is being visited
+    fn try_destroy_structure(&self) -> bool {
+        let structure = {
+            let mut comp = match self.inner.try_borrow_mut() {
+                Ok(x) => x,
+                Err(_) => return false,
+            };
+            if !<C as ComponentTemplate<B>>::template(&comp).is_initialized() {
+                return true;
+            }
+            <C as Component>::before_destroy(&mut comp);
+            <C as ComponentTemplate<B>>::template_mut(&mut comp).take_structure()
+        };
+        // the child components are destroyed when the structure dropped
+        drop(structure);
+        true
+    }
+
+    fn apply_template_once(&self, comp: &mut C) -> Result<bool, Error> {
+        let mut backend_element = self.forest_node_rc.borrow_mut();
+        <C as Component>::before_template_apply(comp);
+        let has_slot_changes = <C as ComponentTemplate<B>>::template_update_store_slot_changes(
+            comp,
+            &self.backend_context,
+            &mut backend_element,
+        )?;
+        drop(backend_element);
+        <C as Component>::after_template_apply(comp);
+        Ok(has_slot_changes)
+    }
+
+    fn apply_template(&self, comp: &mut C) -> Result<bool, Error> {
+        match self.apply_template_once(comp) {
+            Ok(x) => Ok(x),
+            Err(err) => {
+                // the slot content belongs to the owner and cannot be recreated here,
+                // so the owner should handle the error
+                let has_slots = <C as ComponentTemplate<B>>::template(comp)
+                    .slot_scopes()
+                    .iter()
+                    .next()
+                    .is_some();
+                if has_slots {
+                    return Err(err);
+                }
+                <C as Component>::error_captured(comp, err)?;
+                // the structure may be partially updated, so recreate it
+                drop(<C as ComponentTemplate<B>>::template_mut(comp).take_structure());
+                {
+                    let mut backend_element = self.forest_node_rc.borrow_mut();
+                    while let Some(child) = backend_element.first_child_rc() {
+                        let child = backend_element.borrow_mut(&child);
+                        <B::GeneralElement as BackendGeneralElement>::detach(child);
+                    }
+                }
+                <C as ComponentTemplate<B>>::template(comp).clear_slot_scopes();
+                self.apply_template_once(comp)
+            }
+        }
+    }
+
     fn prepare_inner_changes(
         this: &Rc<Self>,
         f: Box<dyn FnOnce(&mut C) -> bool>,
@@ -483,18 +739,18 @@ impl<B: Backend, C: ComponentTemplate<B> + Component> ComponentNodeInBackend<B,
         let has_slot_changes = {
             let mut comp = this.inner.borrow_mut();
             let force_schedule_update = f(&mut comp);
-            if <C as ComponentTemplate<B>>::template(&mut comp).clear_dirty()
+            if this.destroyed.get() {
+                false
+            } else if <C as ComponentTemplate<B>>::template(&mut comp).clear_dirty()
                 || force_schedule_update
             {
-                let mut backend_element = this.forest_node_rc.borrow_mut();
-                <C as Component>::before_template_apply(&mut comp);
-                let has_slot_changes =
-                    <C as ComponentTemplate<B>>::template_update_store_slot_changes(
-                        &mut comp,
-                        &this.backend_context,
-                        &mut backend_element,
-                    )?;
-                has_slot_changes
+                match this.apply_template(&mut comp) {
+                    Ok(x) => x,
+                    Err(err) => {
+                        drop(comp);
+                        return this.owner_weak.capture_error(err);
+                    }
+                }
             } else {
                 false
             }
@@ -541,21 +797,92 @@ impl<B: Backend, C: ComponentTemplate<B> + Component> UpdateScheduler for Compon
     fn sync_update(&self) -> Result<(), Error> {
         let has_slot_changes = {
             let mut comp = self.inner.borrow_mut();
+            if self.destroyed.get() {
+                return Ok(());
+            }
             <C as ComponentTemplate<B>>::template(&mut comp).clear_dirty();
-            let mut backend_element = self.forest_node_rc.borrow_mut();
-            <C as Component>::before_template_apply(&mut comp);
-            let has_slot_changes = <C as ComponentTemplate<B>>::template_update_store_slot_changes(
-                &mut comp,
-                &self.backend_context,
-                &mut backend_element,
-            )?;
-            has_slot_changes
+            match self.apply_template(&mut comp) {
+                Ok(x) => x,
+                Err(err) => {
+                    drop(comp);
+                    return self.owner_weak.capture_error(err);
+                }
+            }
         };
         if has_slot_changes {
             self.owner_weak.apply_updates()?;
         }
         Ok(())
     }
+
+    #[inline]
+    fn spawn_task(&self, task: PrerenderingTask) {
+        #[cfg(feature = "prerendering")]
+        if self.backend_context.initial_backend_stage() == crate::backend::BackendStage::Prerendering
+        {
+            self.backend_context.add_prerendering_task(&self.forest_node_rc.token(), task);
+            return;
+        }
+        B::async_task(task);
+    }
+
+    #[inline]
+    fn find_context(&self, type_id: TypeId) -> Option<Rc<dyn Any>> {
+        // the contexts provided later override the earlier ones
+        match self.contexts.iter().rev().find(|(x, _)| *x == type_id) {
+            Some((_, x)) => Some(x.clone()),
+            None => self.owner_weak.find_context(type_id),
+        }
+    }
+
+    #[inline]
+    fn owner_context(&self, type_id: TypeId) -> Option<Rc<dyn Any>> {
+        self.owner_weak.find_context(type_id)
+    }
+
+    fn destroy(self: Rc<Self>) {
+        if self.destroyed.replace(true) {
+            return;
+        }
+        if !self.try_destroy_structure() {
+            // the component is destroyed during its own update, so destroy it later
+            B::async_task(async move {
+                if !self.try_destroy_structure() {
+                    log::error!("Cannot destroy a component while it is being visited");
+                }
+            });
+        }
+    }
+
+    #[inline]
+    fn capture_error(&self, err: Error) -> Result<(), Error> {
+        let ret = {
+            let mut comp = match self.inner.try_borrow_mut() {
+                Ok(x) => x,
+                Err(_) => return Err(err),
+            };
+            // the error cannot be handled if the component is not created yet or has been destroyed
+            if self.destroyed.get() || !<C as ComponentTemplate<B>>::template(&comp).is_initialized() {
+                return Err(err);
+            }
+            match <C as Component>::error_captured(&mut comp, err) {
+                Ok(()) => {
+                    <C as ComponentTemplate<B>>::template(&mut comp).clear_dirty();
+                    self.apply_template(&mut comp)
+                }
+                Err(err) => Err(err),
+            }
+        };
+        match ret {
+            Ok(has_slot_changes) => {
+                if has_slot_changes {
+                    self.owner_weak.apply_updates()?;
+                }
+                Ok(())
+            }
+            Err(err) => self.owner_weak.capture_error(err),
+        }
+    }
 }
 
 impl<C: Component + ComponentSlotKind> SupportBackend for C {
@@ -578,12 +905,18 @@ impl<B: Backend, C: ComponentTemplate<B> + Component> BackendComponent<B> for Co
         Self: Sized,
     {
         let backend_element = B::GeneralElement::create_virtual_element(owner)?;
+        // the contexts are provided and looked up in `Component::new`
+        let (comp, scope) = crate::context::with_init_scope(&**owner_weak, <C as Component>::new);
         let this = ComponentNode::new(
-            <C as Component>::new(),
+            comp,
             backend_context.clone(),
             backend_element.clone(),
             owner_weak.clone_owner_weak(),
+            scope.provided,
         );
+        for dependents in scope.consumed.iter() {
+            this.rc.add_context_dependent(dependents);
+        }
         let init = TemplateInit {
             updater: this.rc.downgrade(),
         };
@@ -610,24 +943,54 @@ impl<B: Backend, C: ComponentTemplate<B> + Component> BackendComponent<B> for Co
             let mut backend_element = owner.borrow_mut_token(&self.backend_element_token).unwrap();
             let mut force_dirty = false;
             update_fn(&mut comp, &mut force_dirty);
+            let mut slot_count = 0;
             <C as Component>::before_template_apply(&mut comp);
-            <C as ComponentTemplate<B>>::template_create_or_update(
+            let ret = <C as ComponentTemplate<B>>::template_create_or_update(
                 &mut comp,
                 backend_context,
                 &mut backend_element,
-                &mut |slot_change| {
-                    match slot_change {
-                        SlotChange::Added(n, t, d) => slot_fn(n, t, d),
-                        _ => Err(Error::TreeNotCreated),
+                &mut |slot_change| match slot_change {
+                    SlotChange::Added(n, t, d) => {
+                        slot_count += 1;
+                        slot_fn(n, t, d)
                     }
+                    _ => Err(Error::TreeNotCreated),
                 },
-            )?;
+            );
+            if let Err(err) = ret {
+                // the slots passed to the owner cannot be removed, so the owner should handle the error
+                if slot_count > 0 {
+                    return Err(err);
+                }
+                <C as Component>::error_captured(&mut comp, err)?;
+                // remove the partially created content and then retry
+                while let Some(child) = backend_element.first_child_rc() {
+                    let child = backend_element.borrow_mut(&child);
+                    <B::GeneralElement as BackendGeneralElement>::detach(child);
+                }
+                <C as ComponentTemplate<B>>::template(&comp).clear_slot_scopes();
+                // the slots are passed to the owner after the retry succeeded
+                let mut slots = vec![];
+                Self::create_template(&mut comp, backend_context, &mut backend_element, &mut slots)?;
+                drop(backend_element);
+                let slot_scopes = <C as ComponentTemplate<B>>::template(&comp).slot_scopes();
+                for t in slots {
+                    slot_fn(
+                        &mut owner.borrow_mut_token(&t).ok_or(Error::TreeNodeReleased)?,
+                        &t,
+                        &slot_scopes.get(t.stable_addr())?.1,
+                    )?;
+                }
+            }
+            <C as Component>::after_template_apply(&mut comp);
             #[cfg(not(feature = "prerendering"))]
             <C as Component>::created(&comp);
             #[cfg(feature = "prerendering")]
             if backend_context.initial_backend_stage() != crate::backend::BackendStage::Prerendering
             {
                 <C as Component>::created(&comp);
+            } else if let Some(task) = <C as Component>::prerendering_created(&comp) {
+                backend_context.add_prerendering_task(&self.backend_element_token, task);
             }
             Ok(())
         } else {
@@ -652,12 +1015,42 @@ impl<B: Backend, C: ComponentTemplate<B> + Component> BackendComponent<B> for Co
                 // if any data changed, do updates
                 let mut backend_element = owner.borrow_mut_token(&self.backend_element_token).unwrap();
                 <C as Component>::before_template_apply(&mut comp);
-                <C as ComponentTemplate<B>>::template_create_or_update(
+                let ret = <C as ComponentTemplate<B>>::template_create_or_update(
                     &mut comp,
                     backend_context,
                     &mut backend_element,
                     &mut slot_fn,
-                )
+                );
+                if let Err(err) = ret {
+                    // the slot content belongs to the owner and cannot be recreated here,
+                    // so the owner should handle the error
+                    let has_slots = <C as ComponentTemplate<B>>::template(&comp)
+                        .slot_scopes()
+                        .iter()
+                        .next()
+                        .is_some();
+                    if has_slots {
+                        return Err(err);
+                    }
+                    <C as Component>::error_captured(&mut comp, err)?;
+                    // the structure may be partially updated, so recreate it
+                    drop(<C as ComponentTemplate<B>>::template_mut(&mut comp).take_structure());
+                    while let Some(child) = backend_element.first_child_rc() {
+                        let child = backend_element.borrow_mut(&child);
+                        <B::GeneralElement as BackendGeneralElement>::detach(child);
+                    }
+                    <C as ComponentTemplate<B>>::template(&comp).clear_slot_scopes();
+                    <C as Component>::before_template_apply(&mut comp);
+                    <C as ComponentTemplate<B>>::template_create_or_update(
+                        &mut comp,
+                        backend_context,
+                        &mut backend_element,
+                        &mut slot_fn,
+                    )?;
+                }
+                drop(backend_element);
+                <C as Component>::after_template_apply(&mut comp);
+                Ok(())
             } else {
                 let changes = <C as ComponentTemplate<B>>::template(&mut comp)
                     .extract_pending_slot_changes(Vec::with_capacity(0));
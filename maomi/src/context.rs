@@ -0,0 +1,223 @@
+//! The context utilities.
+//!
+//! A context is a shared value provided by a component to its descendants,
+//! so that services like themes, routers or API clients do not need to be passed through properties.
+//! The descendants are the components in the template of the provider (including the slot content),
+//! and the components in their templates recursively.
+//!
+//! A context should be provided with `Context::provide` in `Component::new` ,
+//! and can be looked up with `Context::consume` in `Component::new`
+//! or with `ComponentRc::context` at any time after the component created.
+//! When a context changed, the templates of the components that looked it up are updated.
+//!
+//! ```rust
+//! use maomi::prelude::*;
+//! use maomi::context::Context;
+//!
+//! #[derive(Clone, Copy, PartialEq)]
+//! enum Theme {
+//!     Light,
+//!     Dark,
+//! }
+//!
+//! #[component]
+//! struct App {
+//!     template: template! {
+//!         <ThemedButton />
+//!     },
+//!     theme: Context<Theme>,
+//! }
+//!
+//! impl Component for App {
+//!     fn new() -> Self {
+//!         Self {
+//!             template: Default::default(),
+//!             // provide the context to the descendants
+//!             theme: Context::provide(Theme::Light),
+//!         }
+//!     }
+//! }
+//!
+//! impl App {
+//!     fn toggle_theme(&self) {
+//!         // the `ThemedButton` will be updated
+//!         self.theme.update(|x| *x = if *x == Theme::Light { Theme::Dark } else { Theme::Light });
+//!     }
+//! }
+//!
+//! #[component]
+//! struct ThemedButton {
+//!     template: template! {
+//!         if self.theme.with(|x| *x == Theme::Light) {
+//!             "light"
+//!         } else {
+//!             "dark"
+//!         }
+//!     },
+//!     theme: Context<Theme>,
+//! }
+//!
+//! impl Component for ThemedButton {
+//!     fn new() -> Self {
+//!         Self {
+//!             template: Default::default(),
+//!             // look up the context provided by the ancestors
+//!             theme: Context::consume().expect("no theme provided"),
+//!         }
+//!     }
+//! }
+//! ```
+
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    rc::Rc,
+};
+
+use crate::node::OwnerWeak;
+
+thread_local! {
+    static INIT_SCOPES: RefCell<Vec<InitScope>> = const { RefCell::new(Vec::new()) };
+}
+
+struct ContextInner<T> {
+    value: RefCell<T>,
+    dependents: RefCell<Vec<Box<dyn ContextDependent>>>,
+}
+
+// a component that looked up a context
+//
+// It holds a weak ref of the component,
+// so the key (the component address) cannot be reused by another component until it is pruned.
+pub(crate) trait ContextDependent {
+    fn key(&self) -> *const ();
+    fn is_dropped(&self) -> bool;
+    // schedule an update, returning false if the component is dropped
+    fn notify(&self) -> bool;
+}
+
+pub(crate) trait ContextDependents {
+    fn add_dependent(&self, dependent: Box<dyn ContextDependent>);
+}
+
+fn merge_dependent(dependents: &mut Vec<Box<dyn ContextDependent>>, dependent: Box<dyn ContextDependent>) {
+    dependents.retain(|x| !x.is_dropped());
+    let key = dependent.key();
+    if dependents.iter().all(|x| x.key() != key) {
+        dependents.push(dependent);
+    }
+}
+
+impl<T: 'static> ContextDependents for ContextInner<T> {
+    fn add_dependent(&self, dependent: Box<dyn ContextDependent>) {
+        merge_dependent(&mut self.dependents.borrow_mut(), dependent);
+    }
+}
+
+/// A shared value which can be provided to the descendant components
+pub struct Context<T> {
+    inner: Rc<ContextInner<T>>,
+}
+
+impl<T> Clone for Context<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: 'static> Context<T> {
+    /// Create a context and provide it to the descendant components
+    ///
+    /// It should be called in `Component::new` ,
+    /// otherwise the context is not provided to any component.
+    /// If an ancestor also provides a context of the same type, the nearest one is used by the descendants.
+    pub fn provide(value: T) -> Self {
+        let ret = Self {
+            inner: Rc::new(ContextInner {
+                value: RefCell::new(value),
+                dependents: RefCell::new(vec![]),
+            }),
+        };
+        INIT_SCOPES.with(|x| {
+            if let Some(scope) = x.borrow_mut().last_mut() {
+                scope.provided.push((TypeId::of::<T>(), ret.inner.clone()));
+            }
+        });
+        ret
+    }
+
+    /// Look up a context provided by the ancestor components
+    ///
+    /// It should be called in `Component::new` , otherwise `None` is returned.
+    /// After the component created, `ComponentRc::context` can be used instead.
+    pub fn consume() -> Option<Self> {
+        INIT_SCOPES.with(|x| {
+            let mut scopes = x.borrow_mut();
+            let scope = scopes.last_mut()?;
+            let ret = Self::from_any(scope.owner.find_context(TypeId::of::<T>())?)?;
+            scope.consumed.push(ret.inner.clone());
+            Some(ret)
+        })
+    }
+
+    pub(crate) fn from_any(x: Rc<dyn Any>) -> Option<Self> {
+        let inner = x.downcast::<ContextInner<T>>().ok()?;
+        Some(Self { inner })
+    }
+
+    pub(crate) fn dependents(&self) -> Rc<dyn ContextDependents> {
+        self.inner.clone()
+    }
+
+    /// Get a reference of the value
+    #[inline]
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.inner.value.borrow())
+    }
+
+    /// Set the value
+    ///
+    /// The components that looked up the context will be updated.
+    #[inline]
+    pub fn set(&self, value: T) {
+        self.update(|x| *x = value);
+    }
+
+    /// Get a mutable reference of the value
+    ///
+    /// The components that looked up the context will be updated.
+    pub fn update<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        let ret = f(&mut self.inner.value.borrow_mut());
+        let mut dependents = std::mem::take(&mut *self.inner.dependents.borrow_mut());
+        dependents.retain(|x| x.notify());
+        let mut cur = self.inner.dependents.borrow_mut();
+        // some dependents may be added during updates
+        for dependent in cur.drain(..) {
+            merge_dependent(&mut dependents, dependent);
+        }
+        *cur = dependents;
+        ret
+    }
+}
+
+pub(crate) struct InitScope {
+    owner: Box<dyn OwnerWeak>,
+    pub(crate) provided: Vec<(TypeId, Rc<dyn Any>)>,
+    pub(crate) consumed: Vec<Rc<dyn ContextDependents>>,
+}
+
+/// Call `f` with the context scope of a new component
+pub(crate) fn with_init_scope<R>(owner: &dyn OwnerWeak, f: impl FnOnce() -> R) -> (R, InitScope) {
+    INIT_SCOPES.with(|x| {
+        x.borrow_mut().push(InitScope {
+            owner: owner.clone_owner_weak(),
+            provided: vec![],
+            consumed: vec![],
+        })
+    });
+    let ret = f();
+    let scope = INIT_SCOPES.with(|x| x.borrow_mut().pop().unwrap());
+    (ret, scope)
+}
@@ -11,12 +11,14 @@
 
 pub mod backend;
 pub mod component;
+pub mod context;
 pub mod diff;
 pub mod error;
 pub mod event;
 pub mod mount_point;
 pub mod node;
 pub mod prop;
+pub mod resource;
 pub mod template;
 pub mod text_node;
 pub mod locale_string;
@@ -41,6 +41,13 @@ impl Display for LocaleStaticStr {
     }
 }
 
+/// A placeholder of a static string which lacks translation.
+///
+/// It is used by the template when i18n is enabled.
+/// See `StaticStrPropertyUpdate` for details.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Hash)]
+pub struct LackTranslation;
+
 /// A translated string.
 #[derive(Debug, Clone, Default, PartialEq, Hash)]
 pub struct LocaleString(String);
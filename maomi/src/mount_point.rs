@@ -21,10 +21,11 @@ impl OwnerWeak for DanglingOwner {
 
 /// A mount point which contains a root component.
 ///
-/// A mount point can be created through `BackendContext::attach` .
+/// A mount point can be created through `BackendContext::attach` or `BackendContext::attach_at` .
 pub struct MountPoint<B: Backend, C: Component + ComponentTemplate<B> + 'static> {
     component_node: ComponentNode<C>,
     backend_element: tree::ForestNodeRc<B::GeneralElement>,
+    container: Option<tree::ForestNodeRc<B::GeneralElement>>,
 }
 
 impl<B: Backend, C: Component + ComponentTemplate<B>> MountPoint<B, C> {
@@ -50,14 +51,20 @@ impl<B: Backend, C: Component + ComponentTemplate<B>> MountPoint<B, C> {
         let this = Self {
             component_node,
             backend_element,
+            container: None,
         };
         <B::GeneralElement as BackendGeneralElement>::append(parent, &this.backend_element);
         Ok(this)
     }
 
+    pub(crate) fn set_container(&mut self, container: tree::ForestNodeRc<B::GeneralElement>) {
+        self.container = Some(container);
+    }
+
     pub(crate) fn detach(&mut self, parent: &mut tree::ForestNodeMut<B::GeneralElement>) {
         let elem = parent.borrow_mut(&self.backend_element);
         <B::GeneralElement as BackendGeneralElement>::detach(elem);
+        self.component_node.destroy();
     }
 
     /// Get the root component node.
@@ -72,6 +79,7 @@ impl<B: Backend, C: Component + ComponentTemplate<B>> MountPoint<B, C> {
         DynMountPoint {
             _component_node: Box::new(self.component_node),
             backend_element: self.backend_element,
+            _container: self.container,
         }
     }
 }
@@ -82,11 +90,14 @@ impl<B: Backend, C: Component + ComponentTemplate<B>> MountPoint<B, C> {
 pub struct DynMountPoint<B: Backend> {
     _component_node: Box<dyn std::any::Any>,
     backend_element: tree::ForestNodeRc<B::GeneralElement>,
+    _container: Option<tree::ForestNodeRc<B::GeneralElement>>,
 }
 
 impl<B: Backend> DynMountPoint<B> {
     pub(crate) fn detach(&mut self, parent: &mut tree::ForestNodeMut<B::GeneralElement>) {
         let elem = parent.borrow_mut(&self.backend_element);
         <B::GeneralElement as BackendGeneralElement>::detach(elem);
+        // the root component is destroyed when dropped
+        self._component_node = Box::new(());
     }
 }
@@ -1,6 +1,12 @@
 //! Helper types for node trees.
 
-use std::{collections::HashMap, hash::Hash, marker::PhantomData, any::Any};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    hash::Hash,
+    marker::PhantomData,
+    rc::Rc,
+};
 
 use crate::{
     backend::{tree, SupportBackend},
@@ -49,6 +55,16 @@ pub trait OwnerWeak {
     fn apply_updates(&self) -> Result<(), Error>;
     /// Clone the owner itself.
     fn clone_owner_weak(&self) -> Box<dyn OwnerWeak>;
+    /// Pass an error to the owner.
+    ///
+    /// The error is returned if it is not captured.
+    fn capture_error(&self, err: Error) -> Result<(), Error> {
+        Err(err)
+    }
+    /// Find a context provided by the owner or its ancestors.
+    fn find_context(&self, _type_id: TypeId) -> Option<Rc<dyn Any>> {
+        None
+    }
 }
 
 /// A general node type.
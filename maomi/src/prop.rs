@@ -131,6 +131,15 @@
 //!     },
 //! }
 //! ```
+//! 
+//! If the item is written as a string literal, i.e. `my_prop:"xxx"=` ,
+//! the item name `xxx` is provided as a string,
+//! and the property should implement `NamedListPropertyUpdate` instead.
+//! The item name is checked in compile time, and it should be a valid attribute name.
+//! This is how `data-*` attributes work in `maomi_dom` crate.
+//! 
+//! The list property named `data` is special:
+//! `data:xxx=` is a shorthand of `dataset:"xxx"=` , with the `_` in `xxx` replaced by `-` .
 
 use std::{borrow::Borrow, ops::Deref, fmt::Display, rc::Rc, cell::RefCell};
 
@@ -149,6 +158,57 @@ pub trait PropertyUpdate<S: ?Sized> {
     fn compare_and_set_ref(dest: &mut Self, src: &S, ctx: &mut Self::UpdateContext);
 }
 
+/// The property updater used when i18n is disabled.
+///
+/// Every `PropertyUpdate<S>` automatically implements this trait.
+/// The properties which require translated values when i18n is enabled
+/// (i.e. implements `PropertyUpdate<LocaleString>` )
+/// can implement this trait to accept untranslated strings when i18n is disabled.
+pub trait UntranslatedPropertyUpdate<S: ?Sized> {
+    /// The update context, same as `PropertyUpdate::UpdateContext` .
+    type UpdateContext;
+
+    /// The updater.
+    fn compare_and_set_untranslated(dest: &mut Self, src: &S, ctx: &mut Self::UpdateContext);
+}
+
+impl<S: ?Sized, T: PropertyUpdate<S>> UntranslatedPropertyUpdate<S> for T {
+    type UpdateContext = T::UpdateContext;
+
+    #[inline]
+    fn compare_and_set_untranslated(dest: &mut Self, src: &S, ctx: &mut Self::UpdateContext) {
+        PropertyUpdate::compare_and_set_ref(dest, src, ctx)
+    }
+}
+
+/// The property updater used for static string literals in templates when i18n is enabled.
+///
+/// The literal is provided in both the original form and the translated form `L` .
+/// `L` is a `LocaleStaticStr` if the translation is found, or `LackTranslation` if not.
+/// Every `PropertyUpdate<str>` automatically implements this trait with the original form.
+/// The properties which require translated values can implement `StaticStrPropertyUpdate<LocaleStaticStr>` ,
+/// so that the literal without translation is rejected by the compiler.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be set with this static string",
+    label = "the static string lacks translation",
+)]
+pub trait StaticStrPropertyUpdate<L> {
+    /// The update context, same as `PropertyUpdate::UpdateContext` .
+    type UpdateContext;
+
+    /// The updater.
+    fn compare_and_set_static_str(dest: &mut Self, src: &'static str, translated: L, ctx: &mut Self::UpdateContext);
+}
+
+impl<L, T: PropertyUpdate<str>> StaticStrPropertyUpdate<L> for T {
+    type UpdateContext = T::UpdateContext;
+
+    #[inline]
+    fn compare_and_set_static_str(dest: &mut Self, src: &'static str, _translated: L, ctx: &mut Self::UpdateContext) {
+        PropertyUpdate::compare_and_set_ref(dest, src, ctx)
+    }
+}
+
 /// A property of components that can be set through templates.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
 pub struct Prop<T> {
@@ -387,6 +447,26 @@ pub trait ListPropertyUpdate<S: ?Sized>: ListPropertyInit {
         Self: Sized;
 }
 
+/// The list property updater with named items.
+///
+/// In templates, the `my_prop:"xxx"=` syntax updates the `my_prop` property with this trait,
+/// while the item name `xxx` is provided as a string rather than a `ListPropertyItem` .
+pub trait NamedListPropertyUpdate<S: ?Sized>: ListPropertyInit {
+    /// The updater.
+    ///
+    /// The `name` of an `index` never changes.
+    /// If used in components and updated through templates,
+    /// `ctx` must be set to true if updated.
+    fn compare_and_set_named_item_ref(
+        dest: &mut Self,
+        index: usize,
+        name: &'static str,
+        src: &S,
+        ctx: &mut Self::UpdateContext,
+    ) where
+        Self: Sized;
+}
+
 /// The item updater for a specified list property `L` .
 pub trait ListPropertyItem<L: ListPropertyUpdate<S>, S: ?Sized> {
     /// The item value type.
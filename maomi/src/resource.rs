@@ -0,0 +1,156 @@
+//! Async resources.
+//!
+//! A `Resource` is a value that is loaded by a future.
+//! It can be loaded through `ComponentRc::load` ,
+//! and the template is updated when the future resolved.
+//! In the template, the placeholder content can be shown according to the `ResourceState` .
+//!
+//! ```rust
+//! use maomi::prelude::*;
+//! use maomi::{
+//!     component::PrerenderingTask,
+//!     error::Error,
+//!     resource::{Resource, ResourceState},
+//!     template::TemplateHelper,
+//! };
+//!
+//! async fn fetch_user_name() -> Result<String, Error> {
+//!     Ok("user".into())
+//! }
+//!
+//! #[component]
+//! struct MyComponent {
+//!     template: template! {
+//!         match self.user_name.state() {
+//!             ResourceState::Pending => { "loading" }
+//!             ResourceState::Ready(name) => { { name } }
+//!             ResourceState::Failed(err) => { { &err.to_string() } }
+//!         }
+//!     },
+//!     user_name: Resource<String>,
+//! }
+//!
+//! impl Component for MyComponent {
+//!     fn new() -> Self {
+//!         Self {
+//!             template: Default::default(),
+//!             user_name: Resource::new(),
+//!         }
+//!     }
+//!
+//!     fn created(&self) {
+//!         let this = self.template.component_rc().unwrap();
+//!         this.load(|this| &mut this.user_name, fetch_user_name());
+//!     }
+//!
+//!     fn prerendering_created(&self) -> Option<PrerenderingTask> {
+//!         // the prerendering result is written after the resource loaded
+//!         self.created();
+//!         None
+//!     }
+//! }
+//! ```
+
+use std::rc::Rc;
+
+use crate::error::Error;
+
+/// The state of a `Resource`
+#[derive(Debug)]
+pub enum ResourceState<T, E = Error> {
+    /// The value is loading
+    Pending,
+    /// The value is ready
+    Ready(T),
+    /// The loading failed
+    Failed(E),
+}
+
+/// A value that is loaded by a future
+///
+/// It is usually loaded with `ComponentRc::load` .
+pub struct Resource<T, E = Error> {
+    state: ResourceState<T, E>,
+    ticket: Option<Rc<()>>,
+}
+
+impl<T, E> Default for Resource<T, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, E> Resource<T, E> {
+    /// Create a pending resource
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            state: ResourceState::Pending,
+            ticket: None,
+        }
+    }
+
+    /// Get the state
+    #[inline]
+    pub fn state(&self) -> &ResourceState<T, E> {
+        &self.state
+    }
+
+    /// Returns whether the value is loading
+    #[inline]
+    pub fn is_pending(&self) -> bool {
+        matches!(self.state, ResourceState::Pending)
+    }
+
+    /// Get the value if it is ready
+    #[inline]
+    pub fn get(&self) -> Option<&T> {
+        match &self.state {
+            ResourceState::Ready(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Get the error if the loading failed
+    #[inline]
+    pub fn error(&self) -> Option<&E> {
+        match &self.state {
+            ResourceState::Failed(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Set the value directly
+    ///
+    /// The result of the current loading (if any) will be ignored.
+    #[inline]
+    pub fn set(&mut self, value: T) {
+        self.ticket = None;
+        self.state = ResourceState::Ready(value);
+    }
+
+    /// Start a new loading, returning whether the state changed
+    pub(crate) fn start(&mut self, ticket: Rc<()>) -> bool {
+        self.ticket = Some(ticket);
+        if self.is_pending() {
+            false
+        } else {
+            self.state = ResourceState::Pending;
+            true
+        }
+    }
+
+    /// Finish a loading, returning whether the state changed
+    pub(crate) fn finish(&mut self, ticket: &Rc<()>, ret: Result<T, E>) -> bool {
+        match &self.ticket {
+            Some(x) if Rc::ptr_eq(x, ticket) => {}
+            _ => return false,
+        }
+        self.ticket = None;
+        self.state = match ret {
+            Ok(x) => ResourceState::Ready(x),
+            Err(x) => ResourceState::Failed(x),
+        };
+        true
+    }
+}
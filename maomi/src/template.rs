@@ -58,6 +58,14 @@ pub trait TemplateHelper<C: ?Sized, S, L>: Default {
     where
         C: 'static + Sized;
 
+    #[doc(hidden)]
+    fn take_structure(&mut self) -> Option<S> {
+        None
+    }
+
+    #[doc(hidden)]
+    fn clear_slot_scopes(&self) {}
+
     #[doc(hidden)]
     fn extract_pending_slot_changes(
         &self,
@@ -165,6 +173,16 @@ impl<C, S, L: Default> TemplateHelper<C, S, L> for Template<C, S, L> {
             .ok_or(Error::TreeNotCreated)
     }
 
+    #[inline]
+    fn take_structure(&mut self) -> Option<S> {
+        self.__m_structure.take().map(|x| x.into_inner())
+    }
+
+    #[inline]
+    fn clear_slot_scopes(&self) {
+        *self.__m_slot_scopes.borrow_mut() = L::default();
+    }
+
     #[inline]
     fn extract_pending_slot_changes(
         &self,
@@ -209,6 +227,9 @@ pub trait ComponentTemplate<B: Backend>: ComponentSlotKind {
     /// Get a reference of the template field of the component.
     fn template(&self) -> &Self::TemplateField;
 
+    /// Get a mutable reference of the template field of the component.
+    fn template_mut(&mut self) -> &mut Self::TemplateField;
+
     /// Initialize a template.
     fn template_init(&mut self, init: TemplateInit<Self>)
     where